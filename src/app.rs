@@ -1,5 +1,13 @@
-use std::{path::PathBuf, rc::Rc, sync::Arc};
-use tokio::spawn;
+use chrono::{DateTime, Local};
+use std::{
+    collections::HashMap,
+    future::Future,
+    path::PathBuf,
+    rc::Rc,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::{spawn, task::AbortHandle};
 
 use crate::{
     client::Client,
@@ -8,16 +16,31 @@ use crate::{
     environment::Environment,
     error::{AppError, Result},
     event::{
-        AppEventType, CompleteDownloadObjectResult, CompleteInitializeResult,
-        CompleteLoadObjectDetailResult, CompleteLoadObjectVersionsResult,
-        CompleteLoadObjectsResult, CompletePreviewObjectResult, CompleteReloadBucketsResult,
-        CompleteReloadObjectsResult, Sender,
+        AppEventType, CompleteChangeStorageClassResult, CompleteCopyObjectsResult,
+        CompleteDeleteObjectsResult, CompleteDownloadObjectResult, CompleteInitializeResult,
+        CompleteLoadDeleteTargetResult, CompleteLoadDeletedObjectsResult,
+        CompleteLoadDownloadTargetResult, CompleteLoadObjectDetailResult,
+        CompleteLoadObjectTagsResult, CompleteLoadObjectVersionsResult,
+        CompleteLoadObjectsAtResult, CompleteLoadObjectsResult,
+        CompleteLoadStorageClassTargetResult, CompleteLoadUndeleteTargetResult,
+        CompletePreviewObjectResult, CompletePromoteObjectVersionResult,
+        CompleteReloadBucketsResult, CompleteReloadObjectsResult, CompleteRestoreObjectResult,
+        CompleteSaveObjectTagsResult, CompleteUndeleteObjectResult, CompleteUndeleteObjectsResult,
+        CompleteUpdateObjectMetadataResult, Sender,
+    },
+    file::{collect_local_files, copy_to_clipboard, expand_home_dir, save_binary, save_error_log},
+    object::{
+        AppObjects, BucketProperty, BucketPropertyType, CopyMode, CopyTarget, DeleteTarget,
+        FileDetail, FileVersion, ObjectItem, ObjectKey, ObjectMetadata, ObjectTag, ObjectVersions,
+        RawObject, StorageClassTarget, UndeleteTarget,
     },
-    file::{copy_to_clipboard, save_binary, save_error_log},
-    object::{AppObjects, FileDetail, ObjectItem, RawObject},
     pages::page::{Page, PageStack},
+    transfer::{run_transfer, TransferRequest, TransferStatus, Transfers},
+    ui::common::format_size_byte,
 };
 
+const MAX_RUNNING_TRANSFERS: usize = 2;
+
 #[derive(Debug)]
 pub enum Notification {
     None,
@@ -50,6 +73,10 @@ pub struct App {
 
     notification: Notification,
     is_loading: bool,
+    // the task of the current loading operation that can be canceled by the user
+    loading_handle: Option<AbortHandle>,
+    transfers: Transfers,
+    transfer_handles: HashMap<usize, AbortHandle>,
     width: usize,
     height: usize,
 }
@@ -65,6 +92,9 @@ impl App {
             tx,
             notification: Notification::None,
             is_loading: true,
+            loading_handle: None,
+            transfers: Transfers::default(),
+            transfer_handles: HashMap::new(),
             width,
             height,
         }
@@ -91,13 +121,26 @@ impl App {
                     .load_bucket(&name, prefix, region)
                     .await
                     .map(|b| vec![b]),
-                None => client.load_all_buckets().await,
+                None => {
+                    let progress_tx = tx.clone();
+                    let progress = move |resolved, total| {
+                        progress_tx.send(AppEventType::BucketRegionsProgress(resolved, total))
+                    };
+                    client.load_all_buckets(progress).await
+                }
             };
             let result = CompleteInitializeResult::new(buckets);
             tx.send(AppEventType::CompleteInitialize(result));
         });
     }
 
+    pub fn update_bucket_regions_progress(&mut self, resolved: usize, total: usize) {
+        // the progress of reloading buckets is not shown
+        if let Page::Initializing(page) = self.page_stack.current_page_mut() {
+            page.set_progress(resolved, total);
+        }
+    }
+
     pub fn complete_initialize(&mut self, result: Result<CompleteInitializeResult>) {
         match result {
             Ok(CompleteInitializeResult { buckets }) => {
@@ -127,7 +170,11 @@ impl App {
         } else {
             if bucket_items_len == 0 {
                 let (client, _) = self.unwrap_client_tx();
-                let msg = format!("No bucket found (region: {})", client.region());
+                let msg = if client.all_regions() {
+                    "No bucket found".to_string()
+                } else {
+                    format!("No bucket found (region: {})", client.region())
+                };
                 self.tx.send(AppEventType::NotifyWarn(msg));
             }
             self.is_loading = false;
@@ -137,7 +184,7 @@ impl App {
     pub fn reload_buckets(&self) {
         let (client, tx) = self.unwrap_client_tx();
         spawn(async move {
-            let buckets = client.load_all_buckets().await;
+            let buckets = client.load_all_buckets(|_, _| {}).await;
             let result = CompleteReloadBucketsResult::new(buckets);
             tx.send(AppEventType::CompleteReloadBuckets(result));
         });
@@ -174,11 +221,28 @@ impl App {
         self.is_loading = true;
     }
 
+    pub fn bucket_list_open_properties(&mut self) {
+        let bucket_list_page = self.page_stack.current_page().as_bucket_list();
+        let bucket_name = bucket_list_page.current_selected_item().name.clone();
+
+        let mut bucket_detail_page =
+            Page::of_bucket_detail(bucket_name, Rc::clone(&self.ctx), self.tx.clone());
+        bucket_detail_page
+            .as_mut_bucket_detail()
+            .load_selected_property();
+        self.page_stack.push(bucket_detail_page);
+    }
+
     pub fn object_list_move_down(&mut self) {
         let object_list_page = self.page_stack.current_page().as_object_list();
         let selected = object_list_page.current_selected_item().to_owned();
 
         match selected {
+            ObjectItem::File { .. } if object_list_page.is_time_traveling() => {
+                // the cached detail is of the latest version
+                self.tx.send(AppEventType::LoadObjectDetail);
+                self.is_loading = true;
+            }
             ObjectItem::File { .. } => {
                 let current_object_key = object_list_page.current_selected_object_key();
                 let detail = self.app_objects.get_object_detail(&current_object_key);
@@ -201,7 +265,11 @@ impl App {
             }
             ObjectItem::Dir { .. } => {
                 let object_key = object_list_page.current_selected_object_key();
-                if let Some(current_object_items) = self.app_objects.get_object_items(&object_key) {
+                if let Some(at) = object_list_page.time_travel_at() {
+                    self.tx.send(AppEventType::LoadObjectsAt(object_key, at));
+                } else if let Some(current_object_items) =
+                    self.app_objects.get_object_items(&object_key)
+                {
                     // object list has been already loaded
                     let new_object_list_page = Page::of_object_list(
                         current_object_items,
@@ -229,12 +297,90 @@ impl App {
     pub fn object_list_refresh(&mut self) {
         let object_list_page = self.page_stack.current_page().as_object_list();
         let object_key = object_list_page.current_dir_object_key();
+        if let Some(at) = object_list_page.time_travel_at() {
+            self.tx
+                .send(AppEventType::LoadObjectsAt(object_key.clone(), at));
+            return;
+        }
         self.app_objects.clear_object_items_under(object_key);
 
         self.tx.send(AppEventType::ReloadObjects);
         self.is_loading = true;
     }
 
+    pub fn object_list_upload_object(&mut self, input: String) {
+        self.tx.send(AppEventType::UploadObject(input));
+
+        let page = self.page_stack.current_page_mut().as_mut_object_list();
+        page.close_upload_dialog();
+    }
+
+    pub fn object_list_download_object(&mut self) {
+        let object_list_page = self.page_stack.current_page().as_object_list();
+        let object_key = object_list_page.current_selected_object_key();
+        match object_list_page.current_selected_item() {
+            ObjectItem::Dir { .. } if object_list_page.is_time_traveling() => {
+                let msg = "Downloading a folder is not available in time travel mode".to_string();
+                self.tx.send(AppEventType::NotifyWarn(msg));
+            }
+            ObjectItem::Dir { .. } => {
+                // all objects under the prefix must be loaded before downloading
+                self.tx.send(AppEventType::LoadDownloadTarget);
+                self.is_loading = true;
+            }
+            ObjectItem::File {
+                name, size_byte, ..
+            } => {
+                let size_byte = *size_byte;
+                let path = self.ctx.config.download_file_path(name);
+                let request = TransferRequest::Download {
+                    object_key,
+                    version_id: object_list_page.current_selected_version_id(),
+                    size_byte,
+                    path,
+                };
+                self.enqueue_transfer(request, size_byte);
+            }
+        }
+    }
+
+    pub fn object_list_open_delete_dialog(&mut self) {
+        self.tx.send(AppEventType::LoadDeleteTarget);
+        self.is_loading = true;
+    }
+
+    pub fn object_list_open_storage_class_dialog(&mut self) {
+        self.tx.send(AppEventType::LoadStorageClassTarget);
+        self.is_loading = true;
+    }
+
+    pub fn object_list_open_undelete_dialog(&mut self) {
+        self.tx.send(AppEventType::LoadUndeleteTarget);
+        self.is_loading = true;
+    }
+
+    pub fn object_list_exit_time_travel(&mut self) {
+        // the page is replaced with the current objects
+        self.tx.send(AppEventType::ReloadObjects);
+        self.is_loading = true;
+    }
+
+    pub fn object_list_show_deleted_objects(&mut self) {
+        self.tx.send(AppEventType::LoadDeletedObjects);
+        self.is_loading = true;
+    }
+
+    pub fn object_list_open_copy_dialog(&mut self, mode: CopyMode) {
+        let buckets = self
+            .app_objects
+            .get_bucket_items()
+            .iter()
+            .map(|item| item.name.clone())
+            .collect();
+        let page = self.page_stack.current_page_mut().as_mut_object_list();
+        page.open_copy_dialog(mode, buckets);
+    }
+
     pub fn back_to_bucket_list(&mut self) {
         if self.app_objects.get_bucket_items().len() == 1 {
             return;
@@ -242,7 +388,7 @@ impl App {
         self.page_stack.clear();
     }
 
-    pub fn load_objects(&self) {
+    pub fn load_objects(&mut self) {
         let current_object_key = match self.page_stack.current_page() {
             page @ Page::BucketList(_) => page.as_bucket_list().current_selected_object_key(),
             page @ Page::ObjectList(_) => page.as_object_list().current_selected_object_key(),
@@ -251,7 +397,7 @@ impl App {
         let bucket = current_object_key.bucket_name.clone();
         let prefix = current_object_key.joined_object_path(false);
         let (client, tx) = self.unwrap_client_tx();
-        spawn(async move {
+        self.spawn_cancellable(async move {
             let items = client.load_objects(&bucket, &prefix).await;
             let result = CompleteLoadObjectsResult::new(items);
             tx.send(AppEventType::CompleteLoadObjects(result));
@@ -285,13 +431,13 @@ impl App {
         self.is_loading = false;
     }
 
-    pub fn reload_objects(&self) {
+    pub fn reload_objects(&mut self) {
         let object_list_page = self.page_stack.current_page().as_object_list();
         let object_key = object_list_page.current_dir_object_key();
         let bucket = object_key.bucket_name.clone();
         let prefix = object_key.joined_object_path(false);
         let (client, tx) = self.unwrap_client_tx();
-        spawn(async move {
+        self.spawn_cancellable(async move {
             let items = client.load_objects(&bucket, &prefix).await;
             let result = CompleteReloadObjectsResult::new(items);
             tx.send(AppEventType::CompleteReloadObjects(result));
@@ -299,11 +445,22 @@ impl App {
     }
 
     pub fn complete_reload_objects(&mut self, result: Result<CompleteReloadObjectsResult>) {
+        let show_deleted = self
+            .page_stack
+            .current_page()
+            .as_object_list()
+            .is_showing_deleted();
+        let reloaded = result.is_ok();
         self.page_stack.pop();
         self.complete_load_objects(result.map(|r| r.into()));
+
+        // keep showing deleted objects after refreshing
+        if show_deleted && reloaded {
+            self.object_list_show_deleted_objects();
+        }
     }
 
-    pub fn load_object_detail(&self) {
+    pub fn load_object_detail(&mut self) {
         let object_list_page = self.page_stack.current_page().as_object_list();
 
         if let ObjectItem::File {
@@ -312,15 +469,16 @@ impl App {
         {
             let name = name.clone();
             let size_byte = *size_byte;
+            let version_id = object_list_page.current_selected_version_id();
 
             let map_key = object_list_page.current_selected_object_key().clone();
             let bucket = map_key.bucket_name.clone();
             let key = map_key.joined_object_path(true);
 
             let (client, tx) = self.unwrap_client_tx();
-            spawn(async move {
+            self.spawn_cancellable(async move {
                 let detail = client
-                    .load_object_detail(&bucket, &key, &name, size_byte)
+                    .load_object_detail(&bucket, &key, version_id.as_deref(), &name, size_byte)
                     .await;
                 let result = CompleteLoadObjectDetailResult::new(detail, map_key);
                 tx.send(AppEventType::CompleteLoadObjectDetail(result));
@@ -331,10 +489,12 @@ impl App {
     pub fn complete_load_object_detail(&mut self, result: Result<CompleteLoadObjectDetailResult>) {
         match result {
             Ok(CompleteLoadObjectDetailResult { detail, map_key }) => {
-                self.app_objects
-                    .set_object_detail(map_key.clone(), *detail.clone());
-
                 let object_page = self.page_stack.current_page().as_object_list();
+                let version_id = object_page.current_selected_version_id();
+                if version_id.is_none() {
+                    self.app_objects
+                        .set_object_detail(map_key.clone(), *detail.clone());
+                }
 
                 let object_detail_page = Page::of_object_detail(
                     *detail.clone(),
@@ -345,6 +505,9 @@ impl App {
                     self.tx.clone(),
                 );
                 self.page_stack.push(object_detail_page);
+
+                let object_detail_page = self.page_stack.current_page_mut().as_mut_object_detail();
+                object_detail_page.set_detail_version_id(version_id);
             }
             Err(e) => {
                 self.tx.send(AppEventType::NotifyError(e));
@@ -360,7 +523,7 @@ impl App {
         let versions = self.app_objects.get_object_versions(&current_object_key);
 
         if let Some(versions) = versions {
-            // object versions has been already loaded
+            // object versions has been already loaded (possibly only some of them)
             let result =
                 CompleteLoadObjectVersionsResult::new(Ok(versions.clone()), current_object_key);
             self.tx
@@ -371,7 +534,7 @@ impl App {
         }
     }
 
-    pub fn load_object_versions(&self) {
+    pub fn load_object_versions(&mut self) {
         let object_detail_page = self.page_stack.current_page().as_object_detail();
 
         let map_key = object_detail_page.current_object_key().clone();
@@ -379,13 +542,57 @@ impl App {
         let key = map_key.joined_object_path(true);
 
         let (client, tx) = self.unwrap_client_tx();
-        spawn(async move {
-            let versions = client.load_object_versions(&bucket, &key).await;
+        self.spawn_cancellable(async move {
+            let versions = client.load_object_versions(&bucket, &key, None).await;
             let result = CompleteLoadObjectVersionsResult::new(versions, map_key);
             tx.send(AppEventType::CompleteLoadObjectVersions(result));
         });
     }
 
+    pub fn load_more_object_versions(&mut self) {
+        let object_detail_page = self.page_stack.current_page().as_object_detail();
+
+        let map_key = object_detail_page.current_object_key().clone();
+        let Some(version_id_marker) = self
+            .app_objects
+            .get_object_versions(&map_key)
+            .and_then(|v| v.next_version_id_marker.clone())
+        else {
+            return;
+        };
+        let bucket = map_key.bucket_name.clone();
+        let key = map_key.joined_object_path(true);
+        self.is_loading = true;
+
+        let (client, tx) = self.unwrap_client_tx();
+        self.spawn_cancellable(async move {
+            let versions = client
+                .load_object_versions(&bucket, &key, Some(version_id_marker))
+                .await;
+            let result = CompleteLoadObjectVersionsResult::new(versions, map_key);
+            tx.send(AppEventType::CompleteLoadMoreObjectVersions(result));
+        });
+    }
+
+    pub fn complete_load_more_object_versions(
+        &mut self,
+        result: Result<CompleteLoadObjectVersionsResult>,
+    ) {
+        match result {
+            Ok(CompleteLoadObjectVersionsResult { versions, map_key }) => {
+                self.app_objects
+                    .extend_object_versions(map_key, versions.clone());
+
+                let object_detail_page = self.page_stack.current_page_mut().as_mut_object_detail();
+                object_detail_page.extend_versions(versions);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
     pub fn complete_load_object_versions(
         &mut self,
         result: Result<CompleteLoadObjectVersionsResult>,
@@ -406,6 +613,232 @@ impl App {
         self.is_loading = false;
     }
 
+    pub fn load_object_tags(&mut self, version_id: Option<String>) {
+        let object_detail_page = self.page_stack.current_page().as_object_detail();
+
+        let map_key = object_detail_page.current_object_key();
+        let bucket = map_key.bucket_name.clone();
+        let key = map_key.joined_object_path(true);
+        self.is_loading = true;
+
+        let (client, tx) = self.unwrap_client_tx();
+        self.spawn_cancellable(async move {
+            let tags = client
+                .load_object_tags(&bucket, &key, version_id.clone())
+                .await;
+            let result = CompleteLoadObjectTagsResult::new(tags, version_id);
+            tx.send(AppEventType::CompleteLoadObjectTags(result));
+        });
+    }
+
+    pub fn complete_load_object_tags(&mut self, result: Result<CompleteLoadObjectTagsResult>) {
+        match result {
+            Ok(CompleteLoadObjectTagsResult { tags, version_id }) => {
+                let object_detail_page = self.page_stack.current_page_mut().as_mut_object_detail();
+                object_detail_page.select_tags_tab(tags, version_id);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn save_object_tags(&mut self, version_id: Option<String>, tags: Vec<ObjectTag>) {
+        let object_detail_page = self.page_stack.current_page().as_object_detail();
+
+        let map_key = object_detail_page.current_object_key();
+        let bucket = map_key.bucket_name.clone();
+        let key = map_key.joined_object_path(true);
+        self.is_loading = true;
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let result = client
+                .put_object_tags(&bucket, &key, version_id, &tags)
+                .await;
+            let result = CompleteSaveObjectTagsResult::new(result, tags);
+            tx.send(AppEventType::CompleteSaveObjectTags(result));
+        });
+    }
+
+    pub fn complete_save_object_tags(&mut self, result: Result<CompleteSaveObjectTagsResult>) {
+        match result {
+            Ok(CompleteSaveObjectTagsResult { tags }) => {
+                let object_detail_page = self.page_stack.current_page_mut().as_mut_object_detail();
+                object_detail_page.set_tags(tags);
+
+                let msg = "Saved tags successfully".to_string();
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn update_object_metadata(&mut self, metadata: ObjectMetadata) {
+        let object_detail_page = self.page_stack.current_page().as_object_detail();
+
+        let map_key = object_detail_page.current_object_key().clone();
+        let file_detail = object_detail_page.file_detail();
+        let name = file_detail.name.clone();
+        let size_byte = file_detail.size_byte;
+        let bucket = map_key.bucket_name.clone();
+        let key = map_key.joined_object_path(true);
+        self.is_loading = true;
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let detail = match client.update_object_metadata(&bucket, &key, metadata).await {
+                Ok(()) => {
+                    client
+                        .load_object_detail(&bucket, &key, None, &name, size_byte)
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+            let result = CompleteUpdateObjectMetadataResult::new(detail, map_key);
+            tx.send(AppEventType::CompleteUpdateObjectMetadata(result));
+        });
+    }
+
+    pub fn complete_update_object_metadata(
+        &mut self,
+        result: Result<CompleteUpdateObjectMetadataResult>,
+    ) {
+        match result {
+            Ok(CompleteUpdateObjectMetadataResult { detail, map_key }) => {
+                self.app_objects.clear_object_versions(&map_key);
+                self.app_objects.set_object_detail(map_key, *detail.clone());
+
+                let object_detail_page = self.page_stack.current_page_mut().as_mut_object_detail();
+                object_detail_page.set_file_detail(*detail);
+                // a new version has been created, so the versions must be reloaded
+                object_detail_page.set_versions(ObjectVersions::default());
+
+                let msg = "Updated metadata successfully".to_string();
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn promote_object_version(&mut self, version: FileVersion) {
+        let object_detail_page = self.page_stack.current_page_mut().as_mut_object_detail();
+        object_detail_page.close_promote_version_dialog();
+
+        let map_key = object_detail_page.current_object_key().clone();
+        let file_detail = object_detail_page.file_detail();
+        let name = file_detail.name.clone();
+        // the new latest version has the same content as the promoted one
+        let size_byte = version.size_byte;
+        let bucket = map_key.bucket_name.clone();
+        let key = map_key.joined_object_path(true);
+        self.is_loading = true;
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let detail = match client
+                .promote_object_version(&bucket, &key, version.version_id)
+                .await
+            {
+                Ok(()) => {
+                    client
+                        .load_object_detail(&bucket, &key, None, &name, size_byte)
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+            let result = CompletePromoteObjectVersionResult::new(detail, map_key);
+            tx.send(AppEventType::CompletePromoteObjectVersion(result));
+        });
+    }
+
+    pub fn complete_promote_object_version(
+        &mut self,
+        result: Result<CompletePromoteObjectVersionResult>,
+    ) {
+        match result {
+            Ok(CompletePromoteObjectVersionResult { detail, map_key }) => {
+                self.app_objects.clear_object_versions(&map_key);
+                self.app_objects.set_object_detail(map_key, *detail.clone());
+
+                let object_detail_page = self.page_stack.current_page_mut().as_mut_object_detail();
+                object_detail_page.set_file_detail(*detail);
+                object_detail_page.set_versions(ObjectVersions::default());
+
+                let msg = "Promoted the version to latest successfully".to_string();
+                self.tx.send(AppEventType::NotifySuccess(msg));
+                // reload the versions to show the new latest version
+                self.tx.send(AppEventType::OpenObjectVersionsTab);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn restore_object(&mut self, days: i32, tier: String, version_id: Option<String>) {
+        let object_detail_page = self.page_stack.current_page().as_object_detail();
+
+        let map_key = object_detail_page.current_object_key().clone();
+        let file_detail = object_detail_page.file_detail();
+        let name = file_detail.name.clone();
+        let size_byte = file_detail.size_byte;
+        let bucket = map_key.bucket_name.clone();
+        let key = map_key.joined_object_path(true);
+        self.is_loading = true;
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let detail = match client
+                .restore_object(&bucket, &key, version_id.clone(), days, &tier)
+                .await
+            {
+                Ok(()) => {
+                    client
+                        .load_object_detail(&bucket, &key, version_id.as_deref(), &name, size_byte)
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+            let result = CompleteRestoreObjectResult::new(detail, map_key, version_id);
+            tx.send(AppEventType::CompleteRestoreObject(result));
+        });
+    }
+
+    pub fn complete_restore_object(&mut self, result: Result<CompleteRestoreObjectResult>) {
+        match result {
+            Ok(CompleteRestoreObjectResult {
+                detail,
+                map_key,
+                version_id,
+            }) => {
+                // only the detail of the latest version is cached
+                if version_id.is_none() {
+                    self.app_objects.set_object_detail(map_key, *detail.clone());
+                }
+
+                let object_detail_page = self.page_stack.current_page_mut().as_mut_object_detail();
+                object_detail_page.set_file_detail(*detail);
+                object_detail_page.set_detail_version_id(version_id);
+
+                let msg = "Restore requested successfully".to_string();
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
     pub fn open_help(&mut self) {
         let helps = self.page_stack.current_page().helps();
         if helps.is_empty() {
@@ -422,11 +855,13 @@ impl App {
     pub fn detail_download_object(&mut self, file_detail: FileDetail, version_id: Option<String>) {
         self.tx
             .send(AppEventType::DownloadObject(file_detail, version_id));
-        self.is_loading = true;
     }
 
     pub fn preview_download_object(&self, obj: RawObject, path: String) {
-        let result = CompleteDownloadObjectResult::new(Ok(obj), PathBuf::from(path));
+        // the previewed object is already in memory and its size is capped
+        let path = PathBuf::from(path);
+        let result = save_binary(&path, &obj.bytes);
+        let result = CompleteDownloadObjectResult::new(result, path);
         self.tx.send(AppEventType::CompleteDownloadObject(result));
     }
 
@@ -436,24 +871,15 @@ impl App {
         self.is_loading = true;
     }
 
-    pub fn download_object(&self, file_detail: FileDetail, version_id: Option<String>) {
+    pub fn download_object(&mut self, file_detail: FileDetail, version_id: Option<String>) {
         let object_name = file_detail.name;
         let size_byte = file_detail.size_byte;
 
-        self.download_object_and(
-            &object_name,
-            size_byte,
-            None,
-            version_id,
-            |tx, obj, path| {
-                let result = CompleteDownloadObjectResult::new(obj, path);
-                tx.send(AppEventType::CompleteDownloadObject(result));
-            },
-        )
+        self.enqueue_download(&object_name, size_byte, None, version_id);
     }
 
     pub fn download_object_as(
-        &self,
+        &mut self,
         file_detail: FileDetail,
         input: String,
         version_id: Option<String>,
@@ -461,27 +887,12 @@ impl App {
         let object_name = file_detail.name;
         let size_byte = file_detail.size_byte;
 
-        self.download_object_and(
-            &object_name,
-            size_byte,
-            Some(&input),
-            version_id,
-            |tx, obj, path| {
-                let result = CompleteDownloadObjectResult::new(obj, path);
-                tx.send(AppEventType::CompleteDownloadObject(result));
-            },
-        )
+        self.enqueue_download(&object_name, size_byte, Some(&input), version_id);
     }
 
     pub fn complete_download_object(&mut self, result: Result<CompleteDownloadObjectResult>) {
-        let result = match result {
-            Ok(CompleteDownloadObjectResult { obj, path }) => {
-                save_binary(&path, &obj.bytes).map(|_| path)
-            }
-            Err(e) => Err(e),
-        };
         match result {
-            Ok(path) => {
+            Ok(CompleteDownloadObjectResult { path }) => {
                 let msg = format!(
                     "Download completed successfully: {}",
                     path.to_string_lossy()
@@ -501,10 +912,23 @@ impl App {
         }
     }
 
-    pub fn preview_object(&self, file_detail: FileDetail, version_id: Option<String>) {
+    pub fn preview_object(&mut self, file_detail: FileDetail, version_id: Option<String>) {
         let object_name = file_detail.name.clone();
         let size_byte = file_detail.size_byte;
 
+        // the preview holds the whole object in memory
+        let max_size_byte = self.ctx.config.preview.max_size_byte;
+        if size_byte > max_size_byte {
+            let msg = format!(
+                "Object is too large to preview ({} > {}), download it instead",
+                format_size_byte(size_byte),
+                format_size_byte(max_size_byte)
+            );
+            let result = Err(AppError::msg(msg));
+            self.tx.send(AppEventType::CompletePreviewObject(result));
+            return;
+        }
+
         self.download_object_and(
             &object_name,
             size_byte,
@@ -547,33 +971,759 @@ impl App {
         self.is_loading = false;
     }
 
-    fn download_object_and<F>(
-        &self,
-        object_name: &str,
-        size_byte: usize,
-        save_file_name: Option<&str>,
-        version_id: Option<String>,
-        f: F,
-    ) where
-        F: FnOnce(Sender, Result<RawObject>, PathBuf) + Send + 'static,
-    {
-        let object_key = match self.page_stack.current_page() {
-            page @ Page::ObjectDetail(_) => page.as_object_detail().current_object_key(),
-            page @ Page::ObjectPreview(_) => page.as_object_preview().current_object_key(),
-            page => panic!("Invalid page: {:?}", page),
+    pub fn upload_object(&mut self, input: String) {
+        let object_list_page = self.page_stack.current_page().as_object_list();
+        let object_key = object_list_page.current_dir_object_key().clone();
+
+        let path = expand_home_dir(&input);
+        let files = match collect_local_files(&path) {
+            Ok(files) => files,
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+                return;
+            }
+        };
+        if files.is_empty() {
+            let msg = format!("No files to upload: {}", path.to_string_lossy());
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        let total_size = files.iter().map(|file| file.size_byte).sum();
+
+        let request = TransferRequest::Upload {
+            object_key,
+            path,
+            files: Arc::new(files),
+        };
+        self.enqueue_transfer(request, total_size);
+    }
+
+    pub fn load_download_target(&mut self) {
+        let object_list_page = self.page_stack.current_page().as_object_list();
+        let object_key = object_list_page.current_selected_object_key();
+
+        let bucket = object_key.bucket_name.clone();
+        let prefix = object_key.joined_object_path(false);
+
+        let (client, tx) = self.unwrap_client_tx();
+        self.spawn_cancellable(async move {
+            let objects = client.list_all_objects(&bucket, &prefix).await;
+            let result = CompleteLoadDownloadTargetResult::new(objects, object_key);
+            tx.send(AppEventType::CompleteLoadDownloadTarget(result));
+        });
+    }
+
+    pub fn complete_load_download_target(
+        &mut self,
+        result: Result<CompleteLoadDownloadTargetResult>,
+    ) {
+        self.is_loading = false;
+        let CompleteLoadDownloadTargetResult {
+            object_key,
+            mut objects,
+        } = match result {
+            Ok(result) => result,
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+                return;
+            }
         };
 
+        // folder placeholder objects have no content to save
+        objects.retain(|obj| !obj.key.ends_with('/'));
+        if objects.is_empty() {
+            let msg = format!(
+                "No objects found: s3://{}/{}",
+                object_key.bucket_name,
+                object_key.joined_object_path(false)
+            );
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+
+        let dir_name = object_key.object_path.last().unwrap();
+        let path = self.ctx.config.download_file_path(dir_name);
+        let total_size = objects.iter().map(|obj| obj.size_byte).sum();
+
+        let request = TransferRequest::DownloadDir {
+            object_key,
+            path,
+            objects: Arc::new(objects),
+        };
+        self.enqueue_transfer(request, total_size);
+    }
+
+    pub fn load_delete_target(&mut self) {
+        let object_list_page = self.page_stack.current_page().as_object_list();
+        let object_key = object_list_page.current_selected_object_key();
+
+        let bucket = object_key.bucket_name.clone();
+        let prefix = object_key.joined_object_path(false);
+
+        let (client, tx) = self.unwrap_client_tx();
+        self.spawn_cancellable(async move {
+            let target = client
+                .list_all_objects(&bucket, &prefix)
+                .await
+                .map(|objects| DeleteTarget {
+                    object_key,
+                    dir: true,
+                    objects,
+                    version_id: None,
+                });
+            let result = CompleteLoadDeleteTargetResult::new(target);
+            tx.send(AppEventType::CompleteLoadDeleteTarget(result));
+        });
+    }
+
+    pub fn complete_load_delete_target(&mut self, result: Result<CompleteLoadDeleteTargetResult>) {
+        match result {
+            Ok(CompleteLoadDeleteTargetResult { target }) if target.objects.is_empty() => {
+                let msg = format!("No objects found: {}", target.s3_uri());
+                self.tx.send(AppEventType::NotifyWarn(msg));
+            }
+            Ok(CompleteLoadDeleteTargetResult { target }) => {
+                let page = self.page_stack.current_page_mut().as_mut_object_list();
+                page.open_delete_dialog(target);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn delete_objects(&mut self, target: DeleteTarget) {
+        match self.page_stack.current_page_mut() {
+            Page::ObjectList(page) => page.close_delete_dialog(),
+            Page::ObjectDetail(page) => page.close_delete_dialog(),
+            page => panic!("Invalid page: {:?}", page),
+        }
+        self.is_loading = true;
+
+        let bucket = target.object_key.bucket_name.clone();
+        let total = target.objects.len();
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let result = if target.dir {
+                let keys: Vec<String> = target.objects.iter().map(|o| o.key.clone()).collect();
+                client
+                    .delete_objects(&bucket, &keys, |n| {
+                        let msg = format!("Deleted {} out of {} objects", n, total);
+                        tx.send(AppEventType::NotifyInfo(msg));
+                    })
+                    .await
+            } else {
+                let key = target.object_key.joined_object_path(true);
+                client
+                    .delete_object(&bucket, &key, target.version_id.clone())
+                    .await
+            };
+            let result = CompleteDeleteObjectsResult::new(result, target);
+            tx.send(AppEventType::CompleteDeleteObjects(result));
+        });
+    }
+
+    pub fn complete_delete_objects(&mut self, result: Result<CompleteDeleteObjectsResult>) {
+        match result {
+            Ok(CompleteDeleteObjectsResult { target }) => {
+                self.app_objects
+                    .clear_object_items_under(&target.object_key);
+
+                if let Page::ObjectDetail(_) = self.page_stack.current_page() {
+                    self.page_stack.pop();
+                }
+
+                let msg = format!("Deleted successfully: {}", target.s3_uri());
+                self.tx.send(AppEventType::NotifySuccess(msg));
+                self.tx.send(AppEventType::ObjectListRefresh);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn load_objects_at(&mut self, object_key: ObjectKey, at: DateTime<Local>) {
+        self.is_loading = true;
+
+        let bucket = object_key.bucket_name.clone();
+        let prefix = object_key.joined_object_path(false);
+
+        let (client, tx) = self.unwrap_client_tx();
+        self.spawn_cancellable(async move {
+            let objects = client.load_objects_at(&bucket, &prefix, at).await;
+            let result = CompleteLoadObjectsAtResult::new(objects, object_key, at);
+            tx.send(AppEventType::CompleteLoadObjectsAt(result));
+        });
+    }
+
+    pub fn complete_load_objects_at(&mut self, result: Result<CompleteLoadObjectsAtResult>) {
+        match result {
+            Ok(CompleteLoadObjectsAtResult {
+                objects,
+                object_key,
+                at,
+            }) => {
+                let page = self.page_stack.current_page().as_object_list();
+                if *page.current_dir_object_key() != object_key {
+                    // moved down into a folder while time traveling
+                    let new_object_list_page = Page::of_object_list(
+                        Vec::new(),
+                        object_key,
+                        Rc::clone(&self.ctx),
+                        self.tx.clone(),
+                    );
+                    self.page_stack.push(new_object_list_page);
+                }
+                let page = self.page_stack.current_page_mut().as_mut_object_list();
+                page.set_time_travel_objects(at, objects);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn load_deleted_objects(&mut self) {
+        let object_list_page = self.page_stack.current_page().as_object_list();
+        let object_key = object_list_page.current_dir_object_key();
+        let bucket = object_key.bucket_name.clone();
+        let prefix = object_key.joined_object_path(false);
+
+        let (client, tx) = self.unwrap_client_tx();
+        self.spawn_cancellable(async move {
+            let items = client.load_deleted_objects(&bucket, &prefix).await;
+            let result = CompleteLoadDeletedObjectsResult::new(items);
+            tx.send(AppEventType::CompleteLoadDeletedObjects(result));
+        });
+    }
+
+    pub fn complete_load_deleted_objects(
+        &mut self,
+        result: Result<CompleteLoadDeletedObjectsResult>,
+    ) {
+        match result {
+            Ok(CompleteLoadDeletedObjectsResult { items }) => {
+                if items.is_empty() {
+                    let msg = "No deleted objects found".to_string();
+                    self.tx.send(AppEventType::NotifyInfo(msg));
+                }
+                let page = self.page_stack.current_page_mut().as_mut_object_list();
+                page.show_deleted_items(items);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn undelete_object(&mut self, object_key: ObjectKey) {
+        let page = self.page_stack.current_page_mut().as_mut_object_list();
+        page.close_undelete_dialog();
+        self.is_loading = true;
+
         let bucket = object_key.bucket_name.clone();
         let key = object_key.joined_object_path(true);
 
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let result = client.undelete_object(&bucket, &key).await;
+            let result = CompleteUndeleteObjectResult::new(result, object_key);
+            tx.send(AppEventType::CompleteUndeleteObject(result));
+        });
+    }
+
+    pub fn complete_undelete_object(&mut self, result: Result<CompleteUndeleteObjectResult>) {
+        match result {
+            Ok(CompleteUndeleteObjectResult { object_key }) => {
+                self.app_objects.clear_object_versions(&object_key);
+
+                let msg = format!(
+                    "Undeleted successfully: s3://{}/{}",
+                    object_key.bucket_name,
+                    object_key.joined_object_path(true)
+                );
+                self.tx.send(AppEventType::NotifySuccess(msg));
+                self.tx.send(AppEventType::ObjectListRefresh);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn load_undelete_target(&mut self) {
+        let object_list_page = self.page_stack.current_page().as_object_list();
+        let object_key = object_list_page.current_selected_object_key();
+        let bucket = object_key.bucket_name.clone();
+        let prefix = object_key.joined_object_path(false);
+
+        let (client, tx) = self.unwrap_client_tx();
+        self.spawn_cancellable(async move {
+            let target = client
+                .list_latest_delete_markers(&bucket, &prefix)
+                .await
+                .map(|markers| UndeleteTarget {
+                    object_key,
+                    markers,
+                });
+            let result = CompleteLoadUndeleteTargetResult::new(target);
+            tx.send(AppEventType::CompleteLoadUndeleteTarget(result));
+        });
+    }
+
+    pub fn complete_load_undelete_target(
+        &mut self,
+        result: Result<CompleteLoadUndeleteTargetResult>,
+    ) {
+        match result {
+            Ok(CompleteLoadUndeleteTargetResult { target }) if target.markers.is_empty() => {
+                let msg = format!("No deleted objects found: {}", target.s3_uri());
+                self.tx.send(AppEventType::NotifyWarn(msg));
+            }
+            Ok(CompleteLoadUndeleteTargetResult { target }) => {
+                let page = self.page_stack.current_page_mut().as_mut_object_list();
+                page.open_undelete_objects_dialog(target);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn undelete_objects(&mut self, target: UndeleteTarget) {
+        let page = self.page_stack.current_page_mut().as_mut_object_list();
+        page.close_undelete_objects_dialog();
+        self.is_loading = true;
+
+        let bucket = target.object_key.bucket_name.clone();
+        let total = target.markers.len();
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let result = client
+                .undelete_objects(&bucket, &target.markers, |n| {
+                    let msg = format!("Undeleted {} out of {} objects", n, total);
+                    tx.send(AppEventType::NotifyInfo(msg));
+                })
+                .await;
+            let result = CompleteUndeleteObjectsResult::new(result, target);
+            tx.send(AppEventType::CompleteUndeleteObjects(result));
+        });
+    }
+
+    pub fn complete_undelete_objects(&mut self, result: Result<CompleteUndeleteObjectsResult>) {
+        match result {
+            Ok(CompleteUndeleteObjectsResult { target }) => {
+                self.app_objects
+                    .clear_object_items_under(&target.object_key);
+
+                let msg = format!(
+                    "Undeleted {} objects successfully: {}",
+                    target.markers.len(),
+                    target.s3_uri()
+                );
+                self.tx.send(AppEventType::NotifySuccess(msg));
+                self.tx.send(AppEventType::ObjectListRefresh);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn load_storage_class_target(&mut self) {
+        let object_list_page = self.page_stack.current_page().as_object_list();
+        let object_key = object_list_page.current_selected_object_key();
+        let dir = matches!(
+            object_list_page.current_selected_item(),
+            ObjectItem::Dir { .. }
+        );
+
+        let bucket = object_key.bucket_name.clone();
+        let prefix = object_key.joined_object_path(!dir);
+
+        let (client, tx) = self.unwrap_client_tx();
+        self.spawn_cancellable(async move {
+            let target = client
+                .list_all_objects(&bucket, &prefix)
+                .await
+                .map(|objects| StorageClassTarget {
+                    objects: objects
+                        .into_iter()
+                        // a file key may also be a prefix of other keys
+                        .filter(|o| dir || o.key == prefix)
+                        .collect(),
+                    object_key,
+                    dir,
+                });
+            let result = CompleteLoadStorageClassTargetResult::new(target);
+            tx.send(AppEventType::CompleteLoadStorageClassTarget(result));
+        });
+    }
+
+    pub fn complete_load_storage_class_target(
+        &mut self,
+        result: Result<CompleteLoadStorageClassTargetResult>,
+    ) {
+        match result {
+            Ok(CompleteLoadStorageClassTargetResult { target }) if target.objects.is_empty() => {
+                let msg = format!("No objects found: {}", target.s3_uri());
+                self.tx.send(AppEventType::NotifyWarn(msg));
+            }
+            Ok(CompleteLoadStorageClassTargetResult { target }) => {
+                let page = self.page_stack.current_page_mut().as_mut_object_list();
+                page.open_storage_class_dialog(target);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn change_storage_class(&mut self, target: StorageClassTarget, storage_class: String) {
+        let page = self.page_stack.current_page_mut().as_mut_object_list();
+        page.close_storage_class_dialog();
+        self.is_loading = true;
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let count = client
+                .change_storage_classes(&target, &storage_class, |n, total| {
+                    let msg = format!("Changed {} out of {} objects", n, total);
+                    tx.send(AppEventType::NotifyInfo(msg));
+                })
+                .await;
+            let result = CompleteChangeStorageClassResult::new(count, target, storage_class);
+            tx.send(AppEventType::CompleteChangeStorageClass(result));
+        });
+    }
+
+    pub fn complete_change_storage_class(
+        &mut self,
+        result: Result<CompleteChangeStorageClassResult>,
+    ) {
+        match result {
+            Ok(CompleteChangeStorageClassResult {
+                target,
+                storage_class,
+                count,
+            }) => {
+                self.app_objects
+                    .clear_object_items_under(&target.object_key);
+
+                let msg = format!(
+                    "Changed the storage class of {} objects to {}: {}",
+                    count,
+                    storage_class,
+                    target.s3_uri()
+                );
+                self.tx.send(AppEventType::NotifySuccess(msg));
+                self.tx.send(AppEventType::ObjectListRefresh);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn copy_objects(&mut self, target: CopyTarget) {
+        let page = self.page_stack.current_page_mut().as_mut_object_list();
+        page.close_copy_dialog();
+
+        if target.is_same_location() {
+            let msg = "Source and destination are the same".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        self.is_loading = true;
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let count = client
+                .copy_objects(&target, |n, total| {
+                    let msg = format!("Copied {} out of {} objects", n, total);
+                    tx.send(AppEventType::NotifyInfo(msg));
+                })
+                .await;
+            let result = CompleteCopyObjectsResult::new(count, target);
+            tx.send(AppEventType::CompleteCopyObjects(result));
+        });
+    }
+
+    pub fn complete_copy_objects(&mut self, result: Result<CompleteCopyObjectsResult>) {
+        match result {
+            Ok(CompleteCopyObjectsResult { target, count }) => {
+                // new prefixes may appear anywhere up to the bucket root
+                let dst_bucket_key = ObjectKey {
+                    bucket_name: target.dst_bucket.clone(),
+                    object_path: Vec::new(),
+                };
+                self.app_objects.clear_object_items_under(&dst_bucket_key);
+                if target.mode == CopyMode::Move {
+                    self.app_objects
+                        .clear_object_items_under(&target.object_key);
+                }
+
+                let verb = match target.mode {
+                    CopyMode::Copy => "Copied",
+                    CopyMode::Move => "Moved",
+                };
+                let msg = format!(
+                    "{} {} objects successfully: {} -> {}",
+                    verb,
+                    count,
+                    target.s3_uri(),
+                    target.dst_s3_uri()
+                );
+                self.tx.send(AppEventType::NotifySuccess(msg));
+                self.tx.send(AppEventType::ObjectListRefresh);
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+        self.is_loading = false;
+    }
+
+    pub fn load_bucket_property(&self, bucket: String, property_type: BucketPropertyType) {
+        // each property is loaded independently, so the other sections and pages stay usable
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            let result = client.load_bucket_property(&bucket, property_type).await;
+            tx.send(AppEventType::CompleteLoadBucketProperty(
+                bucket,
+                property_type,
+                result,
+            ));
+        });
+    }
+
+    pub fn complete_load_bucket_property(
+        &mut self,
+        bucket: String,
+        property_type: BucketPropertyType,
+        result: Result<BucketProperty>,
+    ) {
+        if let Err(e) = &result {
+            self.handle_error(e);
+        }
+        // the page may be under the help page, or may have been closed already
+        let bucket_detail_page = self.page_stack.iter_mut().find_map(|page| match page {
+            Page::BucketDetail(page) if page.bucket_name() == bucket => Some(page),
+            _ => None,
+        });
+        if let Some(page) = bucket_detail_page {
+            page.set_property(property_type, result);
+        }
+    }
+
+    pub fn save_bucket_property_json(&self, file_name: String, json: String) {
+        let path = self.ctx.config.download_file_path(&file_name);
+        match save_binary(&path, json.as_bytes()) {
+            Ok(_) => {
+                let msg = format!("Saved to {}", path.display());
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            Err(e) => {
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+    }
+
+    fn enqueue_download(
+        &mut self,
+        object_name: &str,
+        size_byte: usize,
+        save_file_name: Option<&str>,
+        version_id: Option<String>,
+    ) {
+        let object_key = self.current_download_object_key().clone();
         let path = self
             .ctx
             .config
             .download_file_path(save_file_name.unwrap_or(object_name));
 
+        let request = TransferRequest::Download {
+            object_key,
+            version_id,
+            size_byte,
+            path,
+        };
+        self.enqueue_transfer(request, size_byte);
+    }
+
+    fn enqueue_transfer(&mut self, request: TransferRequest, total_size: usize) {
+        let msg = format!("{} queued: {}", request.kind(), request.name());
+        self.transfers.enqueue(request, total_size);
+        self.tx.send(AppEventType::NotifyInfo(msg));
+
+        self.start_queued_transfers();
+        self.update_transfers_page();
+    }
+
+    fn start_queued_transfers(&mut self) {
+        while self.transfers.running_count() < MAX_RUNNING_TRANSFERS {
+            let Some(id) = self.transfers.next_queued_id() else {
+                break;
+            };
+            self.start_transfer(id);
+        }
+    }
+
+    fn start_transfer(&mut self, id: usize) {
+        self.transfers.start(id, Instant::now());
+        let request = self.transfers.get(id).unwrap().request.clone();
+
         let (client, tx) = self.unwrap_client_tx();
-        let loading = self.handle_loading_size(size_byte, tx.clone());
-        spawn(async move {
+        let handle = spawn(async move {
+            let progress_tx = tx.clone();
+            let progress = move |n| progress_tx.send(AppEventType::TransferProgress(id, n));
+            let result = run_transfer(client, request, progress).await;
+            tx.send(AppEventType::CompleteTransfer(id, result));
+        });
+        self.transfer_handles.insert(id, handle.abort_handle());
+    }
+
+    pub fn update_transfer_progress(&mut self, id: usize, done_byte: usize) {
+        self.transfers.update_progress(id, done_byte);
+        self.update_transfers_page();
+    }
+
+    pub fn complete_transfer(&mut self, id: usize, result: Result<()>) {
+        self.transfer_handles.remove(&id);
+
+        let Some(item) = self.transfers.get(id) else {
+            return;
+        };
+        if item.status != TransferStatus::Running {
+            // canceled before the completion event arrived
+            return;
+        }
+        let request = item.request.clone();
+
+        match result {
+            Ok(()) => {
+                self.transfers.finish(id, Instant::now());
+                self.notify_transfer_completed(request);
+            }
+            Err(e) => {
+                self.transfers.fail(id, e.msg.clone(), Instant::now());
+                self.tx.send(AppEventType::NotifyError(e));
+            }
+        }
+
+        self.start_queued_transfers();
+        self.update_transfers_page();
+    }
+
+    fn notify_transfer_completed(&mut self, request: TransferRequest) {
+        match request {
+            TransferRequest::Download { path, .. } => {
+                let msg = format!(
+                    "Download completed successfully: {}",
+                    path.to_string_lossy()
+                );
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            TransferRequest::DownloadDir { path, objects, .. } => {
+                let msg = format!(
+                    "Download completed successfully: {} ({} objects)",
+                    path.to_string_lossy(),
+                    objects.len()
+                );
+                self.tx.send(AppEventType::NotifySuccess(msg));
+            }
+            TransferRequest::Upload {
+                object_key,
+                path,
+                files,
+            } => {
+                let msg = if files.len() == 1 {
+                    format!("Upload completed successfully: {}", path.to_string_lossy())
+                } else {
+                    format!(
+                        "Upload completed successfully: {} ({} files)",
+                        path.to_string_lossy(),
+                        files.len()
+                    )
+                };
+                self.tx.send(AppEventType::NotifySuccess(msg));
+
+                self.app_objects.clear_object_items_under(&object_key);
+                if let Page::ObjectList(page) = self.page_stack.current_page() {
+                    if page.current_dir_object_key() == &object_key {
+                        self.tx.send(AppEventType::ObjectListRefresh);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn retry_transfer(&mut self, id: usize) {
+        if self.transfers.retry(id) {
+            self.start_queued_transfers();
+            self.update_transfers_page();
+        }
+    }
+
+    pub fn cancel_transfer(&mut self, id: usize) {
+        if let Some(handle) = self.transfer_handles.remove(&id) {
+            // partial files are removed and multipart uploads are aborted when the aborted task is dropped
+            handle.abort();
+        }
+        self.transfers
+            .fail(id, "Canceled".to_string(), Instant::now());
+
+        self.start_queued_transfers();
+        self.update_transfers_page();
+    }
+
+    pub fn open_transfers(&mut self) {
+        let items = self.transfers.items().to_vec();
+        let transfers_page = Page::of_transfers(items, Rc::clone(&self.ctx), self.tx.clone());
+        self.page_stack.push(transfers_page);
+    }
+
+    fn update_transfers_page(&mut self) {
+        if let Page::Transfers(page) = self.page_stack.current_page_mut() {
+            page.set_items(self.transfers.items().to_vec());
+        }
+    }
+
+    fn download_object_and<F>(
+        &mut self,
+        object_name: &str,
+        size_byte: usize,
+        save_file_name: Option<&str>,
+        version_id: Option<String>,
+        f: F,
+    ) where
+        F: FnOnce(Sender, Result<RawObject>, PathBuf) + Send + 'static,
+    {
+        let object_key = self.current_download_object_key();
+
+        let bucket = object_key.bucket_name.clone();
+        let key = object_key.joined_object_path(true);
+
+        let path = self
+            .ctx
+            .config
+            .download_file_path(save_file_name.unwrap_or(object_name));
+
+        let (client, tx) = self.unwrap_client_tx();
+        let loading = self.handle_loading_size(size_byte, "downloaded", tx.clone());
+        self.spawn_cancellable(async move {
             let obj = client
                 .download_object(&bucket, &key, version_id, size_byte, loading)
                 .await;
@@ -581,7 +1731,44 @@ impl App {
         });
     }
 
-    fn handle_loading_size(&self, total_size: usize, tx: Sender) -> Box<dyn Fn(usize) + Send> {
+    fn spawn_cancellable<F>(&mut self, future: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let handle = spawn(future);
+        self.loading_handle = Some(handle.abort_handle());
+    }
+
+    pub fn cancel_loading(&mut self) {
+        let Some(handle) = self.loading_handle.take() else {
+            return;
+        };
+        if handle.is_finished() {
+            // the completion event has already been sent
+            return;
+        }
+        // partial files are removed when the aborted task is dropped
+        handle.abort();
+        self.is_loading = false;
+
+        self.tx
+            .send(AppEventType::NotifyInfo("Canceled".to_string()));
+    }
+
+    fn current_download_object_key(&self) -> &ObjectKey {
+        match self.page_stack.current_page() {
+            page @ Page::ObjectDetail(_) => page.as_object_detail().current_object_key(),
+            page @ Page::ObjectPreview(_) => page.as_object_preview().current_object_key(),
+            page => panic!("Invalid page: {:?}", page),
+        }
+    }
+
+    fn handle_loading_size(
+        &self,
+        total_size: usize,
+        verb: &'static str,
+        tx: Sender,
+    ) -> Box<dyn Fn(usize) + Send + Sync> {
         if total_size < 10_000_000 {
             return Box::new(|_| {});
         }
@@ -592,7 +1779,7 @@ impl App {
         let f = move |current| {
             let percent = (current * 100) / total_size;
             let cur_s = humansize::format_size_i(current, opt);
-            let msg = format!("{:3}% downloaded ({} out of {})", percent, cur_s, total_s);
+            let msg = format!("{:3}% {} ({} out of {})", percent, verb, cur_s, total_s);
             tx.send(AppEventType::NotifyInfo(msg));
         };
         Box::new(f)
@@ -643,7 +1830,6 @@ impl App {
             input,
             version_id,
         ));
-        self.is_loading = true;
 
         let page = self.page_stack.current_page_mut().as_mut_object_detail();
         page.close_save_dialog();
@@ -660,7 +1846,6 @@ impl App {
             input,
             version_id,
         ));
-        self.is_loading = true;
 
         let page = self.page_stack.current_page_mut().as_mut_object_preview();
         page.close_save_dialog();
@@ -671,6 +1856,28 @@ impl App {
         object_preview_page.enable_image_render();
     }
 
+    pub fn presign_object(
+        &self,
+        object_key: ObjectKey,
+        version_id: Option<String>,
+        expires_in: Duration,
+    ) {
+        let bucket = object_key.bucket_name.clone();
+        let key = object_key.joined_object_path(true);
+
+        let (client, tx) = self.unwrap_client_tx();
+        spawn(async move {
+            // signing is done locally, so this completes without a request
+            match client
+                .presign_get_object(&bucket, &key, version_id, expires_in)
+                .await
+            {
+                Ok(url) => tx.send(AppEventType::CopyToClipboard("Presigned URL".into(), url)),
+                Err(e) => tx.send(AppEventType::NotifyError(e)),
+            }
+        });
+    }
+
     pub fn copy_to_clipboard(&self, name: String, value: String) {
         match copy_to_clipboard(value) {
             Ok(_) => {
@@ -691,6 +1898,12 @@ impl App {
         self.is_loading
     }
 
+    pub fn cancelable(&self) -> bool {
+        self.loading_handle
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished())
+    }
+
     pub fn current_notification(&self) -> &Notification {
         &self.notification
     }
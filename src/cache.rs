@@ -5,11 +5,33 @@ use std::{
     io::{self, Read, Write},
     path::PathBuf,
     sync::RwLock,
+    time::Duration,
 };
 
+// the file is shared by all namespaces, but only the entries in the current namespace are visible
 pub struct SimpleStringCache {
-    pub cache: RwLock<HashMap<String, String>>,
+    pub cache: RwLock<HashMap<CacheKey, CacheEntry>>,
     pub file_path: String,
+    namespace: String,
+    ttl: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    namespace: String,
+    key: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheEntry {
+    value: String,
+    timestamp: i64, // unix time in seconds
+}
+
+impl CacheEntry {
+    fn is_expired(&self, now: i64, ttl: Duration) -> bool {
+        now - self.timestamp > ttl.as_secs() as i64
+    }
 }
 
 impl fmt::Debug for SimpleStringCache {
@@ -17,18 +39,22 @@ impl fmt::Debug for SimpleStringCache {
         let cache = self.cache.read().unwrap();
         f.debug_struct("SimpleCache")
             .field("file_path", &self.file_path)
+            .field("namespace", &self.namespace)
+            .field("ttl", &self.ttl)
             .field("cache", &*cache)
             .finish()
     }
 }
 
 impl SimpleStringCache {
-    pub fn new(file_path: PathBuf) -> SimpleStringCache {
+    pub fn new(file_path: PathBuf, namespace: String, ttl: Duration) -> SimpleStringCache {
         let cache = HashMap::new();
 
         let cache = SimpleStringCache {
             cache: RwLock::new(cache),
             file_path: file_path.to_string_lossy().into(),
+            namespace,
+            ttl,
         };
 
         cache.load_from_file().unwrap();
@@ -37,16 +63,16 @@ impl SimpleStringCache {
 
     #[allow(dead_code)]
     pub fn put(&self, key: String, value: String) -> io::Result<()> {
-        {
-            let mut cache = self.cache.write().unwrap();
-            cache.insert(key.clone(), value);
-        }
+        self.set(&key, &value);
         Ok(())
     }
 
     pub fn get(&self, key: &str) -> Option<String> {
         let cache = self.cache.read().unwrap();
-        cache.get(key).cloned()
+        cache
+            .get(&self.cache_key(key))
+            .filter(|entry| !entry.is_expired(now(), self.ttl))
+            .map(|entry| entry.value.clone())
     }
 
     fn load_from_file(&self) -> Result<(), io::Error> {
@@ -63,16 +89,20 @@ impl SimpleStringCache {
         let mut cache = self.cache.write().unwrap();
         cache.clear();
 
+        let now = now();
         for line in contents.lines() {
             if line.trim().is_empty() {
                 continue;
             }
 
-            let parts: Vec<&str> = line.split(',').collect();
-            if parts.len() == 2 {
-                cache.insert(parts[0].to_string(), parts[1].to_string());
-            } else {
-                tracing::warn!("Invalid cache file line: {}", line);
+            match parse_line(line) {
+                Some((key, entry)) => {
+                    // expired entries are dropped when the cache is written
+                    if !entry.is_expired(now, self.ttl) {
+                        cache.insert(key, entry);
+                    }
+                }
+                None => tracing::warn!("Invalid cache file line: {}", line),
             }
         }
 
@@ -88,8 +118,8 @@ impl SimpleStringCache {
             .open(&temp_file_path)?;
 
         let cache = self.cache.read().unwrap();
-        for (key, value) in &*cache {
-            writeln!(file, "{},{}", key, value)?;
+        for (key, entry) in &*cache {
+            writeln!(file, "{}", format_line(key, entry))?;
         }
 
         std::fs::rename(temp_file_path, &self.file_path)?;
@@ -98,6 +128,88 @@ impl SimpleStringCache {
 
     pub fn set(&self, key: &str, value: &str) {
         let mut cache = self.cache.write().unwrap();
-        cache.insert(key.to_string(), value.to_string());
+        let entry = CacheEntry {
+            value: value.to_string(),
+            timestamp: now(),
+        };
+        cache.insert(self.cache_key(key), entry);
+    }
+
+    fn cache_key(&self, key: &str) -> CacheKey {
+        CacheKey {
+            namespace: self.namespace.clone(),
+            key: key.to_string(),
+        }
+    }
+}
+
+// e.g. http://localhost:9000|minio
+pub fn build_namespace(endpoint_url: Option<&str>, profile: Option<&str>) -> String {
+    format!(
+        "{}|{}",
+        endpoint_url.unwrap_or_default(),
+        profile.unwrap_or_default()
+    )
+}
+
+fn now() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+// namespace,key,value,timestamp
+// the namespace is the first part because it may contain commas
+fn parse_line(line: &str) -> Option<(CacheKey, CacheEntry)> {
+    let mut parts = line.rsplitn(4, ',');
+    let timestamp = parts.next()?.parse().ok()?;
+    let value = parts.next()?.to_string();
+    let key = parts.next()?.to_string();
+    let namespace = parts.next()?.to_string();
+    Some((CacheKey { namespace, key }, CacheEntry { value, timestamp }))
+}
+
+fn format_line(key: &CacheKey, entry: &CacheEntry) -> String {
+    format!(
+        "{},{},{},{}",
+        key.namespace, key.key, entry.value, entry.timestamp
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        let line = "http://localhost:9000|minio,bucket1,us-east-1,1700000000";
+        let (key, entry) = parse_line(line).unwrap();
+        assert_eq!(key.namespace, "http://localhost:9000|minio");
+        assert_eq!(key.key, "bucket1");
+        assert_eq!(entry.value, "us-east-1");
+        assert_eq!(entry.timestamp, 1700000000);
+        assert_eq!(format_line(&key, &entry), line);
+
+        // the format before entries were namespaced
+        assert_eq!(parse_line("bucket1,us-east-1"), None);
+    }
+
+    #[test]
+    fn test_cache_entry_is_expired() {
+        let entry = CacheEntry {
+            value: "us-east-1".to_string(),
+            timestamp: 1000,
+        };
+        let ttl = Duration::from_secs(100);
+        assert!(!entry.is_expired(1000, ttl));
+        assert!(!entry.is_expired(1100, ttl));
+        assert!(entry.is_expired(1101, ttl));
+    }
+
+    #[test]
+    fn test_build_namespace() {
+        assert_eq!(build_namespace(None, None), "|");
+        assert_eq!(
+            build_namespace(Some("http://localhost:9000"), Some("minio")),
+            "http://localhost:9000|minio"
+        );
     }
 }
@@ -1,20 +1,68 @@
-use std::fmt::Debug;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+    future::Future,
+    io::SeekFrom,
+    ops::Range,
+    path::{Path, PathBuf},
+    sync::RwLock,
+    time::Duration,
+};
 
 use aws_config::{default_provider::region, meta::region::RegionProviderChain, BehaviorVersion};
 use aws_sdk_s3::{
-    config::Region, error::SdkError, operation::list_objects_v2::ListObjectsV2Output,
+    config::{http::HttpResponse, Region},
+    error::{ProvideErrorMetadata, SdkError},
+    operation::{
+        create_multipart_upload::CreateMultipartUploadOutput, get_object::GetObjectError,
+        list_object_versions::ListObjectVersionsOutput, list_objects_v2::ListObjectsV2Output,
+    },
+    presigning::PresigningConfig,
+    primitives::{ByteStream, DateTime, DateTimeFormat, Length},
+    types::{
+        AccessControlPolicy, ChecksumMode, CompletedMultipartUpload, CompletedPart, Delete,
+        GlacierJobParameters, Grant, LifecycleRule, MetadataDirective, ObjectIdentifier,
+        OptionalObjectAttributes, Owner, Permission, RestoreRequest, StorageClass, Tag, Tagging,
+        TaggingDirective, Tier, Type,
+    },
 };
 use chrono::TimeZone;
+use tokio::{
+    io::{AsyncSeekExt, AsyncWriteExt},
+    sync::mpsc,
+    task::JoinSet,
+};
 
 use crate::{
-    cache::SimpleStringCache,
-    config::Config,
+    cache::{build_namespace, SimpleStringCache},
+    config::{CacheConfig, Config, TransferConfig},
     error::{AppError, Result},
-    object::{BucketItem, FileDetail, FileVersion, ObjectItem, RawObject},
+    file::{
+        create_dirs, download_resume_path, download_temp_path, load_download_resume_state,
+        save_download_resume_state, DownloadResumeState,
+    },
+    object::{
+        BucketItem, BucketProperty, BucketPropertyField, BucketPropertyType, CopyMode, CopyTarget,
+        DeleteMarker, FileDetail, FileVersion, ObjectItem, ObjectMetadata, ObjectSummary,
+        ObjectTag, ObjectVersions, RawObject, StorageClassTarget, TimeTravelObjects,
+    },
 };
 
 const DELIMITER: &str = "/";
 
+const DELETE_OBJECTS_BATCH_SIZE: usize = 1000;
+
+const MULTIPART_UPLOAD_THRESHOLD: usize = 16 * 1024 * 1024;
+const MULTIPART_UPLOAD_PART_SIZE: usize = 8 * 1024 * 1024;
+const MULTIPART_MAX_PARTS: usize = 10_000;
+
+const COPY_OBJECT_MAX_SIZE: usize = 5 * 1024 * 1024 * 1024;
+const MULTIPART_COPY_PART_SIZE: usize = 512 * 1024 * 1024;
+
+const DOWNLOAD_MIN_PART_SIZE: usize = 1024 * 1024;
+
+const BUCKET_REGION_LOOKUP_CONCURRENCY: usize = 16;
+
 pub enum AddressingStyle {
     Auto,
     Path,          // https://s3.region.amazonaws.com/bucket/key
@@ -34,7 +82,12 @@ impl AddressingStyle {
 pub struct Client {
     client: aws_sdk_s3::Client,
     region: String,
+    // list buckets in every region, not only in the client's region
+    all_regions: bool,
+    // clients for buckets outside the client's region, created on first use
+    regional_clients: RwLock<HashMap<String, aws_sdk_s3::Client>>,
     bucket_region_cache: SimpleStringCache,
+    transfer_config: TransferConfig,
 }
 
 impl Debug for Client {
@@ -44,12 +97,16 @@ impl Debug for Client {
 }
 
 impl Client {
+    #[allow(clippy::too_many_arguments)]
     pub async fn new(
         region: Option<String>,
         endpoint_url: Option<String>,
         profile: Option<String>,
         default_region_fallback: String,
         addressing_style: AddressingStyle,
+        all_regions: bool,
+        transfer_config: TransferConfig,
+        cache_config: CacheConfig,
     ) -> Client {
         let mut region_builder = region::Builder::default();
         if let Some(profile) = &profile {
@@ -68,6 +125,8 @@ impl Client {
             config_loader = config_loader.profile_name(profile);
         }
         let sdk_config = config_loader.load().await;
+        // the endpoint and profile may also be given by the environment or the shared config
+        let profile = profile.or_else(|| std::env::var("AWS_PROFILE").ok());
 
         let config_builder = aws_sdk_s3::config::Builder::from(&sdk_config)
             .force_path_style(addressing_style.to_force_path_style(&endpoint_url));
@@ -76,12 +135,19 @@ impl Client {
         let client = aws_sdk_s3::Client::from_conf(config);
         let region = sdk_config.region().unwrap().to_string();
 
-        let bucket_region_cache = SimpleStringCache::new(Config::cache_file_path().unwrap());
+        let bucket_region_cache = SimpleStringCache::new(
+            Config::cache_file_path().unwrap(),
+            build_namespace(sdk_config.endpoint_url(), profile.as_deref()),
+            Duration::from_secs(cache_config.bucket_region_ttl_sec),
+        );
 
         Client {
             client,
             region,
+            all_regions,
+            regional_clients: RwLock::new(HashMap::new()),
             bucket_region_cache,
+            transfer_config,
         }
     }
 
@@ -89,49 +155,161 @@ impl Client {
         &self.region
     }
 
-    pub async fn load_all_buckets(&self) -> Result<Vec<BucketItem>> {
+    pub fn all_regions(&self) -> bool {
+        self.all_regions
+    }
+
+    // requests for a bucket must be sent to the region where the bucket is located.
+    // use send_to_bucket instead unless a preceding request of the same operation has already reached the bucket.
+    fn bucket_client(&self, bucket: &str) -> aws_sdk_s3::Client {
+        let region = self.bucket_region(bucket);
+        if !self.all_regions || region == self.region {
+            return self.client.clone();
+        }
+
+        if let Some(client) = self.regional_clients.read().unwrap().get(&region) {
+            return client.clone();
+        }
+        let config = self
+            .client
+            .config()
+            .to_builder()
+            .region(Region::new(region.clone()))
+            .build();
+        let client = aws_sdk_s3::Client::from_conf(config);
+        self.regional_clients
+            .write()
+            .unwrap()
+            .insert(region, client.clone());
+        client
+    }
+
+    // sends a request with the client for the bucket's region.
+    // if the cached region turns out to be stale, e.g. the bucket has been recreated in another region,
+    // the region is fetched again and the request is retried once.
+    async fn send_to_bucket<T, E, F, Fut>(
+        &self,
+        bucket: &str,
+        send: F,
+    ) -> std::result::Result<T, SdkError<E, HttpResponse>>
+    where
+        E: ProvideErrorMetadata,
+        F: Fn(aws_sdk_s3::Client) -> Fut,
+        Fut: Future<Output = std::result::Result<T, SdkError<E, HttpResponse>>>,
+    {
+        match send(self.bucket_client(bucket)).await {
+            Err(e) if is_region_mismatch_error(&e) => {
+                if let Err(refresh_err) = self.refresh_bucket_region(bucket).await {
+                    tracing::warn!("Failed to refresh bucket region: {:?}", refresh_err);
+                    return Err(e);
+                }
+                send(self.bucket_client(bucket)).await
+            }
+            result => result,
+        }
+    }
+
+    // the region is known once the bucket location has been fetched
+    fn bucket_region(&self, bucket: &str) -> String {
+        if !self.all_regions {
+            return self.region.clone();
+        }
+        self.bucket_region_cache
+            .get(bucket)
+            .map(|region| normalize_bucket_region(&region))
+            .unwrap_or_else(|| self.region.clone())
+    }
+
+    pub async fn load_all_buckets<F>(&self, f: F) -> Result<Vec<BucketItem>>
+    where
+        F: Fn(usize, usize),
+    {
         let list_buckets_result = self.client.list_buckets().send().await;
         let list_buckets_output =
             list_buckets_result.map_err(|e| AppError::new("Failed to load buckets", e))?;
 
-        let buckets: Vec<BucketItem> = list_buckets_output
+        let buckets: Vec<String> = list_buckets_output
             .buckets()
             .iter()
-            .map(|bucket| {
-                let bucket_name = bucket.name().unwrap().to_string();
-                let s3_uri = build_bucket_s3_uri(&bucket_name);
-                let arn = build_bucket_arn(&bucket_name);
-                let object_url = build_bucket_url(&self.region, &bucket_name);
-                BucketItem {
-                    name: bucket_name,
-                    s3_uri,
-                    arn,
-                    object_url,
-                    prefix: None,
-                }
-            })
+            .map(|bucket| bucket.name().unwrap().to_string())
             .collect();
 
         if buckets.is_empty() {
             return Err(AppError::msg("No buckets found"));
         }
 
+        let result = self.resolve_bucket_regions(&buckets, f).await;
+        // write even if a lookup failed so that the resolved regions are not requested again
+        self.bucket_region_cache.write_cache().unwrap();
+        let regions = result?;
+
         let mut buckets_in_region: Vec<BucketItem> = Vec::new();
-        for bucket in buckets {
-            let region = self.get_bucket_region(&bucket.name).await?;
-            if region == self.region {
+        for bucket_name in buckets {
+            let region = normalize_bucket_region(&regions[&bucket_name]);
+            if self.all_regions || region == self.region {
+                let bucket = build_bucket_item(bucket_name, None, &region, self.all_regions);
                 buckets_in_region.push(bucket);
             }
         }
 
-        self.bucket_region_cache.write_cache().unwrap();
-
         Ok(buckets_in_region)
     }
 
+    // looks up the regions of the buckets not in the cache concurrently, f is called with (resolved, total)
+    async fn resolve_bucket_regions<F>(
+        &self,
+        buckets: &[String],
+        f: F,
+    ) -> Result<HashMap<String, String>>
+    where
+        F: Fn(usize, usize),
+    {
+        let total = buckets.len();
+        let mut regions = HashMap::new();
+        let mut uncached = Vec::new();
+        for bucket in buckets {
+            match self.bucket_region_cache.get(bucket) {
+                Some(region) => {
+                    regions.insert(bucket.clone(), region);
+                }
+                None => uncached.push(bucket.clone()),
+            }
+        }
+        f(regions.len(), total);
+
+        let mut uncached = uncached.into_iter();
+        let mut spawn_next = |set: &mut JoinSet<(String, Result<String>)>| {
+            if let Some(bucket) = uncached.next() {
+                let client = self.client.clone();
+                set.spawn(async move {
+                    let result = request_bucket_location(client, bucket.clone()).await;
+                    (bucket, result)
+                });
+            }
+        };
+
+        // dropping the set aborts the remaining lookups when any of them fails
+        let mut set = JoinSet::new();
+        for _ in 0..BUCKET_REGION_LOOKUP_CONCURRENCY {
+            spawn_next(&mut set);
+        }
+
+        while let Some(result) = set.join_next().await {
+            let (bucket, result) =
+                result.map_err(|e| AppError::new("Failed to resolve bucket region", e))?;
+            let region = result?;
+            self.bucket_region_cache.set(&bucket, &region);
+            regions.insert(bucket, region);
+            f(regions.len(), total);
+            spawn_next(&mut set);
+        }
+
+        Ok(regions)
+    }
+
     pub async fn get_bucket_region(&self, bucket: &str) -> Result<String> {
         match self.fetch_bucket_location(bucket).await {
-            Ok(region) => Ok(region),
+            Ok(region) => Ok(normalize_bucket_region(&region)),
             Err(e) => Err(e),
         }
     }
@@ -142,30 +320,17 @@ impl Client {
         prefix: Option<String>,
         region: Option<String>,
     ) -> Result<BucketItem> {
-        if let Some(region) = region {
+        let region = if let Some(region) = region {
             self.bucket_region_cache.set(name, &region);
+            region
         } else {
             let region = self.get_bucket_region(name).await?;
-            if region != self.region {
-                return Err(AppError::msg(format!(
-                    "Bucket '{}' is in region '{}', expected '{}'",
-                    name, region, self.region
-                )));
-            }
-        }
+            self.check_bucket_region(name, &region)?;
+            region
+        };
         self.bucket_region_cache.write_cache().unwrap();
 
-        let s3_uri = build_bucket_s3_uri(name);
-        let arn = build_bucket_arn(name);
-        let object_url = build_bucket_url(&self.region, name);
-
-        let bucket = BucketItem {
-            name: name.to_string(),
-            s3_uri,
-            arn,
-            object_url,
-            prefix,
-        };
+        let bucket = build_bucket_item(name.to_string(), prefix, &region, self.all_regions);
         Ok(bucket)
     }
 
@@ -178,19 +343,22 @@ impl Client {
 
         while is_truncated {
             let result = self
-                .client
-                .list_objects_v2()
-                .bucket(bucket)
-                .prefix(prefix)
-                .delimiter(DELIMITER)
-                .set_continuation_token(token)
-                .send()
+                .send_to_bucket(bucket, |client| {
+                    client
+                        .list_objects_v2()
+                        .bucket(bucket)
+                        .prefix(prefix)
+                        .delimiter(DELIMITER)
+                        .set_continuation_token(token.clone())
+                        .send()
+                })
                 .await;
 
             let output = result.map_err(|e| AppError::new("Failed to load objects", e))?;
 
-            let dirs = objects_output_to_dirs(&self.region, bucket, &output);
-            let files = objects_output_to_files(&self.region, bucket, &output);
+            let region = self.bucket_region(bucket);
+            let dirs = objects_output_to_dirs(&region, bucket, &output);
+            let files = objects_output_to_files(&region, bucket, &output);
 
             dirs_vec.push(dirs);
             files_vec.push(files);
@@ -204,20 +372,78 @@ impl Client {
         Ok(di.chain(fi).collect())
     }
 
+    pub async fn list_all_objects(&self, bucket: &str, prefix: &str) -> Result<Vec<ObjectSummary>> {
+        let mut objects = Vec::new();
+
+        let mut token: Option<String> = None;
+        let mut is_truncated = true;
+
+        while is_truncated {
+            let result = self
+                .send_to_bucket(bucket, |client| {
+                    client
+                        .list_objects_v2()
+                        .bucket(bucket)
+                        .prefix(prefix)
+                        .optional_object_attributes(OptionalObjectAttributes::RestoreStatus)
+                        .set_continuation_token(token.clone())
+                        .send()
+                })
+                .await;
+
+            let output = result.map_err(|e| AppError::new("Failed to load objects", e))?;
+
+            objects.extend(output.contents().iter().map(|obj| {
+                ObjectSummary {
+                    key: obj.key().unwrap().to_owned(),
+                    size_byte: obj.size().unwrap_or_default() as usize,
+                    // some S3 compatible storages omit the storage class
+                    storage_class: obj
+                        .storage_class()
+                        .map_or("STANDARD", |c| c.as_str())
+                        .into(),
+                    restored: obj.restore_status().is_some_and(|status| {
+                        !status.is_restore_in_progress().unwrap_or_default()
+                            && status.restore_expiry_date().is_some()
+                    }),
+                }
+            }));
+
+            is_truncated = output.is_truncated().unwrap_or(false);
+            token = output.next_continuation_token().map(String::from);
+        }
+
+        Ok(objects)
+    }
+
     pub async fn load_object_detail(
         &self,
         bucket: &str,
         key: &str,
+        version_id: Option<&str>,
         name: &str,
         size_byte: usize,
     ) -> Result<FileDetail> {
-        let result = self
-            .client
-            .head_object()
-            .bucket(bucket)
-            .key(key)
-            .send()
-            .await;
+        let send = |checksum_mode: Option<ChecksumMode>| {
+            self.send_to_bucket(bucket, move |client| {
+                client
+                    .head_object()
+                    .bucket(bucket)
+                    .key(key)
+                    .set_version_id(version_id.map(String::from))
+                    .set_checksum_mode(checksum_mode.clone())
+                    .send()
+            })
+        };
+        // checksum mode requires extra permissions (e.g. kms:Decrypt) and is not supported by some S3-compatible storages,
+        // so retry without it to show the rest of the detail
+        let result = match send(Some(ChecksumMode::Enabled)).await {
+            Err(e) if is_checksum_mode_error(&e) => {
+                tracing::warn!("Failed to load object detail with checksum mode: {:?}", e);
+                send(None).await
+            }
+            result => result,
+        };
         let output = result.map_err(|e| AppError::new("Failed to load object detail", e))?;
 
         let name = name.to_owned();
@@ -231,7 +457,23 @@ impl Client {
         let key = key.to_owned();
         let s3_uri = build_object_s3_uri(bucket, &key);
         let arn = build_object_arn(bucket, &key);
-        let object_url = build_object_url(&self.region, bucket, &key);
+        let object_url = build_object_url(&self.bucket_region(bucket), bucket, &key);
+
+        let mut user_metadata: Vec<(String, String)> = output
+            .metadata()
+            .map(|m| m.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+            .unwrap_or_default();
+        user_metadata.sort();
+        let checksums = [
+            ("CRC32", output.checksum_crc32()),
+            ("CRC32C", output.checksum_crc32_c()),
+            ("SHA1", output.checksum_sha1()),
+            ("SHA256", output.checksum_sha256()),
+        ]
+        .into_iter()
+        .filter_map(|(algorithm, value)| value.map(|v| (algorithm.into(), v.into())))
+        .collect();
+
         Ok(FileDetail {
             name,
             size_byte,
@@ -243,36 +485,378 @@ impl Client {
             s3_uri,
             arn,
             object_url,
+            cache_control: output.cache_control().unwrap_or_default().into(),
+            content_encoding: output.content_encoding().unwrap_or_default().into(),
+            content_disposition: output.content_disposition().unwrap_or_default().into(),
+            expires: output.expires_string().unwrap_or_default().into(),
+            user_metadata,
+            server_side_encryption: output
+                .server_side_encryption()
+                .map_or("", |s| s.as_str())
+                .into(),
+            sse_kms_key_id: output.ssekms_key_id().unwrap_or_default().into(),
+            checksums,
+            object_lock_mode: output.object_lock_mode().map_or("", |m| m.as_str()).into(),
+            object_lock_retain_until: output.object_lock_retain_until_date().map(convert_datetime),
+            object_lock_legal_hold: output
+                .object_lock_legal_hold_status()
+                .map_or("", |s| s.as_str())
+                .into(),
+            replication_status: output
+                .replication_status()
+                .map_or("", |s| s.as_str())
+                .into(),
+            restore: output.restore().unwrap_or_default().into(),
         })
     }
 
-    pub async fn load_object_versions(&self, bucket: &str, key: &str) -> Result<Vec<FileVersion>> {
+    // loads a page of versions of the key, continuing from the version ID marker if specified
+    pub async fn load_object_versions(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id_marker: Option<String>,
+    ) -> Result<ObjectVersions> {
+        // the key marker is required to specify the version ID marker
+        let key_marker = version_id_marker.as_ref().map(|_| key);
         let result = self
-            .client
-            .list_object_versions()
-            .bucket(bucket)
-            .prefix(key)
-            .send()
+            .send_to_bucket(bucket, |client| {
+                client
+                    .list_object_versions()
+                    .bucket(bucket)
+                    .prefix(key)
+                    .set_key_marker(key_marker.map(String::from))
+                    .set_version_id_marker(version_id_marker.clone())
+                    .send()
+            })
             .await;
         let output = result.map_err(|e| AppError::new("Failed to load object versions", e))?;
 
-        let versions = output
-            .versions()
-            .iter()
+        Ok(object_versions_output_to_versions(key, &output))
+    }
+
+    // returns files under the prefix whose latest version is a delete marker
+    pub async fn load_deleted_objects(
+        &self,
+        bucket: &str,
+        prefix: &str,
+    ) -> Result<Vec<ObjectItem>> {
+        let mut items = Vec::new();
+
+        let mut key_marker: Option<String> = None;
+        let mut version_id_marker: Option<String> = None;
+        let mut is_truncated = true;
+
+        while is_truncated {
+            let result = self
+                .send_to_bucket(bucket, |client| {
+                    client
+                        .list_object_versions()
+                        .bucket(bucket)
+                        .prefix(prefix)
+                        .delimiter(DELIMITER)
+                        .set_key_marker(key_marker.clone())
+                        .set_version_id_marker(version_id_marker.clone())
+                        .send()
+                })
+                .await;
+            let output = result.map_err(|e| AppError::new("Failed to load deleted objects", e))?;
+
+            let files = output
+                .delete_markers()
+                .iter()
+                .filter(|m| m.is_latest().unwrap_or(false))
+                .map(|m| {
+                    let key = m.key().unwrap().to_owned();
+                    let name = parse_path(&key, false).last().unwrap().to_owned();
+                    let last_modified = convert_datetime(m.last_modified().unwrap());
+                    let s3_uri = build_object_s3_uri(bucket, &key);
+                    let arn = build_object_arn(bucket, &key);
+                    let object_url = build_object_url(&self.bucket_region(bucket), bucket, &key);
+                    ObjectItem::File {
+                        name,
+                        size_byte: 0,
+                        last_modified,
+                        key,
+                        s3_uri,
+                        arn,
+                        object_url,
+                        e_tag: String::new(),
+                    }
+                });
+            items.extend(files);
+
+            is_truncated = output.is_truncated().unwrap_or(false);
+            key_marker = output.next_key_marker().map(String::from);
+            version_id_marker = output.next_version_id_marker().map(String::from);
+        }
+
+        Ok(items)
+    }
+
+    // reconstructs the objects under the prefix as they existed at the given time.
+    // all versions under the prefix are listed recursively, so that only the folders
+    // which contained any object at that time are listed.
+    pub async fn load_objects_at(
+        &self,
+        bucket: &str,
+        prefix: &str,
+        at: chrono::DateTime<chrono::Local>,
+    ) -> Result<TimeTravelObjects> {
+        let mut entries = Vec::new();
+
+        let mut key_marker: Option<String> = None;
+        let mut version_id_marker: Option<String> = None;
+        let mut is_truncated = true;
+
+        while is_truncated {
+            let result = self
+                .send_to_bucket(bucket, |client| {
+                    client
+                        .list_object_versions()
+                        .bucket(bucket)
+                        .prefix(prefix)
+                        .set_key_marker(key_marker.clone())
+                        .set_version_id_marker(version_id_marker.clone())
+                        .send()
+                })
+                .await;
+            let output = result.map_err(|e| AppError::new("Failed to load object versions", e))?;
+
+            entries.extend(output.versions().iter().map(|v| VersionEntry {
+                key: v.key().unwrap().to_owned(),
+                version_id: v.version_id().unwrap().to_owned(),
+                last_modified: convert_datetime(v.last_modified().unwrap()),
+                size_byte: v.size().unwrap_or_default() as usize,
+                e_tag: v.e_tag().unwrap_or_default().trim_matches('"').to_string(),
+                is_latest: v.is_latest().unwrap_or(false),
+                is_delete_marker: false,
+            }));
+            entries.extend(output.delete_markers().iter().map(|m| VersionEntry {
+                key: m.key().unwrap().to_owned(),
+                version_id: m.version_id().unwrap().to_owned(),
+                last_modified: convert_datetime(m.last_modified().unwrap()),
+                size_byte: 0,
+                e_tag: String::new(),
+                is_latest: m.is_latest().unwrap_or(false),
+                is_delete_marker: true,
+            }));
+
+            is_truncated = output.is_truncated().unwrap_or(false);
+            key_marker = output.next_key_marker().map(String::from);
+            version_id_marker = output.next_version_id_marker().map(String::from);
+        }
+
+        let (dir_keys, current) = split_dirs_and_files(prefix, current_versions_at(entries, at));
+        let dirs = dir_keys.into_iter().map(|key| {
+            let name = parse_path(&key, true).last().unwrap().to_owned();
+            let s3_uri = build_object_s3_uri(bucket, &key);
+            let object_url = build_object_url(&self.bucket_region(bucket), bucket, &key);
+            ObjectItem::Dir {
+                name,
+                key,
+                s3_uri,
+                object_url,
+            }
+        });
+
+        let mut version_ids = HashMap::new();
+        let files: Vec<ObjectItem> = current
+            .into_iter()
             .map(|v| {
-                let version_id = v.version_id().unwrap().to_string(); // returns "null" if empty...
-                let size_byte = v.size().unwrap() as usize;
-                let last_modified = convert_datetime(v.last_modified().unwrap());
-                let is_latest = v.is_latest().unwrap();
-                FileVersion {
-                    version_id,
-                    size_byte,
-                    last_modified,
-                    is_latest,
+                let name = parse_path(&v.key, false).last().unwrap().to_owned();
+                let s3_uri = build_object_s3_uri(bucket, &v.key);
+                let arn = build_object_arn(bucket, &v.key);
+                let object_url = build_object_url(&self.bucket_region(bucket), bucket, &v.key);
+                version_ids.insert(v.key.clone(), v.version_id);
+                ObjectItem::File {
+                    name,
+                    size_byte: v.size_byte,
+                    last_modified: v.last_modified,
+                    key: v.key,
+                    s3_uri,
+                    arn,
+                    object_url,
+                    e_tag: v.e_tag,
                 }
             })
             .collect();
-        Ok(versions)
+
+        Ok(TimeTravelObjects {
+            items: dirs.chain(files).collect(),
+            version_ids,
+        })
+    }
+
+    // returns the delete markers which are the latest versions of the keys under the prefix
+    pub async fn list_latest_delete_markers(
+        &self,
+        bucket: &str,
+        prefix: &str,
+    ) -> Result<Vec<DeleteMarker>> {
+        let mut markers = Vec::new();
+
+        let mut key_marker: Option<String> = None;
+        let mut version_id_marker: Option<String> = None;
+        let mut is_truncated = true;
+
+        while is_truncated {
+            let result = self
+                .send_to_bucket(bucket, |client| {
+                    client
+                        .list_object_versions()
+                        .bucket(bucket)
+                        .prefix(prefix)
+                        .set_key_marker(key_marker.clone())
+                        .set_version_id_marker(version_id_marker.clone())
+                        .send()
+                })
+                .await;
+            let output = result.map_err(|e| AppError::new("Failed to load deleted objects", e))?;
+
+            markers.extend(
+                output
+                    .delete_markers()
+                    .iter()
+                    .filter(|m| m.is_latest().unwrap_or(false))
+                    .map(|m| DeleteMarker {
+                        key: m.key().unwrap().to_owned(),
+                        version_id: m.version_id().unwrap().to_owned(),
+                        last_modified: convert_datetime(m.last_modified().unwrap()),
+                    }),
+            );
+
+            is_truncated = output.is_truncated().unwrap_or(false);
+            key_marker = output.next_key_marker().map(String::from);
+            version_id_marker = output.next_version_id_marker().map(String::from);
+        }
+
+        Ok(markers)
+    }
+
+    // removes the latest delete marker of the key, so that the previous version becomes current
+    pub async fn undelete_object(&self, bucket: &str, key: &str) -> Result<()> {
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client
+                    .list_object_versions()
+                    .bucket(bucket)
+                    .prefix(key)
+                    .send()
+            })
+            .await;
+        let output = result.map_err(|e| AppError::new("Failed to load object versions", e))?;
+
+        let latest_delete_marker = output
+            .delete_markers()
+            .iter()
+            .find(|m| m.key() == Some(key) && m.is_latest().unwrap_or(false));
+        let Some(version_id) = latest_delete_marker.and_then(|m| m.version_id()) else {
+            return Err(AppError::msg(format!("The object is not deleted: {}", key)));
+        };
+
+        self.send_to_bucket(bucket, |client| {
+            client
+                .delete_object()
+                .bucket(bucket)
+                .key(key)
+                .version_id(version_id)
+                .send()
+        })
+        .await
+        .map_err(|e| AppError::new("Failed to undelete object", e))?;
+        Ok(())
+    }
+
+    pub async fn load_object_tags(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+    ) -> Result<Vec<ObjectTag>> {
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client
+                    .get_object_tagging()
+                    .bucket(bucket)
+                    .key(key)
+                    .set_version_id(version_id.clone())
+                    .send()
+            })
+            .await;
+        let output = result.map_err(|e| AppError::new("Failed to load object tags", e))?;
+
+        let tags = output
+            .tag_set()
+            .iter()
+            .map(|t| ObjectTag {
+                key: t.key().into(),
+                value: t.value().into(),
+            })
+            .collect();
+        Ok(tags)
+    }
+
+    pub async fn put_object_tags(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+        tags: &[ObjectTag],
+    ) -> Result<()> {
+        let tag_set = tags
+            .iter()
+            .map(|t| Tag::builder().key(&t.key).value(&t.value).build())
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| AppError::new("Failed to build object tags", e))?;
+        let tagging = Tagging::builder()
+            .set_tag_set(Some(tag_set))
+            .build()
+            .map_err(|e| AppError::new("Failed to build object tags", e))?;
+
+        self.send_to_bucket(bucket, |client| {
+            client
+                .put_object_tagging()
+                .bucket(bucket)
+                .key(key)
+                .set_version_id(version_id.clone())
+                .tagging(tagging.clone())
+                .send()
+        })
+        .await
+        .map_err(|e| AppError::new("Failed to save object tags", e))?;
+        Ok(())
+    }
+
+    pub async fn restore_object(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+        days: i32,
+        tier: &str,
+    ) -> Result<()> {
+        let job_parameters = GlacierJobParameters::builder()
+            .tier(Tier::from(tier))
+            .build()
+            .map_err(|e| AppError::new("Failed to build restore request", e))?;
+        let restore_request = RestoreRequest::builder()
+            .days(days)
+            .glacier_job_parameters(job_parameters)
+            .build();
+
+        self.send_to_bucket(bucket, |client| {
+            client
+                .restore_object()
+                .bucket(bucket)
+                .key(key)
+                .set_version_id(version_id.clone())
+                .restore_request(restore_request.clone())
+                .send()
+        })
+        .await
+        .map_err(|e| AppError::new("Failed to restore object", e))?;
+        Ok(())
     }
 
     pub async fn download_object<F>(
@@ -286,13 +870,27 @@ impl Client {
     where
         F: Fn(usize),
     {
-        let mut request = self.client.get_object().bucket(bucket).key(key);
-        if let Some(version_id) = version_id {
-            request = request.version_id(version_id);
+        if size_byte > self.download_part_size() {
+            let mut progress = DownloadProgress::default();
+            let sink = RangeSink::Memory;
+            let parts = self
+                .download_ranges(bucket, key, version_id, size_byte, sink, &mut progress, f)
+                .await?;
+            let bytes = parts.into_iter().flat_map(|part| part.bytes).collect();
+            return Ok(RawObject { bytes });
         }
 
-        let result = request.send().await;
-        let output = result.map_err(|e| AppError::new("Failed to download object", e))?;
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client
+                    .get_object()
+                    .bucket(bucket)
+                    .key(key)
+                    .set_version_id(version_id.clone())
+                    .send()
+            })
+            .await;
+        let output = result.map_err(download_error)?;
 
         let mut bytes: Vec<u8> = Vec::with_capacity(size_byte);
         let mut stream = output.body;
@@ -315,76 +913,1409 @@ impl Client {
         Ok(RawObject { bytes })
     }
 
-    pub fn open_management_console_buckets(&self) -> Result<()> {
-        let path = format!(
-            "https://s3.console.aws.amazon.com/s3/buckets?region={}",
-            self.region
-        );
-        open::that(path).map_err(AppError::error)
-    }
+    pub async fn download_object_to_file<F>(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+        size_byte: usize,
+        path: &Path,
+        f: F,
+    ) -> Result<()>
+    where
+        F: Fn(usize),
+    {
+        create_dirs(path)?;
+        let mut temp_file = TempFileGuard::new(download_temp_path(path));
+        let resume_path = download_resume_path(path);
 
-    pub fn open_management_console_list(&self, bucket: &str, prefix: &str) -> Result<()> {
-        let path = format!(
-            "https://s3.console.aws.amazon.com/s3/buckets/{}?region={}&prefix={}",
-            bucket, self.region, prefix
-        );
-        open::that(path).map_err(AppError::error)
-    }
+        let resume_state = self
+            .load_resume_state(
+                bucket,
+                key,
+                version_id.clone(),
+                &temp_file.path,
+                &resume_path,
+            )
+            .await;
+        // saved again only if this download is interrupted too
+        let _ = std::fs::remove_file(&resume_path);
+        let mut progress = DownloadProgress::from(resume_state);
 
-    pub fn open_management_console_object(&self, bucket: &str, prefix: &str) -> Result<()> {
-        let path = format!(
-            "https://s3.console.aws.amazon.com/s3/object/{}?region={}&prefix={}",
-            bucket, self.region, prefix
-        );
-        open::that(path).map_err(AppError::error)
-    }
+        let result = if size_byte > self.download_part_size() {
+            let path = &temp_file.path;
+            self.download_ranges_to_file(bucket, key, version_id, size_byte, path, &mut progress, f)
+                .await
+        } else {
+            self.download_body_to_file(bucket, key, version_id, &temp_file.path, &mut progress, f)
+                .await
+        };
 
-    pub async fn fetch_bucket_location(&self, bucket: &str) -> Result<String> {
-        if let Some(region) = self.bucket_region_cache.get(bucket) {
-            return Ok(region);
+        if let Err(e) = result {
+            if let Some(state) = progress.resume_state() {
+                if save_download_resume_state(&resume_path, &state).is_ok() {
+                    temp_file.keep();
+                }
+            }
+            return Err(e);
         }
 
-        let result = self
-            .client
-            .get_bucket_location()
-            .bucket(bucket)
-            .send()
+        tokio::fs::rename(&temp_file.path, path)
+            .await
+            .map_err(|e| AppError::new("Failed to rename file", e))
+    }
+
+    // returns the saved state only if the partial file is still usable for the current object
+    async fn load_resume_state(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+        temp_path: &Path,
+        resume_path: &Path,
+    ) -> Option<DownloadResumeState> {
+        let state = load_download_resume_state(resume_path)?;
+        let metadata = tokio::fs::metadata(temp_path).await.ok()?;
+        if (metadata.len() as usize) < state.offset {
+            return None;
+        }
+        let output = self
+            .send_to_bucket(bucket, |client| {
+                client
+                    .head_object()
+                    .bucket(bucket)
+                    .key(key)
+                    .set_version_id(version_id.clone())
+                    .send()
+            })
+            .await
+            .ok()?;
+        (output.e_tag() == Some(state.e_tag.as_str())).then_some(state)
+    }
+
+    async fn download_body_to_file<F>(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+        path: &Path,
+        progress: &mut DownloadProgress,
+        f: F,
+    ) -> Result<()>
+    where
+        F: Fn(usize),
+    {
+        let range = (progress.offset > 0).then(|| format!("bytes={}-", progress.offset));
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client
+                    .get_object()
+                    .bucket(bucket)
+                    .key(key)
+                    .set_version_id(version_id.clone())
+                    .set_range(range.clone())
+                    .send()
+            })
+            .await;
+        let output = result.map_err(download_error)?;
+        progress.check_e_tag(output.e_tag())?;
+
+        write_body_to_file(output.body, path, progress, f).await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn download_ranges_to_file<F>(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+        size_byte: usize,
+        path: &Path,
+        progress: &mut DownloadProgress,
+        f: F,
+    ) -> Result<()>
+    where
+        F: Fn(usize),
+    {
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(progress.offset == 0)
+            .open(path)
+            .await
+            .map_err(|e| AppError::new("Failed to create file", e))?;
+        file.set_len(size_byte as u64)
+            .await
+            .map_err(|e| AppError::new("Failed to create file", e))?;
+
+        let sink = RangeSink::File(path.to_path_buf());
+        self.download_ranges(bucket, key, version_id, size_byte, sink, progress, f)
+            .await
+            .map(|_| ())
+    }
+
+    fn download_part_size(&self) -> usize {
+        self.transfer_config
+            .download_part_size_byte
+            .max(DOWNLOAD_MIN_PART_SIZE)
+    }
+
+    // fetches the object from the current offset in byte ranges concurrently, parts are returned in order
+    #[allow(clippy::too_many_arguments)]
+    async fn download_ranges<F>(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+        size_byte: usize,
+        sink: RangeSink,
+        progress: &mut DownloadProgress,
+        f: F,
+    ) -> Result<Vec<RangePart>>
+    where
+        F: Fn(usize),
+    {
+        if progress.e_tag.is_none() {
+            // the listed size may be stale, so pin the download to the object it is checked against
+            let output = self
+                .send_to_bucket(bucket, |client| {
+                    client
+                        .head_object()
+                        .bucket(bucket)
+                        .key(key)
+                        .set_version_id(version_id.clone())
+                        .send()
+                })
+                .await
+                .map_err(|e| AppError::new("Failed to head object", e))?;
+            if output.content_length() != Some(size_byte as i64) {
+                return Err(AppError::msg(
+                    "Object was modified after it was listed, reload and try again",
+                ));
+            }
+            progress.check_e_tag(output.e_tag())?;
+        }
+        let e_tag = progress.e_tag.clone();
+
+        let concurrency = self.transfer_config.download_concurrency.max(1);
+        let part_size = self.download_part_size();
+        let mut ranges = split_ranges(progress.offset..size_byte, part_size).into_iter();
+
+        let (progress_tx, mut progress_rx) = mpsc::unbounded_channel();
+        let mut spawn_next = |set: &mut JoinSet<Result<RangePart>>| {
+            if let Some(range) = ranges.next() {
+                set.spawn(download_range(
+                    self.bucket_client(bucket),
+                    bucket.to_string(),
+                    key.to_string(),
+                    version_id.clone(),
+                    e_tag.clone(),
+                    range,
+                    sink.clone(),
+                    progress_tx.clone(),
+                ));
+            }
+        };
+
+        // dropping the set aborts the remaining parts when any of them fails
+        let mut set = JoinSet::new();
+        for _ in 0..concurrency {
+            spawn_next(&mut set);
+        }
+
+        let mut parts = Vec::new();
+        let mut written = progress.offset;
+        loop {
+            tokio::select! {
+                Some(n) = progress_rx.recv() => {
+                    written += n;
+                    f(written);
+                }
+                result = set.join_next() => match result {
+                    Some(result) => {
+                        let part =
+                            result.map_err(|e| AppError::new("Failed to download object", e))??;
+                        // all parts must come from the same object even if it is overwritten during the download
+                        progress.check_e_tag(part.e_tag.as_deref())?;
+                        progress.complete_range(part.range.clone());
+                        parts.push(part);
+                        spawn_next(&mut set);
+                    }
+                    None => break,
+                },
+            }
+        }
+
+        parts.sort_by_key(|part| part.range.start);
+        Ok(parts)
+    }
+
+    pub async fn presign_get_object(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+        expires_in: Duration,
+    ) -> Result<String> {
+        let config = PresigningConfig::expires_in(expires_in)
+            .map_err(|e| AppError::new("Invalid expiration", e))?;
+        let request = self
+            .bucket_client(bucket)
+            .get_object()
+            .bucket(bucket)
+            .key(key)
+            .set_version_id(version_id)
+            .presigned(config)
+            .await
+            .map_err(|e| AppError::new("Failed to generate presigned URL", e))?;
+        Ok(request.uri().to_string())
+    }
+
+    pub async fn upload_object<F>(
+        &self,
+        bucket: &str,
+        key: &str,
+        path: &Path,
+        size_byte: usize,
+        f: F,
+    ) -> Result<()>
+    where
+        F: Fn(usize),
+    {
+        if size_byte >= MULTIPART_UPLOAD_THRESHOLD {
+            return self
+                .upload_object_multipart(bucket, key, path, size_byte, f)
+                .await;
+        }
+
+        // the file is small enough to be read into memory, so the body can be sent again when retried
+        let body = ByteStream::from_path(path)
+            .await
+            .map_err(|e| AppError::new("Failed to read file", e))?
+            .collect()
+            .await
+            .map_err(|e| AppError::new("Failed to read file", e))?
+            .into_bytes();
+        self.send_to_bucket(bucket, |client| {
+            client
+                .put_object()
+                .bucket(bucket)
+                .key(key)
+                .body(ByteStream::from(body.clone()))
+                .send()
+        })
+        .await
+        .map_err(|e| AppError::new("Failed to upload object", e))?;
+
+        f(size_byte);
+
+        Ok(())
+    }
+
+    async fn upload_object_multipart<F>(
+        &self,
+        bucket: &str,
+        key: &str,
+        path: &Path,
+        size_byte: usize,
+        f: F,
+    ) -> Result<()>
+    where
+        F: Fn(usize),
+    {
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client
+                    .create_multipart_upload()
+                    .bucket(bucket)
+                    .key(key)
+                    .send()
+            })
+            .await;
+        let output = result.map_err(|e| AppError::new("Failed to create multipart upload", e))?;
+        let upload_id = multipart_upload_id(&output)?;
+        let mut guard =
+            MultipartUploadGuard::new(self.bucket_client(bucket), bucket, key, &upload_id);
+
+        let parts = self
+            .upload_parts(bucket, key, &upload_id, path, size_byte, f)
+            .await;
+        let result = self
+            .finish_multipart_upload(bucket, key, upload_id, parts)
+            .await;
+        // finish_multipart_upload has already aborted the upload if any part has failed
+        guard.disarm();
+        result
+    }
+
+    async fn finish_multipart_upload(
+        &self,
+        bucket: &str,
+        key: &str,
+        upload_id: String,
+        parts: Result<Vec<CompletedPart>>,
+    ) -> Result<()> {
+        match parts {
+            Ok(parts) => {
+                let completed = CompletedMultipartUpload::builder()
+                    .set_parts(Some(parts))
+                    .build();
+                self.send_to_bucket(bucket, |client| {
+                    client
+                        .complete_multipart_upload()
+                        .bucket(bucket)
+                        .key(key)
+                        .upload_id(upload_id.clone())
+                        .multipart_upload(completed.clone())
+                        .send()
+                })
+                .await
+                .map_err(|e| AppError::new("Failed to complete multipart upload", e))?;
+                Ok(())
+            }
+            Err(e) => {
+                // the parts uploaded so far are charged until the upload is aborted
+                let result = self
+                    .send_to_bucket(bucket, |client| {
+                        client
+                            .abort_multipart_upload()
+                            .bucket(bucket)
+                            .key(key)
+                            .upload_id(upload_id.clone())
+                            .send()
+                    })
+                    .await;
+                if let Err(abort_err) = result {
+                    tracing::warn!("Failed to abort multipart upload: {:?}", abort_err);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    async fn upload_parts<F>(
+        &self,
+        bucket: &str,
+        key: &str,
+        upload_id: &str,
+        path: &Path,
+        size_byte: usize,
+        f: F,
+    ) -> Result<Vec<CompletedPart>>
+    where
+        F: Fn(usize),
+    {
+        // grow the part size so that the number of parts does not exceed the limit
+        let part_size = MULTIPART_UPLOAD_PART_SIZE.max(size_byte.div_ceil(MULTIPART_MAX_PARTS));
+
+        let mut parts = Vec::new();
+        let mut offset = 0;
+        let mut part_number = 1;
+
+        while offset < size_byte {
+            let length = part_size.min(size_byte - offset);
+            let body = ByteStream::read_from()
+                .path(path)
+                .offset(offset as u64)
+                .length(Length::Exact(length as u64))
+                .build()
+                .await
+                .map_err(|e| AppError::new("Failed to read file", e))?;
+
+            let result = self
+                .bucket_client(bucket)
+                .upload_part()
+                .bucket(bucket)
+                .key(key)
+                .upload_id(upload_id)
+                .part_number(part_number)
+                .body(body)
+                .send()
+                .await;
+            let output = result.map_err(|e| AppError::new("Failed to upload part", e))?;
+
+            let part = CompletedPart::builder()
+                .set_e_tag(output.e_tag().map(String::from))
+                .part_number(part_number)
+                .build();
+            parts.push(part);
+
+            offset += length;
+            part_number += 1;
+
+            f(offset);
+        }
+
+        Ok(parts)
+    }
+
+    pub async fn copy_objects<F>(&self, target: &CopyTarget, f: F) -> Result<usize>
+    where
+        F: Fn(usize, usize),
+    {
+        let bucket = &target.object_key.bucket_name;
+        let objects = if target.dir {
+            let prefix = target.object_key.joined_object_path(false);
+            self.list_all_objects(bucket, &prefix).await?
+        } else {
+            let key = target.object_key.joined_object_path(true);
+            let size_byte = self.fetch_object_size(bucket, &key).await?;
+            vec![ObjectSummary {
+                key,
+                size_byte,
+                storage_class: String::new(),
+                restored: false,
+            }]
+        };
+
+        let total = objects.len();
+        for (i, obj) in objects.iter().enumerate() {
+            let dst_key = target.dst_key(&obj.key);
+            self.copy_object(
+                bucket,
+                &obj.key,
+                &target.dst_bucket,
+                &dst_key,
+                obj.size_byte,
+            )
+            .await?;
+            f(i + 1, total);
+        }
+
+        // sources are deleted only after every object has been copied
+        if target.mode == CopyMode::Move {
+            if target.dir {
+                let keys: Vec<String> = objects.into_iter().map(|o| o.key).collect();
+                self.delete_objects(bucket, &keys, |_| {}).await?;
+            } else {
+                let key = target.object_key.joined_object_path(true);
+                self.delete_object(bucket, &key, None).await?;
+            }
+        }
+
+        Ok(total)
+    }
+
+    async fn fetch_object_size(&self, bucket: &str, key: &str) -> Result<usize> {
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client.head_object().bucket(bucket).key(key).send()
+            })
             .await;
+        let output = result.map_err(|e| AppError::new("Failed to head object", e))?;
+        Ok(output.content_length().unwrap_or_default() as usize)
+    }
+
+    pub async fn copy_object(
+        &self,
+        src_bucket: &str,
+        src_key: &str,
+        dst_bucket: &str,
+        dst_key: &str,
+        size_byte: usize,
+    ) -> Result<()> {
+        if size_byte > COPY_OBJECT_MAX_SIZE {
+            return self
+                .copy_object_multipart(src_bucket, src_key, dst_bucket, dst_key, size_byte)
+                .await;
+        }
+
+        self.send_to_bucket(dst_bucket, |client| {
+            client
+                .copy_object()
+                .bucket(dst_bucket)
+                .key(dst_key)
+                .copy_source(build_copy_source(src_bucket, src_key, None))
+                .send()
+        })
+        .await
+        .map_err(|e| AppError::new("Failed to copy object", e))?;
+        Ok(())
+    }
+
+    async fn copy_object_multipart(
+        &self,
+        src_bucket: &str,
+        src_key: &str,
+        dst_bucket: &str,
+        dst_key: &str,
+        size_byte: usize,
+    ) -> Result<()> {
+        // unlike CopyObject, UploadPartCopy does not carry over the source metadata
+        let result = self
+            .send_to_bucket(src_bucket, |client| {
+                client.head_object().bucket(src_bucket).key(src_key).send()
+            })
+            .await;
+        let head = result.map_err(|e| AppError::new("Failed to head object", e))?;
+
+        let result = self
+            .send_to_bucket(dst_bucket, |client| {
+                client
+                    .create_multipart_upload()
+                    .bucket(dst_bucket)
+                    .key(dst_key)
+                    .set_content_type(head.content_type().map(String::from))
+                    .set_content_encoding(head.content_encoding().map(String::from))
+                    .set_content_disposition(head.content_disposition().map(String::from))
+                    .set_content_language(head.content_language().map(String::from))
+                    .set_cache_control(head.cache_control().map(String::from))
+                    .set_metadata(head.metadata().cloned())
+                    .set_storage_class(head.storage_class().cloned())
+                    .send()
+            })
+            .await;
+        let output = result.map_err(|e| AppError::new("Failed to create multipart upload", e))?;
+        let upload_id = multipart_upload_id(&output)?;
+
+        let parts = self
+            .upload_part_copies(
+                src_bucket, src_key, None, dst_bucket, dst_key, &upload_id, size_byte,
+            )
+            .await;
+        self.finish_multipart_upload(dst_bucket, dst_key, upload_id, parts)
+            .await
+    }
+
+    pub async fn update_object_metadata(
+        &self,
+        bucket: &str,
+        key: &str,
+        metadata: ObjectMetadata,
+    ) -> Result<()> {
+        let options = SelfCopyOptions {
+            metadata: Some(metadata),
+            ..Default::default()
+        };
+        self.copy_object_onto_itself(bucket, key, options).await
+    }
+
+    // copies the version onto the key, so that its content becomes the latest version
+    pub async fn promote_object_version(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: String,
+    ) -> Result<()> {
+        let options = SelfCopyOptions {
+            version_id: Some(version_id),
+            ..Default::default()
+        };
+        self.copy_object_onto_itself(bucket, key, options).await
+    }
+
+    pub async fn change_storage_classes<F>(
+        &self,
+        target: &StorageClassTarget,
+        storage_class: &str,
+        f: F,
+    ) -> Result<usize>
+    where
+        F: Fn(usize, usize),
+    {
+        let bucket = &target.object_key.bucket_name;
+        let objects = target.objects_to_change(storage_class);
+
+        let total = objects.len();
+        for (i, obj) in objects.iter().enumerate() {
+            let options = SelfCopyOptions {
+                storage_class: Some(StorageClass::from(storage_class)),
+                ..Default::default()
+            };
+            self.copy_object_onto_itself(bucket, &obj.key, options)
+                .await?;
+            f(i + 1, total);
+        }
+
+        Ok(total)
+    }
+
+    // everything not overridden by options (metadata, storage class, encryption, tags, ACL, Object Lock) is carried over
+    async fn copy_object_onto_itself(
+        &self,
+        bucket: &str,
+        key: &str,
+        options: SelfCopyOptions,
+    ) -> Result<()> {
+        let version_id = options.version_id.as_deref();
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client
+                    .head_object()
+                    .bucket(bucket)
+                    .key(key)
+                    .set_version_id(options.version_id.clone())
+                    .send()
+            })
+            .await;
+        let head = result.map_err(|e| AppError::new("Failed to head object", e))?;
+        // the copy is created with the private ACL, so a non-default ACL must be put again
+        let acl = self
+            .load_object_acl(bucket, key, options.version_id.clone())
+            .await?;
+
+        let size_byte = head.content_length().unwrap_or_default() as usize;
+        let metadata = options.metadata.unwrap_or_else(|| ObjectMetadata {
+            content_type: head.content_type().unwrap_or_default().into(),
+            cache_control: head.cache_control().unwrap_or_default().into(),
+            content_encoding: head.content_encoding().unwrap_or_default().into(),
+            content_disposition: head.content_disposition().unwrap_or_default().into(),
+            user_metadata: head
+                .metadata()
+                .map(|m| m.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+                .unwrap_or_default(),
+        });
+        let storage_class = options
+            .storage_class
+            .or_else(|| head.storage_class().cloned());
+        let expires = head
+            .expires_string()
+            .and_then(|s| DateTime::from_str(s, DateTimeFormat::HttpDate).ok());
+        let user_metadata: HashMap<String, String> = metadata.user_metadata.into_iter().collect();
+
+        if size_byte <= COPY_OBJECT_MAX_SIZE {
+            self.send_to_bucket(bucket, |client| {
+                client
+                    .copy_object()
+                    .bucket(bucket)
+                    .key(key)
+                    .copy_source(build_copy_source(bucket, key, version_id))
+                    .metadata_directive(MetadataDirective::Replace)
+                    .tagging_directive(TaggingDirective::Copy)
+                    .set_content_type(non_empty(metadata.content_type.clone()))
+                    .set_cache_control(non_empty(metadata.cache_control.clone()))
+                    .set_content_encoding(non_empty(metadata.content_encoding.clone()))
+                    .set_content_disposition(non_empty(metadata.content_disposition.clone()))
+                    .set_content_language(head.content_language().map(String::from))
+                    .set_expires(expires)
+                    .set_website_redirect_location(
+                        head.website_redirect_location().map(String::from),
+                    )
+                    .set_metadata(Some(user_metadata.clone()))
+                    .set_storage_class(storage_class.clone())
+                    .set_server_side_encryption(head.server_side_encryption().cloned())
+                    .set_ssekms_key_id(head.ssekms_key_id().map(String::from))
+                    .set_object_lock_mode(head.object_lock_mode().cloned())
+                    .set_object_lock_retain_until_date(
+                        head.object_lock_retain_until_date().cloned(),
+                    )
+                    .set_object_lock_legal_hold_status(
+                        head.object_lock_legal_hold_status().cloned(),
+                    )
+                    .send()
+            })
+            .await
+            .map_err(|e| AppError::new("Failed to copy object", e))?;
+            return self.put_object_acl(bucket, key, acl).await;
+        }
+
+        // UploadPartCopy carries over neither the metadata nor the tags
+        let tags = self
+            .load_object_tags(bucket, key, options.version_id.clone())
+            .await?;
+
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client
+                    .create_multipart_upload()
+                    .bucket(bucket)
+                    .key(key)
+                    .set_content_type(non_empty(metadata.content_type.clone()))
+                    .set_cache_control(non_empty(metadata.cache_control.clone()))
+                    .set_content_encoding(non_empty(metadata.content_encoding.clone()))
+                    .set_content_disposition(non_empty(metadata.content_disposition.clone()))
+                    .set_content_language(head.content_language().map(String::from))
+                    .set_expires(expires)
+                    .set_website_redirect_location(
+                        head.website_redirect_location().map(String::from),
+                    )
+                    .set_metadata(Some(user_metadata.clone()))
+                    .set_storage_class(storage_class.clone())
+                    .set_server_side_encryption(head.server_side_encryption().cloned())
+                    .set_ssekms_key_id(head.ssekms_key_id().map(String::from))
+                    .set_object_lock_mode(head.object_lock_mode().cloned())
+                    .set_object_lock_retain_until_date(
+                        head.object_lock_retain_until_date().cloned(),
+                    )
+                    .set_object_lock_legal_hold_status(
+                        head.object_lock_legal_hold_status().cloned(),
+                    )
+                    .send()
+            })
+            .await;
+        let output = result.map_err(|e| AppError::new("Failed to create multipart upload", e))?;
+        let upload_id = multipart_upload_id(&output)?;
+
+        let parts = self
+            .upload_part_copies(bucket, key, version_id, bucket, key, &upload_id, size_byte)
+            .await;
+        self.finish_multipart_upload(bucket, key, upload_id, parts)
+            .await?;
+
+        if !tags.is_empty() {
+            self.put_object_tags(bucket, key, None, &tags).await?;
+        }
+        self.put_object_acl(bucket, key, acl).await
+    }
+
+    // returns None if the ACL is the default one, which grants only the owner full control.
+    // buckets with ACLs disabled always return the default one.
+    async fn load_object_acl(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+    ) -> Result<Option<AccessControlPolicy>> {
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client
+                    .get_object_acl()
+                    .bucket(bucket)
+                    .key(key)
+                    .set_version_id(version_id.clone())
+                    .send()
+            })
+            .await;
+        let output = result.map_err(|e| AppError::new("Failed to load object ACL", e))?;
+
+        if is_default_acl(output.owner(), output.grants()) {
+            return Ok(None);
+        }
+        let policy = AccessControlPolicy::builder()
+            .set_owner(output.owner().cloned())
+            .set_grants(Some(output.grants().to_vec()))
+            .build();
+        Ok(Some(policy))
+    }
+
+    async fn put_object_acl(
+        &self,
+        bucket: &str,
+        key: &str,
+        acl: Option<AccessControlPolicy>,
+    ) -> Result<()> {
+        let Some(acl) = acl else {
+            return Ok(());
+        };
+        self.send_to_bucket(bucket, |client| {
+            client
+                .put_object_acl()
+                .bucket(bucket)
+                .key(key)
+                .access_control_policy(acl.clone())
+                .send()
+        })
+        .await
+        .map_err(|e| AppError::new("Failed to put object ACL", e))?;
+        Ok(())
+    }
+
+    async fn upload_part_copies(
+        &self,
+        src_bucket: &str,
+        src_key: &str,
+        src_version_id: Option<&str>,
+        dst_bucket: &str,
+        dst_key: &str,
+        upload_id: &str,
+        size_byte: usize,
+    ) -> Result<Vec<CompletedPart>> {
+        let copy_source = build_copy_source(src_bucket, src_key, src_version_id);
+        let part_size = MULTIPART_COPY_PART_SIZE.max(size_byte.div_ceil(MULTIPART_MAX_PARTS));
+
+        let mut parts = Vec::new();
+        let mut offset = 0;
+        let mut part_number = 1;
+
+        while offset < size_byte {
+            let length = part_size.min(size_byte - offset);
+            let range = format!("bytes={}-{}", offset, offset + length - 1);
+
+            let result = self
+                .send_to_bucket(dst_bucket, |client| {
+                    client
+                        .upload_part_copy()
+                        .bucket(dst_bucket)
+                        .key(dst_key)
+                        .upload_id(upload_id)
+                        .part_number(part_number)
+                        .copy_source(&copy_source)
+                        .copy_source_range(range.clone())
+                        .send()
+                })
+                .await;
+            let output = result.map_err(|e| AppError::new("Failed to copy part", e))?;
+
+            let e_tag = output.copy_part_result().and_then(|r| r.e_tag());
+            let part = CompletedPart::builder()
+                .set_e_tag(e_tag.map(String::from))
+                .part_number(part_number)
+                .build();
+            parts.push(part);
+
+            offset += length;
+            part_number += 1;
+        }
+
+        Ok(parts)
+    }
+
+    pub async fn delete_object(
+        &self,
+        bucket: &str,
+        key: &str,
+        version_id: Option<String>,
+    ) -> Result<()> {
+        self.send_to_bucket(bucket, |client| {
+            client
+                .delete_object()
+                .bucket(bucket)
+                .key(key)
+                .set_version_id(version_id.clone())
+                .send()
+        })
+        .await
+        .map_err(|e| AppError::new("Failed to delete object", e))?;
+        Ok(())
+    }
+
+    pub async fn delete_objects<F>(&self, bucket: &str, keys: &[String], f: F) -> Result<()>
+    where
+        F: Fn(usize),
+    {
+        let objects: Vec<(&str, Option<&str>)> = keys.iter().map(|k| (k.as_str(), None)).collect();
+        self.delete_object_versions(bucket, &objects, f).await
+    }
+
+    // removes the delete markers, so that the previous versions become current
+    pub async fn undelete_objects<F>(
+        &self,
+        bucket: &str,
+        markers: &[DeleteMarker],
+        f: F,
+    ) -> Result<()>
+    where
+        F: Fn(usize),
+    {
+        let objects: Vec<(&str, Option<&str>)> = markers
+            .iter()
+            .map(|m| (m.key.as_str(), Some(m.version_id.as_str())))
+            .collect();
+        self.delete_object_versions(bucket, &objects, f).await
+    }
+
+    // deletes (key, version id) pairs in batches, or the current versions if no version id is given
+    async fn delete_object_versions<F>(
+        &self,
+        bucket: &str,
+        objects: &[(&str, Option<&str>)],
+        f: F,
+    ) -> Result<()>
+    where
+        F: Fn(usize),
+    {
+        let mut deleted = 0;
+        for chunk in objects.chunks(DELETE_OBJECTS_BATCH_SIZE) {
+            let objects = chunk
+                .iter()
+                .map(|(key, version_id)| {
+                    ObjectIdentifier::builder()
+                        .key(*key)
+                        .set_version_id(version_id.map(String::from))
+                        .build()
+                })
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map_err(|e| AppError::new("Failed to build delete request", e))?;
+            let delete = Delete::builder()
+                .set_objects(Some(objects))
+                .quiet(true)
+                .build()
+                .map_err(|e| AppError::new("Failed to build delete request", e))?;
+
+            let result = self
+                .send_to_bucket(bucket, |client| {
+                    client
+                        .delete_objects()
+                        .bucket(bucket)
+                        .delete(delete.clone())
+                        .send()
+                })
+                .await;
+            let output = result.map_err(|e| AppError::new("Failed to delete objects", e))?;
+
+            if let Some(err) = output.errors().first() {
+                return Err(AppError::msg(format!(
+                    "Failed to delete {} objects (e.g. '{}': {})",
+                    output.errors().len(),
+                    err.key().unwrap_or_default(),
+                    err.message().unwrap_or_default(),
+                )));
+            }
+
+            deleted += chunk.len();
+            f(deleted);
+        }
+        Ok(())
+    }
+
+    pub async fn load_bucket_property(
+        &self,
+        bucket: &str,
+        property_type: BucketPropertyType,
+    ) -> Result<BucketProperty> {
+        let property = match property_type {
+            BucketPropertyType::Lifecycle => {
+                BucketProperty::from_json(self.load_bucket_lifecycle(bucket).await?)
+            }
+            BucketPropertyType::Policy => {
+                BucketProperty::from_json(self.load_bucket_policy(bucket).await?)
+            }
+            BucketPropertyType::Versioning => {
+                BucketProperty::from_fields(self.load_bucket_versioning(bucket).await?)
+            }
+            BucketPropertyType::Encryption => {
+                BucketProperty::from_fields(self.load_bucket_encryption(bucket).await?)
+            }
+            BucketPropertyType::PublicAccessBlock => {
+                BucketProperty::from_fields(self.load_bucket_public_access_block(bucket).await?)
+            }
+            BucketPropertyType::ObjectLock => {
+                BucketProperty::from_fields(self.load_bucket_object_lock(bucket).await?)
+            }
+            BucketPropertyType::Tags => {
+                BucketProperty::from_fields(self.load_bucket_tags(bucket).await?)
+            }
+            BucketPropertyType::Logging => {
+                BucketProperty::from_fields(self.load_bucket_logging(bucket).await?)
+            }
+            BucketPropertyType::Website => {
+                BucketProperty::from_fields(self.load_bucket_website(bucket).await?)
+            }
+            BucketPropertyType::Cors => {
+                BucketProperty::from_fields(self.load_bucket_cors(bucket).await?)
+            }
+        };
+        Ok(property)
+    }
+
+    async fn load_bucket_versioning(&self, bucket: &str) -> Result<Vec<BucketPropertyField>> {
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client.get_bucket_versioning().bucket(bucket).send()
+            })
+            .await;
+        let output = result.map_err(|e| bucket_property_error("versioning", e))?;
+
+        // the status is not returned if versioning has never been enabled
+        let status = output.status().map(|s| s.as_str()).unwrap_or("Disabled");
+        let mut fields = vec![BucketPropertyField::new("Status", status, 0)];
+        if let Some(mfa_delete) = output.mfa_delete() {
+            fields.push(BucketPropertyField::new(
+                "MFA delete",
+                mfa_delete.as_str(),
+                0,
+            ));
+        }
+        Ok(fields)
+    }
+
+    async fn load_bucket_encryption(&self, bucket: &str) -> Result<Vec<BucketPropertyField>> {
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client.get_bucket_encryption().bucket(bucket).send()
+            })
+            .await;
+        let Some(output) = optional_bucket_property("default encryption", result)? else {
+            return Ok(Vec::new());
+        };
+
+        let mut fields = Vec::new();
+        let rules = output
+            .server_side_encryption_configuration()
+            .map(|c| c.rules())
+            .unwrap_or_default();
+        for rule in rules {
+            if let Some(default) = rule.apply_server_side_encryption_by_default() {
+                fields.push(BucketPropertyField::new(
+                    "Encryption type",
+                    default.sse_algorithm().as_str(),
+                    0,
+                ));
+                if let Some(key_id) = default.kms_master_key_id() {
+                    fields.push(BucketPropertyField::new("KMS key ID", key_id, 0));
+                }
+            }
+            if let Some(enabled) = rule.bucket_key_enabled() {
+                fields.push(BucketPropertyField::new(
+                    "Bucket key",
+                    enabled_str(enabled),
+                    0,
+                ));
+            }
+        }
+        Ok(fields)
+    }
+
+    async fn load_bucket_public_access_block(
+        &self,
+        bucket: &str,
+    ) -> Result<Vec<BucketPropertyField>> {
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client.get_public_access_block().bucket(bucket).send()
+            })
+            .await;
+        let Some(output) = optional_bucket_property("public access block", result)? else {
+            return Ok(Vec::new());
+        };
+
+        let Some(config) = output.public_access_block_configuration() else {
+            return Ok(Vec::new());
+        };
+        let settings = [
+            ("Block public ACLs", config.block_public_acls()),
+            ("Ignore public ACLs", config.ignore_public_acls()),
+            ("Block public policy", config.block_public_policy()),
+            ("Restrict public buckets", config.restrict_public_buckets()),
+        ];
+        let fields = settings
+            .into_iter()
+            .map(|(name, value)| {
+                BucketPropertyField::new(name, on_off_str(value.unwrap_or_default()), 0)
+            })
+            .collect();
+        Ok(fields)
+    }
+
+    async fn load_bucket_object_lock(&self, bucket: &str) -> Result<Vec<BucketPropertyField>> {
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client.get_object_lock_configuration().bucket(bucket).send()
+            })
+            .await;
+        let Some(output) = optional_bucket_property("Object Lock configuration", result)? else {
+            return Ok(Vec::new());
+        };
+
+        let Some(config) = output.object_lock_configuration() else {
+            return Ok(Vec::new());
+        };
+        let mut fields = Vec::new();
+        if let Some(enabled) = config.object_lock_enabled() {
+            fields.push(BucketPropertyField::new("Object Lock", enabled.as_str(), 0));
+        }
+        if let Some(retention) = config.rule().and_then(|r| r.default_retention()) {
+            fields.push(BucketPropertyField::new("Default retention", "", 0));
+            if let Some(mode) = retention.mode() {
+                fields.push(BucketPropertyField::new("Mode", mode.as_str(), 1));
+            }
+            if let Some(days) = retention.days() {
+                fields.push(BucketPropertyField::new("Days", days.to_string(), 1));
+            }
+            if let Some(years) = retention.years() {
+                fields.push(BucketPropertyField::new("Years", years.to_string(), 1));
+            }
+        }
+        Ok(fields)
+    }
+
+    async fn load_bucket_tags(&self, bucket: &str) -> Result<Vec<BucketPropertyField>> {
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client.get_bucket_tagging().bucket(bucket).send()
+            })
+            .await;
+        let Some(output) = optional_bucket_property("tags", result)? else {
+            return Ok(Vec::new());
+        };
+
+        let fields = output
+            .tag_set()
+            .iter()
+            .map(|t| BucketPropertyField::new(t.key(), t.value(), 0))
+            .collect();
+        Ok(fields)
+    }
+
+    async fn load_bucket_logging(&self, bucket: &str) -> Result<Vec<BucketPropertyField>> {
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client.get_bucket_logging().bucket(bucket).send()
+            })
+            .await;
+        let output = result.map_err(|e| bucket_property_error("server access logging", e))?;
+
+        let Some(logging) = output.logging_enabled() else {
+            return Ok(Vec::new());
+        };
+        let fields = vec![
+            BucketPropertyField::new("Target bucket", logging.target_bucket(), 0),
+            BucketPropertyField::new("Target prefix", logging.target_prefix(), 0),
+        ];
+        Ok(fields)
+    }
+
+    async fn load_bucket_website(&self, bucket: &str) -> Result<Vec<BucketPropertyField>> {
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client.get_bucket_website().bucket(bucket).send()
+            })
+            .await;
+        let Some(output) = optional_bucket_property("static website configuration", result)? else {
+            return Ok(Vec::new());
+        };
+
+        let mut fields = Vec::new();
+        if let Some(redirect) = output.redirect_all_requests_to() {
+            let target = match redirect.protocol() {
+                Some(protocol) => format!("{}://{}", protocol.as_str(), redirect.host_name()),
+                None => redirect.host_name().to_string(),
+            };
+            fields.push(BucketPropertyField::new(
+                "Redirect all requests to",
+                target,
+                0,
+            ));
+        }
+        if let Some(index) = output.index_document() {
+            fields.push(BucketPropertyField::new(
+                "Index document",
+                index.suffix(),
+                0,
+            ));
+        }
+        if let Some(error) = output.error_document() {
+            fields.push(BucketPropertyField::new("Error document", error.key(), 0));
+        }
+        if !output.routing_rules().is_empty() {
+            fields.push(BucketPropertyField::new(
+                "Routing rules",
+                output.routing_rules().len().to_string(),
+                0,
+            ));
+        }
+        Ok(fields)
+    }
+
+    async fn load_bucket_cors(&self, bucket: &str) -> Result<Vec<BucketPropertyField>> {
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client.get_bucket_cors().bucket(bucket).send()
+            })
+            .await;
+        let Some(output) = optional_bucket_property("CORS configuration", result)? else {
+            return Ok(Vec::new());
+        };
+
+        let mut fields = Vec::new();
+        for (i, rule) in output.cors_rules().iter().enumerate() {
+            let name = rule
+                .id()
+                .map(String::from)
+                .unwrap_or_else(|| format!("#{}", i + 1));
+            fields.push(BucketPropertyField::new("Rule", name, 0));
+            let lists = [
+                ("Allowed methods", rule.allowed_methods()),
+                ("Allowed origins", rule.allowed_origins()),
+                ("Allowed headers", rule.allowed_headers()),
+                ("Expose headers", rule.expose_headers()),
+            ];
+            for (name, values) in lists.into_iter().filter(|(_, vs)| !vs.is_empty()) {
+                fields.push(BucketPropertyField::new(name, values.join(", "), 1));
+            }
+            if let Some(max_age) = rule.max_age_seconds() {
+                fields.push(BucketPropertyField::new(
+                    "Max age",
+                    format!("{} seconds", max_age),
+                    1,
+                ));
+            }
+        }
+        Ok(fields)
+    }
+
+    async fn load_bucket_lifecycle(&self, bucket: &str) -> Result<Option<String>> {
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client
+                    .get_bucket_lifecycle_configuration()
+                    .bucket(bucket)
+                    .send()
+            })
+            .await;
+        let Some(output) = optional_bucket_property("lifecycle configuration", result)? else {
+            return Ok(None);
+        };
+
+        let json = lifecycle_rules_to_json(output.rules());
+        let json = serde_json::to_string_pretty(&json)
+            .map_err(|e| AppError::new("Failed to format lifecycle configuration", e))?;
+        Ok(Some(json))
+    }
+
+    async fn load_bucket_policy(&self, bucket: &str) -> Result<Option<String>> {
+        let result = self
+            .send_to_bucket(bucket, |client| {
+                client.get_bucket_policy().bucket(bucket).send()
+            })
+            .await;
+        let Some(output) = optional_bucket_property("bucket policy", result)? else {
+            return Ok(None);
+        };
+
+        Ok(output.policy().map(pretty_print_json))
+    }
+
+    pub fn open_management_console_buckets(&self) -> Result<()> {
+        let path = format!(
+            "https://s3.console.aws.amazon.com/s3/buckets?region={}",
+            self.region
+        );
+        open::that(path).map_err(AppError::error)
+    }
+
+    pub fn open_management_console_list(&self, bucket: &str, prefix: &str) -> Result<()> {
+        let path = format!(
+            "https://s3.console.aws.amazon.com/s3/buckets/{}?region={}&prefix={}",
+            bucket,
+            self.bucket_region(bucket),
+            prefix
+        );
+        open::that(path).map_err(AppError::error)
+    }
+
+    pub fn open_management_console_object(&self, bucket: &str, prefix: &str) -> Result<()> {
+        let path = format!(
+            "https://s3.console.aws.amazon.com/s3/object/{}?region={}&prefix={}",
+            bucket,
+            self.bucket_region(bucket),
+            prefix
+        );
+        open::that(path).map_err(AppError::error)
+    }
+
+    async fn refresh_bucket_region(&self, bucket: &str) -> Result<()> {
+        let region = request_bucket_location(self.client.clone(), bucket.to_string()).await?;
+        self.bucket_region_cache.set(bucket, &region);
+        self.bucket_region_cache.write_cache().unwrap();
+
+        self.check_bucket_region(bucket, &normalize_bucket_region(&region))
+    }
+
+    // buckets outside the client's region can be accessed only in the all-regions mode
+    fn check_bucket_region(&self, bucket: &str, region: &str) -> Result<()> {
+        if !self.all_regions && region != self.region {
+            return Err(AppError::msg(format!(
+                "Bucket '{}' is in region '{}', expected '{}'",
+                bucket, region, self.region
+            )));
+        }
+        Ok(())
+    }
+
+    pub async fn fetch_bucket_location(&self, bucket: &str) -> Result<String> {
+        if let Some(region) = self.bucket_region_cache.get(bucket) {
+            return Ok(region);
+        }
+
+        let region = request_bucket_location(self.client.clone(), bucket.to_string()).await?;
+        self.bucket_region_cache.set(bucket, &region);
+        Ok(region)
+    }
+}
+
+async fn request_bucket_location(client: aws_sdk_s3::Client, bucket: String) -> Result<String> {
+    let result = client.get_bucket_location().bucket(bucket).send().await;
+
+    match result {
+        Ok(response) => {
+            let location = response
+                .location_constraint()
+                .map_or("", |c| c.as_str())
+                .to_string();
+            tracing::debug!("Found region from response: {}", location);
+            Ok(location)
+        }
+        Err(e) => {
+            if let SdkError::ServiceError(service_err) = &e {
+                let raw = service_err.raw();
+                if let Some(bytes) = raw.body().bytes() {
+                    if let Ok(body_str) = String::from_utf8(bytes.to_vec()) {
+                        // use roxmltree to parse XML
+                        let doc = roxmltree::Document::parse(&body_str)
+                            .map_err(|e| AppError::new("Failed to parse XML", e))?;
+
+                        // find the innermost LocationConstraint node
+                        if let Some(region) = doc
+                            .descendants()
+                            .filter(|n| n.has_tag_name("LocationConstraint"))
+                            .filter_map(|n| n.text())
+                            .next()
+                        {
+                            let region = region.trim().to_string();
+                            tracing::debug!("Found region from XML: {}", region);
+                            return Ok(region);
+                        }
+                    }
+                }
+            }
+            Err(AppError::new("Failed to get bucket location", e))
+        }
+    }
+}
+
+// S3 responds with one of these when a request is sent to a region other than the bucket's
+fn is_region_mismatch_error<E: ProvideErrorMetadata>(e: &SdkError<E, HttpResponse>) -> bool {
+    if matches!(
+        e.code(),
+        Some(
+            "PermanentRedirect"
+                | "AuthorizationHeaderMalformed"
+                | "IllegalLocationConstraintException"
+        )
+    ) {
+        return true;
+    }
+    e.raw_response()
+        .is_some_and(|res| res.status().as_u16() == 301)
+}
+
+// the errors which may be caused only by the checksum mode.
+// the response of HeadObject has no body, so a missing kms:Decrypt permission can be told only by the status,
+// which an access denied to the object itself also returns.
+fn is_checksum_mode_error<E: ProvideErrorMetadata>(e: &SdkError<E, HttpResponse>) -> bool {
+    if let Some(code) = e.code() {
+        return matches!(code, "AccessDenied" | "InvalidArgument" | "NotImplemented");
+    }
+    e.raw_response()
+        .is_some_and(|res| matches!(res.status().as_u16(), 400 | 403 | 501))
+}
+
+fn build_bucket_item(
+    name: String,
+    prefix: Option<String>,
+    region: &str,
+    with_region: bool,
+) -> BucketItem {
+    let s3_uri = build_bucket_s3_uri(&name);
+    let arn = build_bucket_arn(&name);
+    let object_url = build_bucket_url(region, &name);
+    BucketItem {
+        name,
+        s3_uri,
+        arn,
+        object_url,
+        prefix,
+        region: with_region.then(|| region.to_string()),
+    }
+}
 
-        match result {
-            Ok(response) => {
-                let location = response.location_constraint().unwrap().to_string();
-                tracing::debug!("Found region from response: {}", location);
-                self.bucket_region_cache.set(bucket, &location);
-                Ok(location)
-            }
-            Err(e) => {
-                if let SdkError::ServiceError(service_err) = &e {
-                    let raw = service_err.raw();
-                    if let Some(bytes) = raw.body().bytes() {
-                        if let Ok(body_str) = String::from_utf8(bytes.to_vec()) {
-                            // use roxmltree to parse XML
-                            let doc = roxmltree::Document::parse(&body_str)
-                                .map_err(|e| AppError::new("Failed to parse XML", e))?;
-
-                            // find the innermost LocationConstraint node
-                            if let Some(region) = doc
-                                .descendants()
-                                .filter(|n| n.has_tag_name("LocationConstraint"))
-                                .filter_map(|n| n.text())
-                                .next()
-                            {
-                                let region = region.trim().to_string();
-                                tracing::debug!("Found region from XML: {}", region);
-                                self.bucket_region_cache.set(bucket, &region);
-                                return Ok(region);
-                            }
-                        }
-                    }
-                }
-                Err(AppError::new("Failed to get bucket location", e))
-            }
-        }
+// GetBucketLocation returns an empty location for us-east-1 and `EU` for the oldest buckets in eu-west-1
+fn normalize_bucket_region(location: &str) -> String {
+    match location {
+        "" => "us-east-1".to_string(),
+        "EU" => "eu-west-1".to_string(),
+        _ => location.to_string(),
     }
 }
 
@@ -450,6 +2381,64 @@ fn objects_output_to_files(
         .collect()
 }
 
+fn object_versions_output_to_versions(
+    key: &str,
+    output: &ListObjectVersionsOutput,
+) -> ObjectVersions {
+    // the prefix also matches other keys starting with the key (e.g. `a.txt.bak` for `a.txt`)
+    let versions = output
+        .versions()
+        .iter()
+        .filter(|v| v.key() == Some(key))
+        .map(|v| {
+            let version_id = v.version_id().unwrap().to_string(); // returns "null" if empty...
+            let size_byte = v.size().unwrap() as usize;
+            let last_modified = convert_datetime(v.last_modified().unwrap());
+            let is_latest = v.is_latest().unwrap();
+            FileVersion {
+                version_id,
+                size_byte,
+                last_modified,
+                is_latest,
+                is_delete_marker: false,
+            }
+        });
+    let delete_markers = output
+        .delete_markers()
+        .iter()
+        .filter(|m| m.key() == Some(key))
+        .map(|m| {
+            let version_id = m.version_id().unwrap().to_string();
+            let last_modified = convert_datetime(m.last_modified().unwrap());
+            let is_latest = m.is_latest().unwrap();
+            FileVersion {
+                version_id,
+                size_byte: 0,
+                last_modified,
+                is_latest,
+                is_delete_marker: true,
+            }
+        });
+
+    let mut versions: Vec<FileVersion> = versions.chain(delete_markers).collect();
+    // versions and delete markers are returned separately, so merge them newest first
+    versions.sort_by_key(|v| std::cmp::Reverse(v.last_modified));
+
+    // the key sorts before any other key matching the prefix,
+    // so once the next key marker moves past it, all versions have been loaded
+    let next_version_id_marker =
+        if output.is_truncated().unwrap_or(false) && output.next_key_marker() == Some(key) {
+            output.next_version_id_marker().map(String::from)
+        } else {
+            None
+        };
+
+    ObjectVersions {
+        versions,
+        next_version_id_marker,
+    }
+}
+
 fn parse_path(path: &str, dir: bool) -> Vec<String> {
     let ss: Vec<String> = path.split(DELIMITER).map(String::from).collect();
     if dir {
@@ -488,3 +2477,961 @@ fn build_object_arn(bucket: &str, key: &str) -> String {
 fn build_object_url(region: &str, bucket: &str, key: &str) -> String {
     format!("https://{}.s3.{}.amazonaws.com/{}", bucket, region, key)
 }
+
+// aborts the multipart upload when its task is aborted (e.g. the transfer is canceled) while uploading parts,
+// otherwise the parts uploaded so far are left and charged
+struct MultipartUploadGuard {
+    client: aws_sdk_s3::Client,
+    bucket: String,
+    key: String,
+    upload_id: String,
+    armed: bool,
+}
+
+impl MultipartUploadGuard {
+    fn new(client: aws_sdk_s3::Client, bucket: &str, key: &str, upload_id: &str) -> Self {
+        Self {
+            client,
+            bucket: bucket.to_string(),
+            key: key.to_string(),
+            upload_id: upload_id.to_string(),
+            armed: true,
+        }
+    }
+
+    fn disarm(&mut self) {
+        self.armed = false;
+    }
+}
+
+impl Drop for MultipartUploadGuard {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+        // the request cannot be awaited in drop, so it is sent from a new task
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let request = self
+            .client
+            .abort_multipart_upload()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .upload_id(&self.upload_id);
+        handle.spawn(async move {
+            if let Err(e) = request.send().await {
+                tracing::warn!("Failed to abort multipart upload: {:?}", e);
+            }
+        });
+    }
+}
+
+// removes the incomplete file when the download fails or its task is aborted, unless it can be resumed
+struct TempFileGuard {
+    path: PathBuf,
+    keep: bool,
+}
+
+impl TempFileGuard {
+    fn new(path: PathBuf) -> Self {
+        Self { path, keep: false }
+    }
+
+    fn keep(&mut self) {
+        self.keep = true;
+    }
+}
+
+impl Drop for TempFileGuard {
+    fn drop(&mut self) {
+        if self.keep {
+            return;
+        }
+        // after a successful rename the file no longer exists
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[derive(Debug, Default)]
+struct DownloadProgress {
+    e_tag: Option<String>,
+    offset: usize,                     // bytes written contiguously from the start
+    completed: BTreeMap<usize, usize>, // ranges completed beyond the offset, start => end
+}
+
+impl From<Option<DownloadResumeState>> for DownloadProgress {
+    fn from(state: Option<DownloadResumeState>) -> Self {
+        match state {
+            Some(state) => DownloadProgress {
+                e_tag: Some(state.e_tag),
+                offset: state.offset,
+                ..Default::default()
+            },
+            None => DownloadProgress::default(),
+        }
+    }
+}
+
+impl DownloadProgress {
+    fn check_e_tag(&mut self, e_tag: Option<&str>) -> Result<()> {
+        match (&self.e_tag, e_tag) {
+            (Some(expected), Some(e_tag)) if expected != e_tag => {
+                Err(AppError::msg("Object was modified during download"))
+            }
+            (None, Some(e_tag)) => {
+                self.e_tag = Some(e_tag.into());
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn complete_range(&mut self, range: Range<usize>) {
+        self.completed.insert(range.start, range.end);
+        while let Some(end) = self.completed.remove(&self.offset) {
+            self.offset = end;
+        }
+    }
+
+    fn resume_state(&self) -> Option<DownloadResumeState> {
+        match &self.e_tag {
+            Some(e_tag) if self.offset > 0 => Some(DownloadResumeState {
+                e_tag: e_tag.clone(),
+                offset: self.offset,
+            }),
+            _ => None,
+        }
+    }
+}
+
+// appends to the file when resuming, `progress.offset` follows the bytes written
+async fn write_body_to_file<F>(
+    mut body: ByteStream,
+    path: &Path,
+    progress: &mut DownloadProgress,
+    f: F,
+) -> Result<()>
+where
+    F: Fn(usize),
+{
+    let file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(progress.offset > 0)
+        .truncate(progress.offset == 0)
+        .open(path)
+        .await
+        .map_err(|e| AppError::new("Failed to create file", e))?;
+    if progress.offset > 0 {
+        // drop anything written after the last saved offset
+        file.set_len(progress.offset as u64)
+            .await
+            .map_err(|e| AppError::new("Failed to write file", e))?;
+    }
+    let mut writer = tokio::io::BufWriter::new(file);
+
+    let mut i = 0;
+    loop {
+        let buf = match body.try_next().await {
+            Ok(Some(buf)) => buf, // buf: 32 KiB
+            Ok(None) => break,
+            Err(e) => {
+                // keep what has been received so far so that it can be resumed
+                if writer.flush().await.is_err() {
+                    progress.offset = 0;
+                }
+                return Err(AppError::new("Failed to collect body", e));
+            }
+        };
+        if let Err(e) = writer.write_all(&buf).await {
+            progress.offset = 0;
+            return Err(AppError::new("Failed to write file", e));
+        }
+        progress.offset += buf.len();
+
+        // suppress too many calls (32 KiB * 32 = 1 MiB)
+        if i >= 32 {
+            f(progress.offset);
+            i = 0;
+        }
+        i += 1;
+    }
+
+    writer
+        .flush()
+        .await
+        .map_err(|e| AppError::new("Failed to write file", e))
+}
+
+#[derive(Debug, Clone)]
+enum RangeSink {
+    File(PathBuf), // written in place, the file must already have the full size
+    Memory,
+}
+
+#[derive(Debug)]
+struct RangePart {
+    range: Range<usize>,
+    e_tag: Option<String>,
+    bytes: Vec<u8>, // empty when written to a file
+}
+
+fn split_ranges(range: Range<usize>, part_size: usize) -> Vec<Range<usize>> {
+    let end = range.end;
+    range
+        .step_by(part_size)
+        .map(|start| start..(start + part_size).min(end))
+        .collect()
+}
+
+// each range is requested with If-Match so that all of them come from the same object
+#[allow(clippy::too_many_arguments)]
+async fn download_range(
+    client: aws_sdk_s3::Client,
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+    e_tag: Option<String>,
+    range: Range<usize>,
+    sink: RangeSink,
+    progress_tx: mpsc::UnboundedSender<usize>,
+) -> Result<RangePart> {
+    let result = client
+        .get_object()
+        .bucket(bucket)
+        .key(key)
+        .set_version_id(version_id)
+        .set_if_match(e_tag)
+        .range(format!("bytes={}-{}", range.start, range.end - 1))
+        .send()
+        .await;
+    let output = result.map_err(download_error)?;
+    let e_tag = output.e_tag().map(String::from);
+    let mut body = output.body;
+
+    let mut writer = match &sink {
+        RangeSink::File(path) => {
+            let mut file = tokio::fs::OpenOptions::new()
+                .write(true)
+                .open(path)
+                .await
+                .map_err(|e| AppError::new("Failed to open file", e))?;
+            file.seek(SeekFrom::Start(range.start as u64))
+                .await
+                .map_err(|e| AppError::new("Failed to write file", e))?;
+            Some(tokio::io::BufWriter::new(file))
+        }
+        RangeSink::Memory => None,
+    };
+
+    let mut bytes = Vec::new();
+    while let Some(buf) = body // buf: 32 KiB
+        .try_next()
+        .await
+        .map_err(|e| AppError::new("Failed to collect body", e))?
+    {
+        match writer.as_mut() {
+            Some(writer) => writer
+                .write_all(&buf)
+                .await
+                .map_err(|e| AppError::new("Failed to write file", e))?,
+            None => bytes.extend_from_slice(&buf),
+        }
+        // the receiver is gone only when the download has already failed
+        let _ = progress_tx.send(buf.len());
+    }
+
+    if let Some(mut writer) = writer {
+        writer
+            .flush()
+            .await
+            .map_err(|e| AppError::new("Failed to write file", e))?;
+    }
+
+    Ok(RangePart {
+        range,
+        e_tag,
+        bytes,
+    })
+}
+
+fn download_error(e: SdkError<GetObjectError, HttpResponse>) -> AppError {
+    // GetObject fails with InvalidObjectState for archived objects which have not been restored
+    if let Some(GetObjectError::InvalidObjectState(state)) = e.as_service_error() {
+        let msg = match state.storage_class() {
+            Some(class) => format!(
+                "The object is archived in {} and must be restored before downloading",
+                class.as_str()
+            ),
+            None => "The object is archived and must be restored before downloading".to_string(),
+        };
+        return AppError::new(msg, e);
+    }
+    // the object no longer matches the ETag requested with If-Match
+    if e.raw_response()
+        .is_some_and(|res| res.status().as_u16() == 412)
+    {
+        return AppError::new("Object was modified during download", e);
+    }
+    AppError::new("Failed to download object", e)
+}
+
+// error codes returned when the requested bucket configuration has not been set
+const BUCKET_PROPERTY_NOT_CONFIGURED_ERROR_CODES: &[&str] = &[
+    "ServerSideEncryptionConfigurationNotFoundError",
+    "NoSuchPublicAccessBlockConfiguration",
+    "ObjectLockConfigurationNotFoundError",
+    "NoSuchTagSet",
+    "NoSuchWebsiteConfiguration",
+    "NoSuchCORSConfiguration",
+    "NoSuchLifecycleConfiguration",
+    "NoSuchBucketPolicy",
+];
+
+// returns None if the property is not configured
+fn optional_bucket_property<T, E>(
+    name: &str,
+    result: std::result::Result<T, SdkError<E, HttpResponse>>,
+) -> Result<Option<T>>
+where
+    E: ProvideErrorMetadata + std::error::Error + Send + Sync + 'static,
+{
+    match result {
+        Ok(output) => Ok(Some(output)),
+        Err(e)
+            if e.code()
+                .is_some_and(|c| BUCKET_PROPERTY_NOT_CONFIGURED_ERROR_CODES.contains(&c)) =>
+        {
+            Ok(None)
+        }
+        Err(e) => Err(bucket_property_error(name, e)),
+    }
+}
+
+fn bucket_property_error<E>(name: &str, e: SdkError<E, HttpResponse>) -> AppError
+where
+    E: ProvideErrorMetadata + std::error::Error + Send + Sync + 'static,
+{
+    // keep the error code so that e.g. AccessDenied can be told apart from other failures
+    let msg = match (e.code(), e.message()) {
+        (Some(code), Some(message)) => format!("Failed to load {} ({}: {})", name, code, message),
+        (Some(code), None) => format!("Failed to load {} ({})", name, code),
+        _ => format!("Failed to load {}", name),
+    };
+    AppError::new(msg, e)
+}
+
+fn enabled_str(enabled: bool) -> &'static str {
+    if enabled {
+        "Enabled"
+    } else {
+        "Disabled"
+    }
+}
+
+fn on_off_str(on: bool) -> &'static str {
+    if on {
+        "On"
+    } else {
+        "Off"
+    }
+}
+
+// returns the input as it is if it is not valid JSON
+fn pretty_print_json(s: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(s)
+        .and_then(|v| serde_json::to_string_pretty(&v))
+        .unwrap_or_else(|_| s.to_string())
+}
+
+// same shape as the LifecycleConfiguration of the S3 API, keys are sorted
+fn lifecycle_rules_to_json(rules: &[LifecycleRule]) -> serde_json::Value {
+    let rules: Vec<serde_json::Value> = rules.iter().map(lifecycle_rule_to_json).collect();
+    serde_json::json!({ "Rules": rules })
+}
+
+fn lifecycle_rule_to_json(rule: &LifecycleRule) -> serde_json::Value {
+    let mut obj = serde_json::Map::new();
+    insert_json(&mut obj, "ID", rule.id());
+    insert_json(&mut obj, "Status", Some(rule.status().as_str()));
+    if let Some(filter) = rule.filter() {
+        let mut filter_obj = serde_json::Map::new();
+        insert_json(&mut filter_obj, "Prefix", filter.prefix());
+        if let Some(tag) = filter.tag() {
+            filter_obj.insert("Tag".into(), tag_to_json(tag));
+        }
+        insert_json(
+            &mut filter_obj,
+            "ObjectSizeGreaterThan",
+            filter.object_size_greater_than(),
+        );
+        insert_json(
+            &mut filter_obj,
+            "ObjectSizeLessThan",
+            filter.object_size_less_than(),
+        );
+        if let Some(and) = filter.and() {
+            let mut and_obj = serde_json::Map::new();
+            insert_json(&mut and_obj, "Prefix", and.prefix());
+            if !and.tags().is_empty() {
+                let tags = and.tags().iter().map(tag_to_json).collect();
+                and_obj.insert("Tags".into(), serde_json::Value::Array(tags));
+            }
+            insert_json(
+                &mut and_obj,
+                "ObjectSizeGreaterThan",
+                and.object_size_greater_than(),
+            );
+            insert_json(
+                &mut and_obj,
+                "ObjectSizeLessThan",
+                and.object_size_less_than(),
+            );
+            filter_obj.insert("And".into(), and_obj.into());
+        }
+        obj.insert("Filter".into(), filter_obj.into());
+    } else {
+        // rules created before the Filter element was introduced have a top level prefix
+        #[allow(deprecated)]
+        insert_json(&mut obj, "Prefix", rule.prefix());
+    }
+    if let Some(expiration) = rule.expiration() {
+        let mut expiration_obj = serde_json::Map::new();
+        insert_json(&mut expiration_obj, "Days", expiration.days());
+        insert_json(
+            &mut expiration_obj,
+            "Date",
+            expiration.date().map(format_json_datetime),
+        );
+        insert_json(
+            &mut expiration_obj,
+            "ExpiredObjectDeleteMarker",
+            expiration.expired_object_delete_marker(),
+        );
+        obj.insert("Expiration".into(), expiration_obj.into());
+    }
+    if !rule.transitions().is_empty() {
+        let transitions = rule
+            .transitions()
+            .iter()
+            .map(|t| {
+                let mut transition_obj = serde_json::Map::new();
+                insert_json(&mut transition_obj, "Days", t.days());
+                insert_json(
+                    &mut transition_obj,
+                    "Date",
+                    t.date().map(format_json_datetime),
+                );
+                insert_json(
+                    &mut transition_obj,
+                    "StorageClass",
+                    t.storage_class().map(|c| c.as_str()),
+                );
+                transition_obj.into()
+            })
+            .collect();
+        obj.insert("Transitions".into(), serde_json::Value::Array(transitions));
+    }
+    if !rule.noncurrent_version_transitions().is_empty() {
+        let transitions = rule
+            .noncurrent_version_transitions()
+            .iter()
+            .map(|t| {
+                let mut transition_obj = serde_json::Map::new();
+                insert_json(&mut transition_obj, "NoncurrentDays", t.noncurrent_days());
+                insert_json(
+                    &mut transition_obj,
+                    "NewerNoncurrentVersions",
+                    t.newer_noncurrent_versions(),
+                );
+                insert_json(
+                    &mut transition_obj,
+                    "StorageClass",
+                    t.storage_class().map(|c| c.as_str()),
+                );
+                transition_obj.into()
+            })
+            .collect();
+        obj.insert(
+            "NoncurrentVersionTransitions".into(),
+            serde_json::Value::Array(transitions),
+        );
+    }
+    if let Some(expiration) = rule.noncurrent_version_expiration() {
+        let mut expiration_obj = serde_json::Map::new();
+        insert_json(
+            &mut expiration_obj,
+            "NoncurrentDays",
+            expiration.noncurrent_days(),
+        );
+        insert_json(
+            &mut expiration_obj,
+            "NewerNoncurrentVersions",
+            expiration.newer_noncurrent_versions(),
+        );
+        obj.insert("NoncurrentVersionExpiration".into(), expiration_obj.into());
+    }
+    if let Some(abort) = rule.abort_incomplete_multipart_upload() {
+        let mut abort_obj = serde_json::Map::new();
+        insert_json(
+            &mut abort_obj,
+            "DaysAfterInitiation",
+            abort.days_after_initiation(),
+        );
+        obj.insert("AbortIncompleteMultipartUpload".into(), abort_obj.into());
+    }
+    obj.into()
+}
+
+fn tag_to_json(tag: &Tag) -> serde_json::Value {
+    serde_json::json!({ "Key": tag.key(), "Value": tag.value() })
+}
+
+fn format_json_datetime(dt: &DateTime) -> String {
+    dt.fmt(DateTimeFormat::DateTime).unwrap_or_default()
+}
+
+// absent values are omitted rather than written as null
+fn insert_json<T: Into<serde_json::Value>>(
+    obj: &mut serde_json::Map<String, serde_json::Value>,
+    key: &str,
+    value: Option<T>,
+) {
+    if let Some(value) = value {
+        obj.insert(key.into(), value.into());
+    }
+}
+
+#[derive(Debug, Default)]
+struct SelfCopyOptions {
+    metadata: Option<ObjectMetadata>,
+    storage_class: Option<StorageClass>,
+    version_id: Option<String>, // the version to copy from, latest if None
+}
+
+fn non_empty(s: String) -> Option<String> {
+    if s.is_empty() {
+        None
+    } else {
+        Some(s)
+    }
+}
+
+fn build_copy_source(bucket: &str, key: &str, version_id: Option<&str>) -> String {
+    // the key part of x-amz-copy-source must be URL-encoded
+    let encoded: String = key
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect();
+    match version_id {
+        Some(version_id) => format!("{}/{}?versionId={}", bucket, encoded, version_id),
+        None => format!("{}/{}", bucket, encoded),
+    }
+}
+
+fn is_default_acl(owner: Option<&Owner>, grants: &[Grant]) -> bool {
+    let owner_id = owner.and_then(|o| o.id());
+    grants.iter().all(|g| {
+        g.permission() == Some(&Permission::FullControl)
+            && g.grantee().is_some_and(|grantee| {
+                grantee.r#type() == &Type::CanonicalUser && grantee.id() == owner_id
+            })
+    })
+}
+
+fn multipart_upload_id(output: &CreateMultipartUploadOutput) -> Result<String> {
+    output
+        .upload_id()
+        .map(String::from)
+        .ok_or_else(|| AppError::msg("No upload ID in the create multipart upload response"))
+}
+
+#[derive(Debug)]
+struct VersionEntry {
+    key: String,
+    version_id: String,
+    last_modified: chrono::DateTime<chrono::Local>,
+    size_byte: usize,
+    e_tag: String,
+    is_latest: bool,
+    is_delete_marker: bool,
+}
+
+// returns the version of each key that was current at the given time, sorted by key.
+// keys that did not exist yet or had been deleted at that time are excluded.
+fn current_versions_at(
+    mut entries: Vec<VersionEntry>,
+    at: chrono::DateTime<chrono::Local>,
+) -> Vec<VersionEntry> {
+    // the response lists versions and delete markers separately, so restore the newest-first order of each key.
+    // the stable sort keeps the response order among versions with the same last modified time,
+    // and the latest entry, then a delete marker, is treated as the newer one on a tie.
+    entries.sort_by(|a, b| {
+        a.key
+            .cmp(&b.key)
+            .then(b.last_modified.cmp(&a.last_modified))
+            .then(b.is_latest.cmp(&a.is_latest))
+            .then(b.is_delete_marker.cmp(&a.is_delete_marker))
+    });
+
+    let mut current: Vec<VersionEntry> = Vec::new();
+    for entry in entries.into_iter().filter(|e| e.last_modified <= at) {
+        if current.last().is_some_and(|c| c.key == entry.key) {
+            continue;
+        }
+        current.push(entry);
+    }
+    current.retain(|e| !e.is_delete_marker);
+    current
+}
+
+// splits the versions under the prefix into the folders directly under the prefix and the files in the prefix
+fn split_dirs_and_files(
+    prefix: &str,
+    entries: Vec<VersionEntry>,
+) -> (Vec<String>, Vec<VersionEntry>) {
+    let mut dir_keys = Vec::new();
+    let mut files = Vec::new();
+    for entry in entries {
+        let rest = &entry.key[prefix.len()..];
+        if rest.is_empty() {
+            // the folder object of the prefix itself
+            continue;
+        }
+        match rest.find(DELIMITER) {
+            Some(i) => dir_keys.push(format!("{}{}", prefix, &rest[..=i])),
+            None => files.push(entry),
+        }
+    }
+    dir_keys.dedup();
+    (dir_keys, files)
+}
+
+#[cfg(test)]
+mod tests {
+    use aws_sdk_s3::types::{DeleteMarkerEntry, Grantee, ObjectVersion};
+
+    use super::*;
+
+    #[test]
+    fn test_download_progress_complete_range() {
+        let mut progress = DownloadProgress::default();
+        progress.complete_range(4..8);
+        assert_eq!(progress.offset, 0);
+        progress.complete_range(0..4);
+        assert_eq!(progress.offset, 8);
+        progress.complete_range(12..16);
+        assert_eq!(progress.offset, 8);
+        assert_eq!(progress.resume_state(), None);
+
+        progress.check_e_tag(Some("\"abc\"")).unwrap();
+        assert!(progress.check_e_tag(Some("\"xyz\"")).is_err());
+        assert_eq!(
+            progress.resume_state(),
+            Some(DownloadResumeState {
+                e_tag: "\"abc\"".into(),
+                offset: 8,
+            })
+        );
+    }
+
+    #[test]
+    fn test_is_default_acl() {
+        let owner = Owner::builder().id("owner").build();
+        let grant = |grantee: Grantee, permission: Permission| {
+            Grant::builder()
+                .grantee(grantee)
+                .permission(permission)
+                .build()
+        };
+        let owner_grantee = Grantee::builder()
+            .r#type(Type::CanonicalUser)
+            .id("owner")
+            .build()
+            .unwrap();
+        let all_users_grantee = Grantee::builder()
+            .r#type(Type::Group)
+            .uri("http://acs.amazonaws.com/groups/global/AllUsers")
+            .build()
+            .unwrap();
+
+        let private = vec![grant(owner_grantee.clone(), Permission::FullControl)];
+        assert!(is_default_acl(Some(&owner), &private));
+
+        let public_read = vec![
+            grant(owner_grantee, Permission::FullControl),
+            grant(all_users_grantee, Permission::Read),
+        ];
+        assert!(!is_default_acl(Some(&owner), &public_read));
+    }
+
+    #[test]
+    fn test_build_copy_source() {
+        assert_eq!(build_copy_source("b", "a/b.txt", None), "b/a/b.txt");
+        assert_eq!(
+            build_copy_source("b", "a b+c.txt", Some("v1")),
+            "b/a%20b%2Bc.txt?versionId=v1"
+        );
+    }
+
+    #[test]
+    fn test_object_versions_output_to_versions() {
+        let output = ListObjectVersionsOutput::builder()
+            .versions(object_version("a.txt", "v1", 1))
+            .versions(object_version("a.txt", "v3", 3))
+            .versions(object_version("a.txt.bak", "v4", 4))
+            .delete_markers(delete_marker_entry("a.txt", "v2", 2))
+            .delete_markers(delete_marker_entry("a.txt.bak", "v5", 5))
+            .is_truncated(true)
+            .next_key_marker("a.txt")
+            .next_version_id_marker("v0")
+            .build();
+        let actual = object_versions_output_to_versions("a.txt", &output);
+        let version_ids: Vec<&str> = actual
+            .versions
+            .iter()
+            .map(|v| v.version_id.as_str())
+            .collect();
+        assert_eq!(version_ids, vec!["v3", "v2", "v1"]);
+        assert!(actual.versions[1].is_delete_marker);
+        assert_eq!(actual.next_version_id_marker, Some("v0".into()));
+
+        // the next key marker has moved past the key
+        let output = ListObjectVersionsOutput::builder()
+            .versions(object_version("a.txt", "v1", 1))
+            .is_truncated(true)
+            .next_key_marker("a.txt.bak")
+            .next_version_id_marker("v4")
+            .build();
+        let actual = object_versions_output_to_versions("a.txt", &output);
+        assert_eq!(actual.versions.len(), 1);
+        assert_eq!(actual.next_version_id_marker, None);
+
+        let output = ListObjectVersionsOutput::builder()
+            .versions(object_version("a.txt", "v1", 1))
+            .is_truncated(false)
+            .build();
+        let actual = object_versions_output_to_versions("a.txt", &output);
+        assert_eq!(actual.next_version_id_marker, None);
+    }
+
+    #[test]
+    fn test_object_versions_extend() {
+        let mut versions = object_versions_output_to_versions(
+            "a.txt",
+            &ListObjectVersionsOutput::builder()
+                .versions(object_version("a.txt", "v3", 3))
+                .is_truncated(true)
+                .next_key_marker("a.txt")
+                .next_version_id_marker("v3")
+                .build(),
+        );
+        assert!(versions.has_more());
+
+        versions.extend(object_versions_output_to_versions(
+            "a.txt",
+            &ListObjectVersionsOutput::builder()
+                .versions(object_version("a.txt", "v2", 2))
+                .versions(object_version("a.txt", "v1", 1))
+                .is_truncated(false)
+                .build(),
+        ));
+        let version_ids: Vec<&str> = versions
+            .versions
+            .iter()
+            .map(|v| v.version_id.as_str())
+            .collect();
+        assert_eq!(version_ids, vec!["v3", "v2", "v1"]);
+        assert!(!versions.has_more());
+    }
+
+    fn object_version(key: &str, version_id: &str, secs: i64) -> ObjectVersion {
+        ObjectVersion::builder()
+            .key(key)
+            .version_id(version_id)
+            .size(0)
+            .last_modified(DateTime::from_secs(secs))
+            .is_latest(false)
+            .build()
+    }
+
+    fn delete_marker_entry(key: &str, version_id: &str, secs: i64) -> DeleteMarkerEntry {
+        DeleteMarkerEntry::builder()
+            .key(key)
+            .version_id(version_id)
+            .last_modified(DateTime::from_secs(secs))
+            .is_latest(false)
+            .build()
+    }
+
+    #[test]
+    fn test_current_versions_at() {
+        let entries = vec![
+            version_entry("a", "a1", 1, false),
+            version_entry("a", "a2", 3, false),
+            version_entry("b", "b1", 1, false),
+            version_entry("b", "b2", 2, true),
+            version_entry("c", "c1", 4, false),
+            version_entry("d", "d1", 2, true),
+            version_entry("d", "d2", 1, false),
+        ];
+        let at = chrono::Local.timestamp_opt(2, 0).unwrap();
+        let actual: Vec<(String, String)> = current_versions_at(entries, at)
+            .into_iter()
+            .map(|e| (e.key, e.version_id))
+            .collect();
+        assert_eq!(actual, vec![("a".into(), "a1".into())]);
+    }
+
+    fn version_entry(
+        key: &str,
+        version_id: &str,
+        secs: i64,
+        is_delete_marker: bool,
+    ) -> VersionEntry {
+        VersionEntry {
+            key: key.into(),
+            version_id: version_id.into(),
+            last_modified: chrono::Local.timestamp_opt(secs, 0).unwrap(),
+            size_byte: 0,
+            e_tag: String::new(),
+            is_latest: false,
+            is_delete_marker,
+        }
+    }
+
+    #[test]
+    fn test_current_versions_at_same_last_modified() {
+        let mut entries = vec![
+            version_entry("a", "a1", 1, false),
+            version_entry("a", "a2", 1, true),
+            version_entry("b", "b2", 1, false),
+            version_entry("b", "b1", 1, true),
+        ];
+        entries[2].is_latest = true;
+        let at = chrono::Local.timestamp_opt(1, 0).unwrap();
+        let actual: Vec<(String, String)> = current_versions_at(entries, at)
+            .into_iter()
+            .map(|e| (e.key, e.version_id))
+            .collect();
+        assert_eq!(actual, vec![("b".into(), "b2".into())]);
+    }
+
+    #[test]
+    fn test_split_dirs_and_files() {
+        let entries = vec![
+            version_entry("dir/", "v1", 1, false),
+            version_entry("dir/a.txt", "v2", 1, false),
+            version_entry("dir/sub/", "v3", 1, false),
+            version_entry("dir/sub/b.txt", "v4", 1, false),
+            version_entry("dir/sub2/c/d.txt", "v5", 1, false),
+        ];
+        let (dirs, files) = split_dirs_and_files("dir/", entries);
+        assert_eq!(dirs, vec!["dir/sub/", "dir/sub2/"]);
+        let files: Vec<String> = files.into_iter().map(|e| e.key).collect();
+        assert_eq!(files, vec!["dir/a.txt"]);
+    }
+
+    #[test]
+    fn test_lifecycle_rules_to_json() {
+        use aws_sdk_s3::types::{
+            AbortIncompleteMultipartUpload, ExpirationStatus, LifecycleExpiration,
+            LifecycleRuleFilter, Transition, TransitionStorageClass,
+        };
+
+        let rules = vec![
+            LifecycleRule::builder()
+                .id("logs")
+                .status(ExpirationStatus::Enabled)
+                .filter(LifecycleRuleFilter::builder().prefix("logs/").build())
+                .expiration(LifecycleExpiration::builder().days(365).build())
+                .transitions(
+                    Transition::builder()
+                        .days(30)
+                        .storage_class(TransitionStorageClass::Glacier)
+                        .build(),
+                )
+                .build()
+                .unwrap(),
+            LifecycleRule::builder()
+                .status(ExpirationStatus::Disabled)
+                .filter(LifecycleRuleFilter::builder().prefix("").build())
+                .abort_incomplete_multipart_upload(
+                    AbortIncompleteMultipartUpload::builder()
+                        .days_after_initiation(7)
+                        .build(),
+                )
+                .build()
+                .unwrap(),
+        ];
+
+        let actual = serde_json::to_string_pretty(&lifecycle_rules_to_json(&rules)).unwrap();
+        let expected = r#"{
+  "Rules": [
+    {
+      "Expiration": {
+        "Days": 365
+      },
+      "Filter": {
+        "Prefix": "logs/"
+      },
+      "ID": "logs",
+      "Status": "Enabled",
+      "Transitions": [
+        {
+          "Days": 30,
+          "StorageClass": "GLACIER"
+        }
+      ]
+    },
+    {
+      "AbortIncompleteMultipartUpload": {
+        "DaysAfterInitiation": 7
+      },
+      "Filter": {
+        "Prefix": ""
+      },
+      "Status": "Disabled"
+    }
+  ]
+}"#;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_normalize_bucket_region() {
+        assert_eq!(normalize_bucket_region(""), "us-east-1");
+        assert_eq!(normalize_bucket_region("EU"), "eu-west-1");
+        assert_eq!(normalize_bucket_region("ap-northeast-1"), "ap-northeast-1");
+    }
+
+    #[test]
+    fn test_pretty_print_json() {
+        let policy = r#"{"Version":"2012-10-17","Statement":[{"Effect":"Allow"}]}"#;
+        let expected = r#"{
+  "Statement": [
+    {
+      "Effect": "Allow"
+    }
+  ],
+  "Version": "2012-10-17"
+}"#;
+        assert_eq!(pretty_print_json(policy), expected);
+        assert_eq!(pretty_print_json("not json"), "not json");
+    }
+
+    #[test]
+    fn test_split_ranges() {
+        assert_eq!(split_ranges(0..10, 4), vec![0..4, 4..8, 8..10]);
+        assert_eq!(split_ranges(0..8, 4), vec![0..4, 4..8]);
+        assert_eq!(split_ranges(0..3, 4), vec![0..3]);
+        assert_eq!(split_ranges(5..10, 4), vec![5..9, 9..10]);
+        assert!(split_ranges(0..0, 4).is_empty());
+    }
+}
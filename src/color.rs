@@ -13,6 +13,7 @@ pub struct ColorTheme {
     pub list_selected_inactive_bg: Color,
     pub list_selected_inactive_fg: Color,
     pub list_filter_match: Color,
+    pub list_deleted: Color,
 
     pub detail_selected: Color,
 
@@ -41,6 +42,7 @@ impl Default for ColorTheme {
             list_selected_inactive_bg: Color::DarkGray,
             list_selected_inactive_fg: Color::Black,
             list_filter_match: Color::Red,
+            list_deleted: Color::DarkGray,
 
             detail_selected: Color::Cyan,
 
@@ -27,6 +27,10 @@ pub struct Config {
     pub ui: UiConfig,
     #[nested]
     pub preview: PreviewConfig,
+    #[nested]
+    pub transfer: TransferConfig,
+    #[nested]
+    pub cache: CacheConfig,
 }
 
 #[optional(derives = [Deserialize])]
@@ -61,6 +65,24 @@ pub struct PreviewConfig {
     #[default = "base16-ocean.dark"]
     pub highlight_theme: String,
     pub image: bool,
+    #[default = 104857600] // 100 MiB
+    pub max_size_byte: usize,
+}
+
+#[optional(derives = [Deserialize])]
+#[derive(Debug, Clone, SmartDefault)]
+pub struct TransferConfig {
+    #[default = 8388608] // 8 MiB
+    pub download_part_size_byte: usize,
+    #[default = 8]
+    pub download_concurrency: usize,
+}
+
+#[optional(derives = [Deserialize])]
+#[derive(Debug, Clone, SmartDefault)]
+pub struct CacheConfig {
+    #[default = 604800] // 7 days
+    pub bucket_region_ttl_sec: u64,
 }
 
 fn default_download_dir() -> String {
@@ -3,14 +3,20 @@ use std::{
     path::PathBuf,
     sync::mpsc,
     thread,
+    time::Duration,
 };
 
+use chrono::{DateTime, Local};
 use ratatui::crossterm::event::KeyEvent;
 
 use crate::{
     client::Client,
     error::{AppError, Result},
-    object::{BucketItem, FileDetail, FileVersion, ObjectItem, ObjectKey, RawObject},
+    object::{
+        BucketItem, BucketProperty, BucketPropertyType, CopyMode, CopyTarget, DeleteTarget,
+        FileDetail, FileVersion, ObjectItem, ObjectKey, ObjectMetadata, ObjectSummary, ObjectTag,
+        ObjectVersions, RawObject, StorageClassTarget, TimeTravelObjects, UndeleteTarget,
+    },
 };
 
 #[derive(Debug)]
@@ -19,6 +25,7 @@ pub enum AppEventType {
     Resize(usize, usize),
     Initialize(Client, Option<String>, Option<String>, Option<String>),
     CompleteInitialize(Result<CompleteInitializeResult>),
+    BucketRegionsProgress(usize, usize),
     ReloadBuckets,
     CompleteReloadBuckets(Result<CompleteReloadBucketsResult>),
     LoadObjects,
@@ -29,16 +36,67 @@ pub enum AppEventType {
     CompleteLoadObjectDetail(Result<CompleteLoadObjectDetailResult>),
     LoadObjectVersions,
     CompleteLoadObjectVersions(Result<CompleteLoadObjectVersionsResult>),
+    LoadMoreObjectVersions,
+    CompleteLoadMoreObjectVersions(Result<CompleteLoadObjectVersionsResult>),
+    LoadObjectTags(Option<String>),
+    CompleteLoadObjectTags(Result<CompleteLoadObjectTagsResult>),
+    SaveObjectTags(Option<String>, Vec<ObjectTag>),
+    CompleteSaveObjectTags(Result<CompleteSaveObjectTagsResult>),
+    UpdateObjectMetadata(ObjectMetadata),
+    CompleteUpdateObjectMetadata(Result<CompleteUpdateObjectMetadataResult>),
+    PromoteObjectVersion(FileVersion),
+    CompletePromoteObjectVersion(Result<CompletePromoteObjectVersionResult>),
+    RestoreObject(i32, String, Option<String>),
+    CompleteRestoreObject(Result<CompleteRestoreObjectResult>),
     DownloadObject(FileDetail, Option<String>),
     DownloadObjectAs(FileDetail, String, Option<String>),
     CompleteDownloadObject(Result<CompleteDownloadObjectResult>),
     PreviewObject(FileDetail, Option<String>),
     CompletePreviewObject(Result<CompletePreviewObjectResult>),
+    UploadObject(String),
+    TransferProgress(usize, usize),
+    CompleteTransfer(usize, Result<()>),
+    RetryTransfer(usize),
+    CancelTransfer(usize),
+    LoadDownloadTarget,
+    CompleteLoadDownloadTarget(Result<CompleteLoadDownloadTargetResult>),
+    LoadDeleteTarget,
+    CompleteLoadDeleteTarget(Result<CompleteLoadDeleteTargetResult>),
+    DeleteObjects(DeleteTarget),
+    CompleteDeleteObjects(Result<CompleteDeleteObjectsResult>),
+    LoadStorageClassTarget,
+    CompleteLoadStorageClassTarget(Result<CompleteLoadStorageClassTargetResult>),
+    ChangeStorageClass(StorageClassTarget, String),
+    CompleteChangeStorageClass(Result<CompleteChangeStorageClassResult>),
+    LoadDeletedObjects,
+    CompleteLoadDeletedObjects(Result<CompleteLoadDeletedObjectsResult>),
+    UndeleteObject(ObjectKey),
+    CompleteUndeleteObject(Result<CompleteUndeleteObjectResult>),
+    LoadUndeleteTarget,
+    CompleteLoadUndeleteTarget(Result<CompleteLoadUndeleteTargetResult>),
+    UndeleteObjects(UndeleteTarget),
+    CompleteUndeleteObjects(Result<CompleteUndeleteObjectsResult>),
+    LoadObjectsAt(ObjectKey, DateTime<Local>),
+    CompleteLoadObjectsAt(Result<CompleteLoadObjectsAtResult>),
+    CopyObjects(CopyTarget),
+    CompleteCopyObjects(Result<CompleteCopyObjectsResult>),
+    LoadBucketProperty(String, BucketPropertyType),
+    CompleteLoadBucketProperty(String, BucketPropertyType, Result<BucketProperty>),
+    SaveBucketPropertyJson(String, String),
     BucketListMoveDown,
     BucketListRefresh,
+    BucketListOpenProperties,
     ObjectListMoveDown,
     ObjectListMoveUp,
     ObjectListRefresh,
+    ObjectListUploadObject(String),
+    ObjectListDownloadObject,
+    ObjectListOpenDeleteDialog,
+    ObjectListOpenCopyDialog(CopyMode),
+    ObjectListOpenStorageClassDialog,
+    ObjectListShowDeletedObjects,
+    ObjectListOpenUndeleteDialog,
+    ObjectListExitTimeTravel,
     BackToBucketList,
     OpenObjectVersionsTab,
     OpenPreview(FileDetail, Option<String>),
@@ -52,7 +110,9 @@ pub enum AppEventType {
     ObjectDetailOpenManagementConsole,
     CloseCurrentPage,
     OpenHelp,
+    OpenTransfers,
     CopyToClipboard(String, String),
+    PresignObject(ObjectKey, Option<String>, Duration),
     NotifyInfo(String),
     NotifySuccess(String),
     NotifyWarn(String),
@@ -142,13 +202,13 @@ impl CompleteLoadObjectDetailResult {
 
 #[derive(Debug)]
 pub struct CompleteLoadObjectVersionsResult {
-    pub versions: Vec<FileVersion>,
+    pub versions: ObjectVersions,
     pub map_key: ObjectKey,
 }
 
 impl CompleteLoadObjectVersionsResult {
     pub fn new(
-        versions: Result<Vec<FileVersion>>,
+        versions: Result<ObjectVersions>,
         map_key: ObjectKey,
     ) -> Result<CompleteLoadObjectVersionsResult> {
         let versions = versions?;
@@ -156,16 +216,97 @@ impl CompleteLoadObjectVersionsResult {
     }
 }
 
+#[derive(Debug)]
+pub struct CompleteLoadObjectTagsResult {
+    pub tags: Vec<ObjectTag>,
+    pub version_id: Option<String>,
+}
+
+impl CompleteLoadObjectTagsResult {
+    pub fn new(
+        tags: Result<Vec<ObjectTag>>,
+        version_id: Option<String>,
+    ) -> Result<CompleteLoadObjectTagsResult> {
+        let tags = tags?;
+        Ok(CompleteLoadObjectTagsResult { tags, version_id })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteSaveObjectTagsResult {
+    pub tags: Vec<ObjectTag>,
+}
+
+impl CompleteSaveObjectTagsResult {
+    pub fn new(result: Result<()>, tags: Vec<ObjectTag>) -> Result<CompleteSaveObjectTagsResult> {
+        result?;
+        Ok(CompleteSaveObjectTagsResult { tags })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteUpdateObjectMetadataResult {
+    pub detail: Box<FileDetail>,
+    pub map_key: ObjectKey,
+}
+
+impl CompleteUpdateObjectMetadataResult {
+    pub fn new(
+        detail: Result<FileDetail>,
+        map_key: ObjectKey,
+    ) -> Result<CompleteUpdateObjectMetadataResult> {
+        let detail = Box::new(detail?);
+        Ok(CompleteUpdateObjectMetadataResult { detail, map_key })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompletePromoteObjectVersionResult {
+    pub detail: Box<FileDetail>,
+    pub map_key: ObjectKey,
+}
+
+impl CompletePromoteObjectVersionResult {
+    pub fn new(
+        detail: Result<FileDetail>,
+        map_key: ObjectKey,
+    ) -> Result<CompletePromoteObjectVersionResult> {
+        let detail = Box::new(detail?);
+        Ok(CompletePromoteObjectVersionResult { detail, map_key })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteRestoreObjectResult {
+    pub detail: Box<FileDetail>,
+    pub map_key: ObjectKey,
+    pub version_id: Option<String>,
+}
+
+impl CompleteRestoreObjectResult {
+    pub fn new(
+        detail: Result<FileDetail>,
+        map_key: ObjectKey,
+        version_id: Option<String>,
+    ) -> Result<CompleteRestoreObjectResult> {
+        let detail = Box::new(detail?);
+        Ok(CompleteRestoreObjectResult {
+            detail,
+            map_key,
+            version_id,
+        })
+    }
+}
+
 #[derive(Debug)]
 pub struct CompleteDownloadObjectResult {
-    pub obj: RawObject,
     pub path: PathBuf,
 }
 
 impl CompleteDownloadObjectResult {
-    pub fn new(obj: Result<RawObject>, path: PathBuf) -> Result<CompleteDownloadObjectResult> {
-        let obj = obj?;
-        Ok(CompleteDownloadObjectResult { obj, path })
+    pub fn new(result: Result<()>, path: PathBuf) -> Result<CompleteDownloadObjectResult> {
+        result?;
+        Ok(CompleteDownloadObjectResult { path })
     }
 }
 
@@ -194,6 +335,169 @@ impl CompletePreviewObjectResult {
     }
 }
 
+#[derive(Debug)]
+pub struct CompleteLoadDownloadTargetResult {
+    pub object_key: ObjectKey,
+    pub objects: Vec<ObjectSummary>,
+}
+
+impl CompleteLoadDownloadTargetResult {
+    pub fn new(
+        objects: Result<Vec<ObjectSummary>>,
+        object_key: ObjectKey,
+    ) -> Result<CompleteLoadDownloadTargetResult> {
+        let objects = objects?;
+        Ok(CompleteLoadDownloadTargetResult {
+            object_key,
+            objects,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadDeleteTargetResult {
+    pub target: DeleteTarget,
+}
+
+impl CompleteLoadDeleteTargetResult {
+    pub fn new(target: Result<DeleteTarget>) -> Result<CompleteLoadDeleteTargetResult> {
+        let target = target?;
+        Ok(CompleteLoadDeleteTargetResult { target })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteDeleteObjectsResult {
+    pub target: DeleteTarget,
+}
+
+impl CompleteDeleteObjectsResult {
+    pub fn new(result: Result<()>, target: DeleteTarget) -> Result<CompleteDeleteObjectsResult> {
+        result?;
+        Ok(CompleteDeleteObjectsResult { target })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadStorageClassTargetResult {
+    pub target: StorageClassTarget,
+}
+
+impl CompleteLoadStorageClassTargetResult {
+    pub fn new(target: Result<StorageClassTarget>) -> Result<CompleteLoadStorageClassTargetResult> {
+        let target = target?;
+        Ok(CompleteLoadStorageClassTargetResult { target })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteChangeStorageClassResult {
+    pub target: StorageClassTarget,
+    pub storage_class: String,
+    pub count: usize,
+}
+
+impl CompleteChangeStorageClassResult {
+    pub fn new(
+        count: Result<usize>,
+        target: StorageClassTarget,
+        storage_class: String,
+    ) -> Result<CompleteChangeStorageClassResult> {
+        let count = count?;
+        Ok(CompleteChangeStorageClassResult {
+            target,
+            storage_class,
+            count,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadDeletedObjectsResult {
+    pub items: Vec<ObjectItem>,
+}
+
+impl CompleteLoadDeletedObjectsResult {
+    pub fn new(items: Result<Vec<ObjectItem>>) -> Result<CompleteLoadDeletedObjectsResult> {
+        let items = items?;
+        Ok(CompleteLoadDeletedObjectsResult { items })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteUndeleteObjectResult {
+    pub object_key: ObjectKey,
+}
+
+impl CompleteUndeleteObjectResult {
+    pub fn new(result: Result<()>, object_key: ObjectKey) -> Result<CompleteUndeleteObjectResult> {
+        result?;
+        Ok(CompleteUndeleteObjectResult { object_key })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadUndeleteTargetResult {
+    pub target: UndeleteTarget,
+}
+
+impl CompleteLoadUndeleteTargetResult {
+    pub fn new(target: Result<UndeleteTarget>) -> Result<CompleteLoadUndeleteTargetResult> {
+        let target = target?;
+        Ok(CompleteLoadUndeleteTargetResult { target })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteUndeleteObjectsResult {
+    pub target: UndeleteTarget,
+}
+
+impl CompleteUndeleteObjectsResult {
+    pub fn new(
+        result: Result<()>,
+        target: UndeleteTarget,
+    ) -> Result<CompleteUndeleteObjectsResult> {
+        result?;
+        Ok(CompleteUndeleteObjectsResult { target })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteLoadObjectsAtResult {
+    pub objects: TimeTravelObjects,
+    pub object_key: ObjectKey,
+    pub at: DateTime<Local>,
+}
+
+impl CompleteLoadObjectsAtResult {
+    pub fn new(
+        objects: Result<TimeTravelObjects>,
+        object_key: ObjectKey,
+        at: DateTime<Local>,
+    ) -> Result<CompleteLoadObjectsAtResult> {
+        let objects = objects?;
+        Ok(CompleteLoadObjectsAtResult {
+            objects,
+            object_key,
+            at,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct CompleteCopyObjectsResult {
+    pub target: CopyTarget,
+    pub count: usize,
+}
+
+impl CompleteCopyObjectsResult {
+    pub fn new(count: Result<usize>, target: CopyTarget) -> Result<CompleteCopyObjectsResult> {
+        let count = count?;
+        Ok(CompleteCopyObjectsResult { target, count })
+    }
+}
+
 #[derive(Clone)]
 pub struct Sender {
     tx: mpsc::Sender<AppEventType>,
@@ -1,9 +1,10 @@
 use arboard::Clipboard;
 use chrono::Local;
+use serde::{Deserialize, Serialize};
 use std::{
     fs::{File, OpenOptions},
     io::{BufWriter, Write},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 use crate::error::{AppError, Result};
@@ -43,7 +44,7 @@ pub fn open_or_create_append_file<P: AsRef<Path>>(path: P) -> std::io::Result<Fi
     OpenOptions::new().create(true).append(true).open(path)
 }
 
-fn create_dirs<P: AsRef<Path>>(path: P) -> Result<()> {
+pub fn create_dirs<P: AsRef<Path>>(path: P) -> Result<()> {
     match path.as_ref().parent() {
         Some(path) => std::fs::create_dir_all(path)
             .map_err(|e| AppError::new("Failed to create directories", e)),
@@ -51,6 +52,103 @@ fn create_dirs<P: AsRef<Path>>(path: P) -> Result<()> {
     }
 }
 
+// objects are written to this path first and renamed once the download completes
+pub fn download_temp_path<P: AsRef<Path>>(path: P) -> PathBuf {
+    let path = path.as_ref();
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{}.download", name))
+}
+
+// the progress of an interrupted download, saved next to its temp file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DownloadResumeState {
+    pub e_tag: String,
+    pub offset: usize, // the temp file is complete up to this byte
+}
+
+pub fn download_resume_path<P: AsRef<Path>>(path: P) -> PathBuf {
+    let path = path.as_ref();
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{}.download.resume", name))
+}
+
+pub fn load_download_resume_state<P: AsRef<Path>>(path: P) -> Option<DownloadResumeState> {
+    let content = std::fs::read_to_string(path).ok()?;
+    toml::from_str(&content).ok()
+}
+
+pub fn save_download_resume_state<P: AsRef<Path>>(
+    path: P,
+    state: &DownloadResumeState,
+) -> Result<()> {
+    let content =
+        toml::to_string(state).map_err(|e| AppError::new("Failed to serialize state", e))?;
+    std::fs::write(path, content).map_err(|e| AppError::new("Failed to write file", e))
+}
+
+#[derive(Debug, Clone)]
+pub struct LocalFile {
+    pub path: PathBuf,
+    pub key: String, // relative to the parent of the selected path, joined with '/'
+    pub size_byte: usize,
+}
+
+pub fn expand_home_dir(path: &str) -> PathBuf {
+    match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(std::path::MAIN_SEPARATOR) => {
+            match dirs::home_dir() {
+                Some(home) => home.join(rest.trim_start_matches(std::path::MAIN_SEPARATOR)),
+                None => PathBuf::from(path),
+            }
+        }
+        _ => PathBuf::from(path),
+    }
+}
+
+pub fn collect_local_files<P: AsRef<Path>>(path: P) -> Result<Vec<LocalFile>> {
+    let path = path.as_ref();
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .ok_or_else(|| AppError::msg(format!("Invalid path: {}", path.to_string_lossy())))?;
+
+    let mut files = Vec::new();
+    collect_local_files_into(path, name, &mut files)?;
+    files.sort_by(|a, b| a.key.cmp(&b.key));
+    Ok(files)
+}
+
+fn collect_local_files_into(path: &Path, key: String, files: &mut Vec<LocalFile>) -> Result<()> {
+    let metadata =
+        std::fs::metadata(path).map_err(|e| AppError::new("Failed to read file metadata", e))?;
+
+    if metadata.is_file() {
+        files.push(LocalFile {
+            path: path.to_path_buf(),
+            key,
+            size_byte: metadata.len() as usize,
+        });
+    } else if metadata.is_dir() {
+        let entries =
+            std::fs::read_dir(path).map_err(|e| AppError::new("Failed to read directory", e))?;
+        for entry in entries {
+            let entry = entry.map_err(|e| AppError::new("Failed to read directory", e))?;
+            let file_type = entry
+                .file_type()
+                .map_err(|e| AppError::new("Failed to read file metadata", e))?;
+            if file_type.is_symlink() && entry.path().is_dir() {
+                // do not follow directory links to avoid cycles
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().to_string();
+            let key = format!("{}/{}", key, name);
+            collect_local_files_into(&entry.path(), key, files)?;
+        }
+    }
+
+    Ok(())
+}
+
 pub fn copy_to_clipboard(value: String) -> Result<()> {
     Clipboard::new()
         .and_then(|mut c| c.set_text(value))
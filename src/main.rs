@@ -12,11 +12,12 @@ mod macros;
 mod object;
 mod pages;
 mod run;
+mod transfer;
 mod ui;
 mod util;
 mod widget;
 
-use clap::{arg, Parser, ValueEnum};
+use clap::{Parser, ValueEnum};
 use event::AppEventType;
 use file::open_or_create_append_file;
 use ratatui::{backend::Backend, Terminal};
@@ -71,6 +72,10 @@ struct Args {
     #[arg(short = 'x', long, value_name = "PREFIX")]
     prefix: Option<String>,
 
+    /// List buckets in all regions, not only in the specified region
+    #[arg(long)]
+    all_regions: bool,
+
     /// Path style type for object paths
     #[arg(long, value_name = "TYPE", default_value = "auto")]
     path_style: PathStyle,
@@ -110,6 +115,8 @@ async fn run<B: Backend>(
     let (tx, rx) = event::new();
     let (width, height) = get_frame_size(terminal);
     let default_region_fallback = ctx.config.default_region.clone();
+    let transfer_config = ctx.config.transfer.clone();
+    let cache_config = ctx.config.cache.clone();
 
     let mut app = App::new(ctx, tx.clone(), width, height);
 
@@ -121,6 +128,9 @@ async fn run<B: Backend>(
             args.profile,
             default_region_fallback,
             args.path_style.into(),
+            args.all_regions,
+            transfer_config,
+            cache_config,
         )
         .await;
         let bucket = args.bucket.clone();
@@ -4,6 +4,7 @@ use std::{
 };
 
 use chrono::{DateTime, Local};
+use itsuki::zero_indexed_enum;
 
 #[derive(Clone, Debug)]
 pub struct BucketItem {
@@ -12,6 +13,8 @@ pub struct BucketItem {
     pub arn: String,
     pub object_url: String,
     pub prefix: Option<String>,
+    // only set when buckets in every region are listed
+    pub region: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -57,7 +60,7 @@ impl ObjectItem {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct FileDetail {
     pub name: String,
     pub size_byte: usize,
@@ -69,6 +72,86 @@ pub struct FileDetail {
     pub s3_uri: String,
     pub arn: String,
     pub object_url: String,
+    pub cache_control: String,
+    pub content_encoding: String,
+    pub content_disposition: String,
+    pub expires: String,
+    pub user_metadata: Vec<(String, String)>, // sorted by key, without the x-amz-meta- prefix
+    pub server_side_encryption: String,
+    pub sse_kms_key_id: String,
+    pub checksums: Vec<(String, String)>, // (algorithm, value)
+    pub object_lock_mode: String,
+    pub object_lock_retain_until: Option<DateTime<Local>>,
+    pub object_lock_legal_hold: String,
+    pub replication_status: String,
+    pub restore: String,
+}
+
+impl FileDetail {
+    pub fn is_archived(&self) -> bool {
+        is_archived_storage_class(&self.storage_class)
+    }
+
+    pub fn restore_status(&self) -> RestoreStatus {
+        RestoreStatus::parse(&self.restore)
+    }
+
+    pub fn is_readable(&self) -> bool {
+        !self.is_archived() || matches!(self.restore_status(), RestoreStatus::Restored(_))
+    }
+}
+
+// objects in these storage classes cannot be read until they are restored
+fn is_archived_storage_class(storage_class: &str) -> bool {
+    matches!(storage_class, "GLACIER" | "DEEP_ARCHIVE")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RestoreStatus {
+    NotRestored,
+    InProgress,
+    Restored(Option<DateTime<Local>>), // expiry date of the restored copy
+}
+
+impl RestoreStatus {
+    // parse the x-amz-restore header value
+    // e.g. ongoing-request="false", expiry-date="Fri, 21 Dec 2012 00:00:00 GMT"
+    fn parse(s: &str) -> RestoreStatus {
+        if s.is_empty() {
+            return RestoreStatus::NotRestored;
+        }
+        if s.contains(r#"ongoing-request="true""#) {
+            return RestoreStatus::InProgress;
+        }
+        let expiry_date = s
+            .split_once(r#"expiry-date=""#)
+            .and_then(|(_, rest)| rest.split_once('"'))
+            .and_then(|(date, _)| DateTime::parse_from_rfc2822(date).ok())
+            .map(|date| date.with_timezone(&Local));
+        RestoreStatus::Restored(expiry_date)
+    }
+}
+
+// system and user metadata which can be rewritten by copying the object onto itself
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ObjectMetadata {
+    pub content_type: String,
+    pub cache_control: String,
+    pub content_encoding: String,
+    pub content_disposition: String,
+    pub user_metadata: Vec<(String, String)>,
+}
+
+impl From<&FileDetail> for ObjectMetadata {
+    fn from(detail: &FileDetail) -> Self {
+        ObjectMetadata {
+            content_type: detail.content_type.clone(),
+            cache_control: detail.cache_control.clone(),
+            content_encoding: detail.content_encoding.clone(),
+            content_disposition: detail.content_disposition.clone(),
+            user_metadata: detail.user_metadata.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -76,8 +159,274 @@ pub struct FileVersion {
     pub version_id: String,
     pub size_byte: usize,
     pub last_modified: DateTime<Local>,
-    #[allow(dead_code)]
     pub is_latest: bool,
+    pub is_delete_marker: bool,
+}
+
+// versions of an object loaded so far, newest first
+#[derive(Debug, Clone, Default)]
+pub struct ObjectVersions {
+    pub versions: Vec<FileVersion>,
+    pub next_version_id_marker: Option<String>, // None if all versions have been loaded
+}
+
+impl ObjectVersions {
+    pub fn has_more(&self) -> bool {
+        self.next_version_id_marker.is_some()
+    }
+
+    pub fn extend(&mut self, more: ObjectVersions) {
+        self.versions.extend(more.versions);
+        self.next_version_id_marker = more.next_version_id_marker;
+    }
+}
+
+// objects under a prefix as they existed at a point in time
+#[derive(Debug, Clone, Default)]
+pub struct TimeTravelObjects {
+    pub items: Vec<ObjectItem>,
+    pub version_ids: HashMap<String, String>, // key -> version id current at that time
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectTag {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ObjectSummary {
+    pub key: String,
+    pub size_byte: usize,
+    pub storage_class: String, // empty if unknown
+    pub restored: bool,        // a restored copy of the archived object is available
+}
+
+impl ObjectSummary {
+    pub fn is_readable(&self) -> bool {
+        !is_archived_storage_class(&self.storage_class) || self.restored
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteTarget {
+    pub object_key: ObjectKey,
+    pub dir: bool,
+    pub objects: Vec<ObjectSummary>,
+    pub version_id: Option<String>,
+}
+
+impl DeleteTarget {
+    pub fn s3_uri(&self) -> String {
+        format!(
+            "s3://{}/{}",
+            self.object_key.bucket_name,
+            self.object_key.joined_object_path(!self.dir)
+        )
+    }
+
+    pub fn total_size_byte(&self) -> usize {
+        self.objects.iter().map(|o| o.size_byte).sum()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StorageClassTarget {
+    pub object_key: ObjectKey,
+    pub dir: bool,
+    pub objects: Vec<ObjectSummary>,
+}
+
+impl StorageClassTarget {
+    pub fn s3_uri(&self) -> String {
+        format!(
+            "s3://{}/{}",
+            self.object_key.bucket_name,
+            self.object_key.joined_object_path(!self.dir)
+        )
+    }
+
+    // objects already in the storage class are left as they are,
+    // and archived objects cannot be copied until they are restored
+    pub fn objects_to_change(&self, storage_class: &str) -> Vec<&ObjectSummary> {
+        self.objects
+            .iter()
+            .filter(|o| o.storage_class != storage_class && o.is_readable())
+            .collect()
+    }
+
+    pub fn unrestored_objects(&self, storage_class: &str) -> Vec<&ObjectSummary> {
+        self.objects
+            .iter()
+            .filter(|o| o.storage_class != storage_class && !o.is_readable())
+            .collect()
+    }
+
+    // returns (storage class, object count, total size) of the objects moving out of each class
+    pub fn moving_out_summary(&self, storage_class: &str) -> Vec<(String, usize, usize)> {
+        let mut summary: Vec<(String, usize, usize)> = Vec::new();
+        for obj in self.objects_to_change(storage_class) {
+            match summary.iter_mut().find(|(c, _, _)| *c == obj.storage_class) {
+                Some((_, count, size_byte)) => {
+                    *count += 1;
+                    *size_byte += obj.size_byte;
+                }
+                None => summary.push((obj.storage_class.clone(), 1, obj.size_byte)),
+            }
+        }
+        summary.sort_by(|a, b| a.0.cmp(&b.0));
+        summary
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteMarker {
+    pub key: String,
+    pub version_id: String,
+    pub last_modified: DateTime<Local>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UndeleteTarget {
+    pub object_key: ObjectKey,
+    pub markers: Vec<DeleteMarker>, // the latest delete markers of the keys under the prefix
+}
+
+impl UndeleteTarget {
+    pub fn s3_uri(&self) -> String {
+        format!(
+            "s3://{}/{}",
+            self.object_key.bucket_name,
+            self.object_key.joined_object_path(false)
+        )
+    }
+
+    // all markers if no time is given
+    pub fn markers_created_after(&self, after: Option<DateTime<Local>>) -> Vec<&DeleteMarker> {
+        self.markers
+            .iter()
+            .filter(|m| after.is_none_or(|after| m.last_modified > after))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyMode {
+    Copy,
+    Move,
+}
+
+#[derive(Debug, Clone)]
+pub struct CopyTarget {
+    pub object_key: ObjectKey,
+    pub dir: bool,
+    pub dst_bucket: String,
+    pub dst_prefix: String, // empty or ends with '/'
+    pub mode: CopyMode,
+}
+
+impl CopyTarget {
+    pub fn s3_uri(&self) -> String {
+        format!(
+            "s3://{}/{}",
+            self.object_key.bucket_name,
+            self.object_key.joined_object_path(!self.dir)
+        )
+    }
+
+    pub fn dst_s3_uri(&self) -> String {
+        format!("s3://{}/{}", self.dst_bucket, self.dst_prefix)
+    }
+
+    // the selected file or directory is placed directly under the destination prefix
+    pub fn dst_key(&self, src_key: &str) -> String {
+        let base = self.base_prefix();
+        let relative = src_key.strip_prefix(&base).unwrap_or(src_key);
+        format!("{}{}", self.dst_prefix, relative)
+    }
+
+    pub fn is_same_location(&self) -> bool {
+        self.object_key.bucket_name == self.dst_bucket && self.base_prefix() == self.dst_prefix
+    }
+
+    fn base_prefix(&self) -> String {
+        let parent = &self.object_key.object_path[..self.object_key.object_path.len() - 1];
+        parent.iter().map(|p| format!("{}/", p)).collect()
+    }
+}
+
+#[zero_indexed_enum]
+pub enum BucketPropertyType {
+    Versioning,
+    Encryption,
+    PublicAccessBlock,
+    ObjectLock,
+    Tags,
+    Logging,
+    Website,
+    Cors,
+    Lifecycle,
+    Policy,
+}
+
+impl BucketPropertyType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Versioning => "Versioning",
+            Self::Encryption => "Default encryption",
+            Self::PublicAccessBlock => "Block public access",
+            Self::ObjectLock => "Object Lock",
+            Self::Tags => "Tags",
+            Self::Logging => "Server access logging",
+            Self::Website => "Static website hosting",
+            Self::Cors => "CORS",
+            Self::Lifecycle => "Lifecycle rules",
+            Self::Policy => "Bucket policy",
+        }
+    }
+}
+
+// a property is shown either as a list of fields, nested ones are indented by depth,
+// or as a JSON document (bucket policy and lifecycle configuration).
+// both are empty if the property is not configured
+#[derive(Debug, Clone, Default)]
+pub struct BucketProperty {
+    pub fields: Vec<BucketPropertyField>,
+    pub json: Option<String>, // pretty-printed
+}
+
+impl BucketProperty {
+    pub fn from_fields(fields: Vec<BucketPropertyField>) -> Self {
+        Self { fields, json: None }
+    }
+
+    pub fn from_json(json: Option<String>) -> Self {
+        Self {
+            fields: Vec::new(),
+            json,
+        }
+    }
+
+    pub fn is_configured(&self) -> bool {
+        !self.fields.is_empty() || self.json.is_some()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BucketPropertyField {
+    pub name: String,
+    pub value: String, // empty for a field that only groups the following nested fields
+    pub depth: usize,
+}
+
+impl BucketPropertyField {
+    pub fn new(name: impl Into<String>, value: impl Into<String>, depth: usize) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+            depth,
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -85,7 +434,7 @@ pub struct AppObjects {
     bucket_items: Vec<BucketItem>,
     object_items_map: HashMap<ObjectKey, Vec<ObjectItem>>,
     detail_map: HashMap<ObjectKey, FileDetail>,
-    versions_map: HashMap<ObjectKey, Vec<FileVersion>>,
+    versions_map: HashMap<ObjectKey, ObjectVersions>,
 }
 
 impl AppObjects {
@@ -109,7 +458,7 @@ impl AppObjects {
         self.detail_map.get(key)
     }
 
-    pub fn get_object_versions(&self, key: &ObjectKey) -> Option<&Vec<FileVersion>> {
+    pub fn get_object_versions(&self, key: &ObjectKey) -> Option<&ObjectVersions> {
         self.versions_map.get(key)
     }
 
@@ -117,10 +466,18 @@ impl AppObjects {
         self.detail_map.insert(key, detail);
     }
 
-    pub fn set_object_versions(&mut self, key: ObjectKey, versions: Vec<FileVersion>) {
+    pub fn set_object_versions(&mut self, key: ObjectKey, versions: ObjectVersions) {
         self.versions_map.insert(key, versions);
     }
 
+    pub fn extend_object_versions(&mut self, key: ObjectKey, versions: ObjectVersions) {
+        self.versions_map.entry(key).or_default().extend(versions);
+    }
+
+    pub fn clear_object_versions(&mut self, key: &ObjectKey) {
+        self.versions_map.remove(key);
+    }
+
     pub fn clear_object_items_under(&mut self, key: &ObjectKey) {
         self.object_items_map.retain(|k, _| !k.has_prefix(key));
         self.detail_map.retain(|k, _| !k.has_prefix(key));
@@ -240,6 +597,154 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[rstest]
+    #[case("", RestoreStatus::NotRestored)]
+    #[case(r#"ongoing-request="true""#, RestoreStatus::InProgress)]
+    #[case(
+        r#"ongoing-request="false", expiry-date="Fri, 21 Dec 2012 00:00:00 GMT""#,
+        RestoreStatus::Restored(Some(DateTime::parse_from_rfc3339("2012-12-21T00:00:00Z").unwrap().with_timezone(&Local)))
+    )]
+    #[case(r#"ongoing-request="false""#, RestoreStatus::Restored(None))]
+    fn test_restore_status_parse(#[case] s: &str, #[case] expected: RestoreStatus) {
+        assert_eq!(RestoreStatus::parse(s), expected);
+    }
+
+    #[rstest]
+    #[case("STANDARD", "", true)]
+    #[case("GLACIER_IR", "", true)]
+    #[case("GLACIER", "", false)]
+    #[case("DEEP_ARCHIVE", r#"ongoing-request="true""#, false)]
+    #[case(
+        "GLACIER",
+        r#"ongoing-request="false", expiry-date="Fri, 21 Dec 2012 00:00:00 GMT""#,
+        true
+    )]
+    fn test_file_detail_is_readable(
+        #[case] storage_class: &str,
+        #[case] restore: &str,
+        #[case] expected: bool,
+    ) {
+        let detail = FileDetail {
+            storage_class: storage_class.to_string(),
+            restore: restore.to_string(),
+            ..Default::default()
+        };
+        assert_eq!(detail.is_readable(), expected);
+    }
+
+    #[rstest]
+    #[case(&["a", "b", "c.txt"], false, "x/", "a/b/c.txt", "x/c.txt")]
+    #[case(&["a", "b"], true, "x/", "a/b/c/d.txt", "x/b/c/d.txt")]
+    #[case(&["a", "b"], true, "", "a/b/c.txt", "b/c.txt")]
+    #[case(&["a"], false, "x/y/", "a", "x/y/a")]
+    fn test_copy_target_dst_key(
+        #[case] object_path: &[&str],
+        #[case] dir: bool,
+        #[case] dst_prefix: &str,
+        #[case] src_key: &str,
+        #[case] expected: &str,
+    ) {
+        let target = CopyTarget {
+            object_key: object_key("foo", object_path),
+            dir,
+            dst_bucket: "bar".to_string(),
+            dst_prefix: dst_prefix.to_string(),
+            mode: CopyMode::Copy,
+        };
+        assert_eq!(target.dst_key(src_key), expected);
+    }
+
+    #[rstest]
+    #[case(None, &["a", "b", "c"])]
+    #[case(Some(1), &["b", "c"])]
+    #[case(Some(2), &["c"])]
+    #[case(Some(3), &[])]
+    fn test_undelete_target_markers_created_after(
+        #[case] after_secs: Option<i64>,
+        #[case] expected: &[&str],
+    ) {
+        use chrono::TimeZone;
+
+        let marker = |key: &str, secs: i64| DeleteMarker {
+            key: key.to_string(),
+            version_id: format!("{}-v", key),
+            last_modified: Local.timestamp_opt(secs, 0).unwrap(),
+        };
+        let target = UndeleteTarget {
+            object_key: object_key("foo", &["dir"]),
+            markers: vec![marker("a", 1), marker("b", 2), marker("c", 3)],
+        };
+        let after = after_secs.map(|secs| Local.timestamp_opt(secs, 0).unwrap());
+        let actual: Vec<&str> = target
+            .markers_created_after(after)
+            .iter()
+            .map(|m| m.key.as_str())
+            .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_storage_class_target_objects_to_change() {
+        let target = storage_class_target();
+
+        let keys = |objects: Vec<&ObjectSummary>| -> Vec<String> {
+            objects.iter().map(|o| o.key.clone()).collect()
+        };
+        assert_eq!(
+            keys(target.objects_to_change("STANDARD_IA")),
+            vec!["a", "c", "e"]
+        );
+        assert_eq!(keys(target.unrestored_objects("STANDARD_IA")), vec!["d"]);
+        assert_eq!(keys(target.objects_to_change("STANDARD")), vec!["b", "e"]);
+        // the archived object which has not been restored is left even if it is in another class
+        assert_eq!(
+            keys(target.objects_to_change("GLACIER")),
+            vec!["a", "b", "c"]
+        );
+        assert!(target.unrestored_objects("GLACIER").is_empty());
+    }
+
+    #[test]
+    fn test_storage_class_target_moving_out_summary() {
+        let target = storage_class_target();
+
+        assert_eq!(
+            target.moving_out_summary("STANDARD_IA"),
+            vec![
+                ("GLACIER".to_string(), 1, 8),
+                ("STANDARD".to_string(), 2, 3),
+            ]
+        );
+        assert_eq!(
+            target.moving_out_summary("GLACIER"),
+            vec![
+                ("STANDARD".to_string(), 2, 3),
+                ("STANDARD_IA".to_string(), 1, 4),
+            ]
+        );
+    }
+
+    fn storage_class_target() -> StorageClassTarget {
+        let summary =
+            |key: &str, size_byte: usize, storage_class: &str, restored: bool| ObjectSummary {
+                key: key.to_string(),
+                size_byte,
+                storage_class: storage_class.to_string(),
+                restored,
+            };
+        StorageClassTarget {
+            object_key: object_key("foo", &["dir"]),
+            dir: true,
+            objects: vec![
+                summary("a", 1, "STANDARD", false),
+                summary("b", 4, "STANDARD_IA", false),
+                summary("c", 2, "STANDARD", false),
+                summary("d", 16, "GLACIER", false),
+                summary("e", 8, "GLACIER", true),
+            ],
+        }
+    }
+
     fn object_key(bucket_name: &str, object_path: &[&str]) -> ObjectKey {
         ObjectKey {
             bucket_name: bucket_name.to_string(),
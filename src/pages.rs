@@ -1,10 +1,12 @@
 pub mod page;
 
+pub mod bucket_detail;
 pub mod bucket_list;
 pub mod help;
 pub mod initializing;
 pub mod object_detail;
 pub mod object_list;
 pub mod object_preview;
+pub mod transfers;
 
 mod util;
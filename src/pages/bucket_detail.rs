@@ -0,0 +1,605 @@
+use std::rc::Rc;
+
+use laurier::{key_code, key_code_char};
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style, Stylize},
+    text::Line,
+    widgets::{Block, ListItem, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::{
+    app::AppContext,
+    color::ColorTheme,
+    error::Result,
+    event::{AppEventType, Sender},
+    object::{BucketProperty, BucketPropertyType},
+    pages::util::{build_helps, build_short_helps},
+    widget::{
+        build_text_lines, ScrollLines, ScrollLinesOptions, ScrollLinesState, ScrollList,
+        ScrollListState,
+    },
+};
+
+const PROPERTY_LIST_WIDTH: u16 = 30;
+
+// only used to choose the syntax for highlighting
+const JSON_FILE_NAME: &str = "property.json";
+
+#[derive(Debug)]
+pub struct BucketDetailPage {
+    bucket_name: String,
+    // indexed by BucketPropertyType::val
+    properties: Vec<PropertyState>,
+
+    focus: Focus,
+    list_state: ScrollListState,
+
+    ctx: Rc<AppContext>,
+    tx: Sender,
+}
+
+#[derive(Debug, Default)]
+enum PropertyState {
+    #[default]
+    NotLoaded,
+    Loading,
+    Loaded {
+        lines: ScrollLinesState,
+        json: Option<String>,
+    },
+    Failed(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Focus {
+    List,
+    Content,
+}
+
+impl BucketDetailPage {
+    pub fn new(bucket_name: String, ctx: Rc<AppContext>, tx: Sender) -> Self {
+        let properties_len = BucketPropertyType::len();
+        Self {
+            bucket_name,
+            properties: (0..properties_len)
+                .map(|_| PropertyState::default())
+                .collect(),
+            focus: Focus::List,
+            list_state: ScrollListState::new(properties_len),
+            ctx,
+            tx,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        match key {
+            key_code!(KeyCode::Esc) => {
+                self.tx.send(AppEventType::Quit);
+            }
+            key_code!(KeyCode::Backspace) => {
+                self.tx.send(AppEventType::CloseCurrentPage);
+            }
+            key_code_char!('h') => {
+                self.focus = Focus::List;
+            }
+            key_code_char!('l') => {
+                self.focus = Focus::Content;
+            }
+            key_code_char!('j') => match self.focus {
+                Focus::List => {
+                    self.list_state.select_next();
+                    self.load_selected_property();
+                }
+                Focus::Content => {
+                    self.with_selected_lines(ScrollLinesState::scroll_forward);
+                }
+            },
+            key_code_char!('k') => match self.focus {
+                Focus::List => {
+                    self.list_state.select_prev();
+                    self.load_selected_property();
+                }
+                Focus::Content => {
+                    self.with_selected_lines(ScrollLinesState::scroll_backward);
+                }
+            },
+            key_code_char!('g') => match self.focus {
+                Focus::List => {
+                    self.list_state.select_first();
+                    self.load_selected_property();
+                }
+                Focus::Content => {
+                    self.with_selected_lines(ScrollLinesState::scroll_to_top);
+                }
+            },
+            key_code_char!('G') => match self.focus {
+                Focus::List => {
+                    self.list_state.select_last();
+                    self.load_selected_property();
+                }
+                Focus::Content => {
+                    self.with_selected_lines(ScrollLinesState::scroll_to_end);
+                }
+            },
+            key_code_char!('f') if self.focus == Focus::Content => {
+                self.with_selected_lines(ScrollLinesState::scroll_page_forward);
+            }
+            key_code_char!('b') if self.focus == Focus::Content => {
+                self.with_selected_lines(ScrollLinesState::scroll_page_backward);
+            }
+            key_code_char!('R') => {
+                self.reload_selected_property();
+            }
+            key_code_char!('r') if self.selected_json().is_some() => {
+                self.copy_json_to_clipboard();
+            }
+            key_code_char!('s') if self.selected_json().is_some() => {
+                self.save_json();
+            }
+            key_code_char!('?') => {
+                self.tx.send(AppEventType::OpenHelp);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let chunks =
+            Layout::horizontal([Constraint::Length(PROPERTY_LIST_WIDTH), Constraint::Min(0)])
+                .split(area);
+
+        let list_items = build_list_items(
+            self.list_state.offset,
+            self.list_state.selected,
+            self.focus == Focus::List,
+            chunks[0],
+            &self.ctx.theme,
+        );
+        let list = ScrollList::new(list_items)
+            .title(format!(" {} ", self.bucket_name))
+            .theme(&self.ctx.theme);
+        f.render_stateful_widget(list, chunks[0], &mut self.list_state);
+
+        let property_type = self.selected_property_type();
+        let block = Block::bordered()
+            .title(format!(" {} ", property_type.name()))
+            .fg(self.ctx.theme.fg);
+        match &mut self.properties[property_type.val()] {
+            PropertyState::Loaded { lines: state, .. } => {
+                let scroll_lines = ScrollLines::default().block(block).theme(&self.ctx.theme);
+                f.render_stateful_widget(scroll_lines, chunks[1], state);
+            }
+            PropertyState::NotLoaded | PropertyState::Loading => {
+                let paragraph =
+                    Paragraph::new("Loading...").block(block.padding(Padding::horizontal(1)));
+                f.render_widget(paragraph, chunks[1]);
+            }
+            PropertyState::Failed(msg) => {
+                // the error is shown only in its own section
+                let paragraph = Paragraph::new(msg.as_str().fg(self.ctx.theme.status_error))
+                    .wrap(Wrap { trim: false })
+                    .block(block.padding(Padding::horizontal(1)));
+                f.render_widget(paragraph, chunks[1]);
+            }
+        }
+    }
+
+    pub fn helps(&self) -> Vec<String> {
+        let helps: &[(&[&str], &str)] = &[
+            (&["Esc", "Ctrl-c"], "Quit app"),
+            (&["h/l"], "Focus property list/content"),
+            (&["j/k"], "Select property / Scroll content"),
+            (&["g/G"], "Go to top/bottom"),
+            (&["f"], "Scroll content page forward"),
+            (&["b"], "Scroll content page backward"),
+            (&["R"], "Reload selected property"),
+            (&["r"], "Copy JSON to clipboard"),
+            (&["s"], "Save JSON to download directory"),
+            (&["Backspace"], "Close current page"),
+        ];
+        build_helps(helps)
+    }
+
+    pub fn short_helps(&self) -> Vec<(String, usize)> {
+        let helps: &[(&[&str], &str, usize)] = match self.focus {
+            Focus::List => &[
+                (&["Esc"], "Quit", 0),
+                (&["j/k"], "Select", 1),
+                (&["l"], "Focus content", 2),
+                (&["R"], "Reload", 4),
+                (&["Backspace"], "Close", 3),
+                (&["?"], "Help", 0),
+            ],
+            Focus::Content => &[
+                (&["Esc"], "Quit", 0),
+                (&["j/k"], "Scroll", 1),
+                (&["h"], "Focus list", 2),
+                (&["R"], "Reload", 4),
+                (&["r"], "Copy", 5),
+                (&["s"], "Save", 5),
+                (&["Backspace"], "Close", 3),
+                (&["?"], "Help", 0),
+            ],
+        };
+        build_short_helps(helps)
+    }
+}
+
+impl BucketDetailPage {
+    // properties are loaded when they are selected for the first time
+    pub fn load_selected_property(&mut self) {
+        let property_type = self.selected_property_type();
+        let state = &mut self.properties[property_type.val()];
+        if let PropertyState::NotLoaded = state {
+            *state = PropertyState::Loading;
+            self.tx.send(AppEventType::LoadBucketProperty(
+                self.bucket_name.clone(),
+                property_type,
+            ));
+        }
+    }
+
+    fn reload_selected_property(&mut self) {
+        let property_type = self.selected_property_type();
+        let state = &mut self.properties[property_type.val()];
+        if let PropertyState::Loading = state {
+            return;
+        }
+        *state = PropertyState::NotLoaded;
+        self.load_selected_property();
+    }
+
+    pub fn set_property(
+        &mut self,
+        property_type: BucketPropertyType,
+        result: Result<BucketProperty>,
+    ) {
+        self.properties[property_type.val()] = match result {
+            Ok(BucketProperty {
+                json: Some(json), ..
+            }) => {
+                let (lines, warn_msg) = build_text_lines(
+                    &json,
+                    JSON_FILE_NAME,
+                    self.ctx.config.preview.highlight,
+                    &self.ctx.config.preview.highlight_theme,
+                );
+                if let Some(msg) = warn_msg {
+                    self.tx.send(AppEventType::NotifyWarn(msg));
+                }
+                let lines = ScrollLinesState::new(lines, ScrollLinesOptions::default());
+                let json = Some(json);
+                PropertyState::Loaded { lines, json }
+            }
+            Ok(property) => {
+                let lines = build_property_lines(&property);
+                let lines = ScrollLinesState::new(lines, ScrollLinesOptions::new(false, true));
+                PropertyState::Loaded { lines, json: None }
+            }
+            Err(e) => PropertyState::Failed(e.msg),
+        };
+    }
+
+    fn copy_json_to_clipboard(&self) {
+        if let Some(json) = self.selected_json() {
+            let name = self.selected_property_type().name().to_string();
+            self.tx
+                .send(AppEventType::CopyToClipboard(name, json.to_string()));
+        }
+    }
+
+    fn save_json(&self) {
+        if let Some(json) = self.selected_json() {
+            let name = build_json_file_name(&self.bucket_name, self.selected_property_type());
+            self.tx
+                .send(AppEventType::SaveBucketPropertyJson(name, json.to_string()));
+        }
+    }
+
+    fn selected_json(&self) -> Option<&str> {
+        match &self.properties[self.selected_property_type().val()] {
+            PropertyState::Loaded { json, .. } => json.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn bucket_name(&self) -> &str {
+        &self.bucket_name
+    }
+
+    fn selected_property_type(&self) -> BucketPropertyType {
+        BucketPropertyType::try_from(self.list_state.selected).unwrap()
+    }
+
+    fn with_selected_lines(&mut self, f: impl FnOnce(&mut ScrollLinesState)) {
+        let property_type = self.selected_property_type();
+        if let PropertyState::Loaded { lines, .. } = &mut self.properties[property_type.val()] {
+            f(lines);
+        }
+    }
+}
+
+fn build_list_items(
+    offset: usize,
+    selected: usize,
+    focused: bool,
+    area: Rect,
+    theme: &ColorTheme,
+) -> Vec<ListItem<'static>> {
+    let show_item_count = (area.height as usize).saturating_sub(2 /* border */);
+    BucketPropertyType::vars_vec()
+        .into_iter()
+        .skip(offset)
+        .take(show_item_count)
+        .enumerate()
+        .map(|(i, property_type)| {
+            let item = ListItem::new(format!(" {} ", property_type.name()));
+            if i + offset != selected {
+                item
+            } else if focused {
+                item.style(
+                    Style::default()
+                        .bg(theme.list_selected_bg)
+                        .fg(theme.list_selected_fg),
+                )
+            } else {
+                item.style(
+                    Style::default()
+                        .bg(theme.list_selected_inactive_bg)
+                        .fg(theme.list_selected_inactive_fg),
+                )
+            }
+        })
+        .collect()
+}
+
+// e.g. my-bucket.lifecycle.json
+fn build_json_file_name(bucket_name: &str, property_type: BucketPropertyType) -> String {
+    let suffix = match property_type {
+        BucketPropertyType::Lifecycle => "lifecycle",
+        BucketPropertyType::Policy => "policy",
+        _ => "property",
+    };
+    format!("{}.{}.json", bucket_name, suffix)
+}
+
+fn build_property_lines(property: &BucketProperty) -> Vec<Line<'static>> {
+    if !property.is_configured() {
+        return vec![Line::from("Not configured")];
+    }
+    property
+        .fields
+        .iter()
+        .map(|field| {
+            let indent = "  ".repeat(field.depth);
+            if field.value.is_empty() {
+                let name = format!("{}{}:", indent, field.name);
+                Line::from(name.add_modifier(Modifier::BOLD))
+            } else {
+                let name = format!("{}{}: ", indent, field.name);
+                Line::from(vec![
+                    name.add_modifier(Modifier::BOLD),
+                    field.value.clone().into(),
+                ])
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::AppError, event, object::BucketPropertyField, set_cells};
+
+    use super::*;
+    use ratatui::{backend::TestBackend, buffer::Buffer, style::Color, Terminal};
+
+    #[test]
+    fn test_render() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let (tx, _rx) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        let mut page = BucketDetailPage::new("bucket1".to_string(), ctx, tx);
+        page.load_selected_property();
+        let property = BucketProperty::from_fields(vec![
+            BucketPropertyField::new("Status", "Enabled", 0),
+            BucketPropertyField::new("MFA delete", "Disabled", 0),
+        ]);
+        page.set_property(BucketPropertyType::Versioning, Ok(property));
+
+        terminal.draw(|f| {
+            page.render(f, f.area());
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌ bucket1 ──────────  1 / 10 ┐┌ Versioning ──────────────────┐",
+            "│  Versioning                ││ Status: Enabled              │",
+            "│  Default encryption        ││ MFA delete: Disabled         │",
+            "│  Block public access       ││                              │",
+            "│  Object Lock               ││                              │",
+            "│  Tags                      ││                              │",
+            "│  Server access logging     ││                              │",
+            "│  Static website hosting    ││                              │",
+            "│  CORS                      ││                              │",
+            "│  Lifecycle rules           ││                              │",
+            "│  Bucket policy             ││                              │",
+            "└────────────────────────────┘└──────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..28, [1]) => bg: Color::Cyan, fg: Color::Black,
+            // field names
+            (32..40, [1]) => modifier: Modifier::BOLD,
+            (32..44, [2]) => modifier: Modifier::BOLD,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_json_property() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let (tx, _rx) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        let mut page = BucketDetailPage::new("bucket1".to_string(), ctx, tx);
+        terminal.draw(|f| page.render(f, f.area()))?;
+        page.handle_key(KeyEvent::from(KeyCode::Char('G')));
+        let json = r#"{
+  "Version": "2012-10-17",
+  "Statement": []
+}"#;
+        let property = BucketProperty::from_json(Some(json.to_string()));
+        page.set_property(BucketPropertyType::Policy, Ok(property));
+
+        terminal.draw(|f| {
+            page.render(f, f.area());
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌ bucket1 ────────── 10 / 10 ┐┌ Bucket policy ───────────────┐",
+            "│  Versioning                ││ 1 {                          │",
+            r#"│  Default encryption        ││ 2   "Version": "2012-10-17", │"#,
+            r#"│  Block public access       ││ 3   "Statement": []          │"#,
+            "│  Object Lock               ││ 4 }                          │",
+            "│  Tags                      ││                              │",
+            "│  Server access logging     ││                              │",
+            "│  Static website hosting    ││                              │",
+            "│  CORS                      ││                              │",
+            "│  Lifecycle rules           ││                              │",
+            "│  Bucket policy             ││                              │",
+            "└────────────────────────────┘└──────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..28, [10]) => bg: Color::Cyan, fg: Color::Black,
+            // line numbers
+            ([32], [1, 2, 3, 4]) => fg: Color::DarkGray,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_json_file_name() {
+        assert_eq!(
+            build_json_file_name("bucket1", BucketPropertyType::Policy),
+            "bucket1.policy.json"
+        );
+        assert_eq!(
+            build_json_file_name("bucket1", BucketPropertyType::Lifecycle),
+            "bucket1.lifecycle.json"
+        );
+    }
+
+    #[test]
+    fn test_render_failed_property() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let (tx, _rx) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        let mut page = BucketDetailPage::new("bucket1".to_string(), ctx, tx);
+        page.load_selected_property();
+        page.set_property(
+            BucketPropertyType::Versioning,
+            Ok(BucketProperty::default()),
+        );
+        terminal.draw(|f| page.render(f, f.area()))?;
+        page.handle_key(KeyEvent::from(KeyCode::Char('G')));
+        let e = AppError::msg("Failed to load bucket policy (AccessDenied: Access Denied)");
+        page.set_property(BucketPropertyType::Policy, Err(e));
+        page.handle_key(KeyEvent::from(KeyCode::Char('l')));
+
+        terminal.draw(|f| {
+            page.render(f, f.area());
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌ bucket1 ────────── 10 / 10 ┐┌ Bucket policy ───────────────┐",
+            "│  Versioning                ││ Failed to load bucket policy │",
+            "│  Default encryption        ││ (AccessDenied: Access        │",
+            "│  Block public access       ││ Denied)                      │",
+            "│  Object Lock               ││                              │",
+            "│  Tags                      ││                              │",
+            "│  Server access logging     ││                              │",
+            "│  Static website hosting    ││                              │",
+            "│  CORS                      ││                              │",
+            "│  Lifecycle rules           ││                              │",
+            "│  Bucket policy             ││                              │",
+            "└────────────────────────────┘└──────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item (content is focused)
+            (2..28, [10]) => bg: Color::DarkGray, fg: Color::Black,
+            // error message
+            (32..60, [1]) => fg: Color::Red,
+            (32..53, [2]) => fg: Color::Red,
+            (32..39, [3]) => fg: Color::Red,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        // other properties are not affected
+        assert!(matches!(page.properties[0], PropertyState::Loaded { .. }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_short_terminal() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let (tx, _rx) = event::new();
+        let backend = TestBackend::new(62, 8);
+        let mut terminal = Terminal::new(backend)?;
+        terminal.clear()?;
+
+        let mut page = BucketDetailPage::new("bucket1".to_string(), ctx, tx);
+        terminal.draw(|f| page.render(f, f.area()))?;
+        page.handle_key(KeyEvent::from(KeyCode::Char('G')));
+        page.set_property(
+            BucketPropertyType::Policy,
+            Ok(BucketProperty::from_json(None)),
+        );
+
+        terminal.draw(|f| {
+            page.render(f, f.area());
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌ bucket1 ────────── 10 / 10 ┐┌ Bucket policy ───────────────┐",
+            "│  Tags                      ││ Not configured               │",
+            "│  Server access logging     ││                              │",
+            "│  Static website hosting    ││                              │",
+            "│  CORS                     │││                              │",
+            "│  Lifecycle rules          │││                              │",
+            "│  Bucket policy            │││                              │",
+            "└────────────────────────────┘└──────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..28, [6]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    fn setup_terminal() -> std::io::Result<Terminal<TestBackend>> {
+        let backend = TestBackend::new(62, 12);
+        let mut terminal = Terminal::new(backend)?;
+        terminal.clear()?;
+        Ok(terminal)
+    }
+}
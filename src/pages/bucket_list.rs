@@ -22,6 +22,8 @@ use crate::{
     },
 };
 
+const REGION_WIDTH: usize = 14; // "ap-southeast-1".len()
+
 #[derive(Debug)]
 pub struct BucketListPage {
     bucket_items: Vec<BucketItem>,
@@ -98,6 +100,9 @@ impl BucketListPage {
                 key_code_char!('x') if self.non_empty() => {
                     self.tx.send(AppEventType::BucketListOpenManagementConsole);
                 }
+                key_code_char!('p') if self.non_empty() => {
+                    self.tx.send(AppEventType::BucketListOpenProperties);
+                }
                 key_code_char!('/') => {
                     self.open_filter_dialog();
                 }
@@ -107,6 +112,9 @@ impl BucketListPage {
                 key_code_char!('r') => {
                     self.open_copy_detail_dialog();
                 }
+                key_code_char!('t') => {
+                    self.tx.send(AppEventType::OpenTransfers);
+                }
                 key_code_char!('?') => {
                     self.tx.send(AppEventType::OpenHelp);
                 }
@@ -218,11 +226,13 @@ impl BucketListPage {
                         (&["f"], "Scroll page forward"),
                         (&["b"], "Scroll page backward"),
                         (&["Enter"], "Open bucket"),
+                        (&["p"], "Open bucket properties"),
                         (&["/"], "Filter bucket list"),
                         (&["o"], "Sort bucket list"),
                         (&["r"], "Open copy dialog"),
                         (&["R"], "Refresh bucket list"),
                         (&["x"], "Open management console in browser"),
+                        (&["t"], "Open transfers"),
                     ]
                 } else {
                     &[
@@ -233,11 +243,13 @@ impl BucketListPage {
                         (&["f"], "Scroll page forward"),
                         (&["b"], "Scroll page backward"),
                         (&["Enter"], "Open bucket"),
+                        (&["p"], "Open bucket properties"),
                         (&["/"], "Filter bucket list"),
                         (&["o"], "Sort bucket list"),
                         (&["r"], "Open copy dialog"),
                         (&["R"], "Refresh bucket list"),
                         (&["x"], "Open management console in browser"),
+                        (&["t"], "Open transfers"),
                     ]
                 }
             }
@@ -420,6 +432,12 @@ impl BucketListPage {
             BucketListSortType::Default => Box::new(|a, b| a.cmp(b)),
             BucketListSortType::NameAsc => Box::new(|a, b| items[*a].name.cmp(&items[*b].name)),
             BucketListSortType::NameDesc => Box::new(|a, b| items[*b].name.cmp(&items[*a].name)),
+            BucketListSortType::RegionAsc => Box::new(|a, b| {
+                (&items[*a].region, &items[*a].name).cmp(&(&items[*b].region, &items[*b].name))
+            }),
+            BucketListSortType::RegionDesc => Box::new(|a, b| {
+                (&items[*b].region, &items[*b].name).cmp(&(&items[*a].region, &items[*a].name))
+            }),
         };
 
         self.view_indices.sort_by(sort_func);
@@ -483,18 +501,35 @@ fn build_list_items<'a>(
         .enumerate()
         .map(|(idx, item)| {
             let selected = idx + offset == selected;
-            build_list_item(&item.name, selected, filter, theme)
+            build_list_item(item, selected, filter, area.width, theme)
         })
         .collect()
 }
 
 fn build_list_item<'a>(
-    name: &'a str,
+    item: &'a BucketItem,
     selected: bool,
     filter: &'a str,
+    width: u16,
     theme: &'a ColorTheme,
 ) -> ListItem<'a> {
-    let line = if filter.is_empty() {
+    let line = match &item.region {
+        Some(region) => build_bucket_line_with_region(&item.name, region, filter, width, theme),
+        None => build_bucket_line(&item.name, filter, theme),
+    };
+
+    let style = if selected {
+        Style::default()
+            .bg(theme.list_selected_bg)
+            .fg(theme.list_selected_fg)
+    } else {
+        Style::default()
+    };
+    ListItem::new(line).style(style)
+}
+
+fn build_bucket_line<'a>(name: &'a str, filter: &'a str, theme: &'a ColorTheme) -> Line<'a> {
+    if filter.is_empty() {
         Line::from(vec![" ".into(), name.into(), " ".into()])
     } else {
         let i = name.find(filter).unwrap();
@@ -506,16 +541,44 @@ fn build_list_item<'a>(
         spans.insert(0, " ".into());
         spans.push(" ".into());
         Line::from(spans)
-    };
+    }
+}
 
-    let style = if selected {
-        Style::default()
-            .bg(theme.list_selected_bg)
-            .fg(theme.list_selected_fg)
+fn build_bucket_line_with_region<'a>(
+    name: &'a str,
+    region: &'a str,
+    filter: &'a str,
+    width: u16,
+    theme: &'a ColorTheme,
+) -> Line<'a> {
+    let region_w: usize = REGION_WIDTH;
+    let name_w: usize =
+        (width as usize).saturating_sub(region_w + 6 /* spaces */ + 4 /* border + pad */);
+
+    let name = format!("{:<name_w$}", name, name_w = name_w);
+    let region = format!("{:<region_w$}", region, region_w = region_w);
+
+    if filter.is_empty() {
+        Line::from(vec![
+            " ".into(),
+            name.into(),
+            "    ".into(),
+            region.into(),
+            " ".into(),
+        ])
     } else {
-        Style::default()
-    };
-    ListItem::new(line).style(style)
+        let i = name.find(filter).unwrap();
+        let mut spans = highlight_matched_text(name)
+            .matched_range(i, i + filter.chars().count())
+            .not_matched_style(Style::default())
+            .matched_style(Style::default().fg(theme.list_filter_match))
+            .into_spans();
+        spans.insert(0, " ".into());
+        spans.push("    ".into());
+        spans.push(region.into());
+        spans.push(" ".into());
+        Line::from(spans)
+    }
 }
 
 #[cfg(test)]
@@ -563,6 +626,49 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render_with_region() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let (tx, _) = event::new();
+        let backend = TestBackend::new(40, 10);
+        let mut terminal = Terminal::new(backend)?;
+
+        terminal.draw(|f| {
+            let items = [
+                ("bucket1", "us-east-1"),
+                ("bucket2", "ap-northeast-1"),
+                ("bucket3", "eu-west-1"),
+            ]
+            .into_iter()
+            .map(|(name, region)| bucket_item_in_region(name, region))
+            .collect();
+            let mut page = BucketListPage::new(items, ctx, tx);
+            let area = Rect::new(0, 0, 40, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────── 1 / 3 ┐",
+            "│  bucket1             us-east-1       │",
+            "│  bucket2             ap-northeast-1  │",
+            "│  bucket3             eu-west-1       │",
+            "│                                      │",
+            "│                                      │",
+            "│                                      │",
+            "│                                      │",
+            "│                                      │",
+            "└──────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            (2..38, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_render_with_scroll() -> std::io::Result<()> {
         let ctx = Rc::default();
@@ -702,21 +808,19 @@ mod tests {
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
             "┌───────────────────── 1 / 5 ┐",
-            "│  qux                       │",
             "│ ╭Sort────────────────────╮ │",
             "│ │ Default                │ │",
             "│ │ Name (Asc)             │ │",
             "│ │ Name (Desc)            │ │",
+            "│ │ Region (Asc)           │ │",
+            "│ │ Region (Desc)          │ │",
             "│ ╰────────────────────────╯ │",
             "│                            │",
-            "│                            ��",
             "└────────────────────────────┘",
         ]);
         set_cells! { expected =>
-            // selected item
-            (2..28, [1]) => bg: Color::Cyan, fg: Color::Black,
             // selected sort item
-            (4..26, [5]) => fg: Color::Cyan,
+            (4..26, [4]) => fg: Color::Cyan,
         }
 
         terminal.backend().assert_buffer(&expected);
@@ -833,6 +937,32 @@ mod tests {
         assert_eq!(page.view_indices, vec![0, 4]);
     }
 
+    #[test]
+    fn test_sort_items_by_region() {
+        let ctx = Rc::default();
+        let (tx, _) = event::new();
+
+        let items = [
+            ("foo", "us-east-1"),
+            ("bar", "eu-west-1"),
+            ("baz", "us-east-1"),
+            ("qux", "ap-northeast-1"),
+        ]
+        .into_iter()
+        .map(|(name, region)| bucket_item_in_region(name, region))
+        .collect();
+        let mut page = BucketListPage::new(items, ctx, tx);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('o')));
+        page.handle_key(KeyEvent::from(KeyCode::Char('k'))); // select RegionDesc
+
+        assert_eq!(page.view_indices, vec![0, 2, 1, 3]);
+
+        page.handle_key(KeyEvent::from(KeyCode::Char('k'))); // select RegionAsc
+
+        assert_eq!(page.view_indices, vec![3, 1, 2, 0]);
+    }
+
     fn setup_terminal() -> std::io::Result<Terminal<TestBackend>> {
         let backend = TestBackend::new(30, 10);
         let mut terminal = Terminal::new(backend)?;
@@ -847,6 +977,14 @@ mod tests {
             arn: "".to_string(),
             object_url: "".to_string(),
             prefix: None,
+            region: None,
+        }
+    }
+
+    fn bucket_item_in_region(name: &str, region: &str) -> BucketItem {
+        BucketItem {
+            region: Some(region.to_string()),
+            ..bucket_item(name)
         }
     }
 }
@@ -3,9 +3,9 @@ use std::rc::Rc;
 use laurier::key_code;
 use ratatui::{
     crossterm::event::{KeyCode, KeyEvent},
-    layout::Rect,
+    layout::{Alignment, Rect},
     style::Stylize,
-    widgets::Block,
+    widgets::{Block, Paragraph},
     Frame,
 };
 
@@ -17,13 +17,20 @@ use crate::{
 
 #[derive(Debug)]
 pub struct InitializingPage {
+    // (resolved, total) bucket regions
+    progress: Option<(usize, usize)>,
+
     ctx: Rc<AppContext>,
     tx: Sender,
 }
 
 impl InitializingPage {
     pub fn new(ctx: Rc<AppContext>, tx: Sender) -> Self {
-        Self { ctx, tx }
+        Self {
+            progress: None,
+            ctx,
+            tx,
+        }
     }
 
     pub fn handle_key(&mut self, key: KeyEvent) {
@@ -33,8 +40,23 @@ impl InitializingPage {
     }
 
     pub fn render(&mut self, f: &mut Frame, area: Rect) {
-        let content = Block::bordered().fg(self.ctx.theme.fg);
-        f.render_widget(content, area);
+        let block = Block::bordered().fg(self.ctx.theme.fg);
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        if let Some((resolved, total)) = self.progress {
+            let text = format!("Resolving bucket regions... {} / {}", resolved, total);
+            let paragraph = Paragraph::new(text)
+                .alignment(Alignment::Center)
+                .fg(self.ctx.theme.fg);
+            // the center is covered by the loading dialog
+            let line_area = Rect::new(inner.x, inner.bottom().saturating_sub(2), inner.width, 1);
+            f.render_widget(paragraph, line_area);
+        }
+    }
+
+    pub fn set_progress(&mut self, resolved: usize, total: usize) {
+        self.progress = Some((resolved, total));
     }
 
     pub fn helps(&self) -> Vec<String> {
@@ -85,6 +107,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render_progress() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let (tx, _) = event::new();
+        let backend = TestBackend::new(50, 7);
+        let mut terminal = Terminal::new(backend)?;
+
+        terminal.draw(|f| {
+            let mut page = InitializingPage::new(ctx, tx);
+            page.set_progress(12, 300);
+            let area = Rect::new(0, 0, 50, 7);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌────────────────────────────────────────────────┐",
+            "│                                                │",
+            "│                                                │",
+            "│                                                │",
+            "│      Resolving bucket regions... 12 / 300      │",
+            "│                                                │",
+            "└────────────────────────────────────────────────┘",
+        ]);
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
     fn setup_terminal() -> std::io::Result<Terminal<TestBackend>> {
         let backend = TestBackend::new(30, 10);
         let mut terminal = Terminal::new(backend)?;
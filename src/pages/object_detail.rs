@@ -1,4 +1,4 @@
-use std::rc::Rc;
+use std::{rc::Rc, time::Duration};
 
 use laurier::{key_code, key_code_char};
 use ratatui::{
@@ -16,19 +16,35 @@ use crate::{
     color::ColorTheme,
     config::UiConfig,
     event::{AppEventType, Sender},
-    object::{FileDetail, FileVersion, ObjectItem, ObjectKey},
-    pages::util::{build_helps, build_short_helps},
+    object::{
+        DeleteTarget, FileDetail, FileVersion, ObjectItem, ObjectKey, ObjectMetadata,
+        ObjectSummary, ObjectTag, ObjectVersions, RestoreStatus,
+    },
+    pages::util::{build_delete_confirm_lines, build_helps, build_short_helps},
     ui::common::{format_datetime, format_size_byte, format_version},
+    util::{parse_duration, parse_key_value},
     widget::{
-        Bar, CopyDetailDialog, CopyDetailDialogState, Divider, InputDialog, InputDialogState,
+        Bar, ConfirmDialog, CopyDetailDialog, CopyDetailDialogState, Divider, InputDialog,
+        InputDialogState, MetadataDialog, MetadataDialogState, RestoreDialog, RestoreDialogState,
         ScrollLines, ScrollLinesOptions, ScrollLinesState, ScrollList, ScrollListState,
     },
 };
 
+const PRESIGN_MAX_EXPIRY: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+// load more versions when the selection gets this close to the end of the loaded versions
+const LOAD_MORE_VERSIONS_THRESHOLD: usize = 5;
+
+// https://docs.aws.amazon.com/AmazonS3/latest/userguide/object-tagging.html
+const MAX_OBJECT_TAGS: usize = 10;
+
 #[derive(Debug)]
 pub struct ObjectDetailPage {
     file_detail: FileDetail,
     file_versions: Vec<FileVersion>,
+    has_more_versions: bool,
+    // the version shown in the detail tab, None if it is the latest
+    detail_version_id: Option<String>,
     object_key: ObjectKey,
 
     tab: Tab,
@@ -45,6 +61,7 @@ pub struct ObjectDetailPage {
 enum Tab {
     Detail(DetailTabState),
     Version(VersionTabState),
+    Tags(TagsTabState),
 }
 
 impl Tab {
@@ -52,6 +69,7 @@ impl Tab {
         match self {
             Tab::Detail(_) => 0,
             Tab::Version(_) => 1,
+            Tab::Tags(_) => 2,
         }
     }
 }
@@ -61,6 +79,14 @@ enum ViewState {
     Default,
     SaveDialog(InputDialogState),
     CopyDetailDialog(CopyDetailDialogState),
+    PresignDialog(InputDialogState),
+    DeleteConfirmDialog(Box<DeleteTarget>),
+    AddTagDialog(InputDialogState),
+    EditTagDialog(usize, InputDialogState),
+    RemoveTagConfirmDialog(usize),
+    MetadataDialog(Box<MetadataDialogState>),
+    RestoreDialog(Box<RestoreDialogState>),
+    PromoteVersionConfirmDialog(usize),
 }
 
 impl ObjectDetailPage {
@@ -76,6 +102,8 @@ impl ObjectDetailPage {
         Self {
             file_detail,
             file_versions: Vec::new(),
+            has_more_versions: false,
+            detail_version_id: None,
             object_key,
             tab: Tab::Detail(detail_tab_state),
             view_state: ViewState::Default,
@@ -95,8 +123,11 @@ impl ObjectDetailPage {
                 key_code!(KeyCode::Backspace) => {
                     self.tx.send(AppEventType::CloseCurrentPage);
                 }
-                key_code_char!('h') | key_code_char!('l') => {
-                    self.toggle_tab();
+                key_code_char!('h') => {
+                    self.select_prev_tab();
+                }
+                key_code_char!('l') => {
+                    self.select_next_tab();
                 }
                 key_code_char!('j') => match self.tab {
                     Tab::Detail(ref mut state) => {
@@ -104,6 +135,10 @@ impl ObjectDetailPage {
                     }
                     Tab::Version(ref mut state) => {
                         state.select_next();
+                        self.load_more_versions_if_needed();
+                    }
+                    Tab::Tags(ref mut state) => {
+                        state.select_next();
                     }
                 },
                 key_code_char!('k') => match self.tab {
@@ -113,16 +148,46 @@ impl ObjectDetailPage {
                     Tab::Version(ref mut state) => {
                         state.select_prev();
                     }
+                    Tab::Tags(ref mut state) => {
+                        state.select_prev();
+                    }
                 },
-                key_code_char!('g') => {
-                    if let Tab::Version(ref mut state) = self.tab {
+                key_code_char!('g') => match self.tab {
+                    Tab::Version(ref mut state) => {
                         state.select_first();
                     }
-                }
-                key_code_char!('G') => {
-                    if let Tab::Version(ref mut state) = self.tab {
+                    Tab::Tags(ref mut state) => {
+                        state.select_first();
+                    }
+                    Tab::Detail(_) => {}
+                },
+                key_code_char!('G') => match self.tab {
+                    Tab::Version(ref mut state) => {
                         state.select_last();
+                        self.load_more_versions_if_needed();
                     }
+                    Tab::Tags(ref mut state) => {
+                        state.select_last();
+                    }
+                    Tab::Detail(_) => {}
+                },
+                key_code_char!('m') if matches!(self.tab, Tab::Detail(_)) => {
+                    self.open_metadata_dialog();
+                }
+                key_code_char!('R') if matches!(self.tab, Tab::Detail(_)) => {
+                    self.open_restore_dialog();
+                }
+                key_code_char!('P') if matches!(self.tab, Tab::Version(_)) => {
+                    self.open_promote_version_dialog();
+                }
+                key_code_char!('a') if matches!(self.tab, Tab::Tags(_)) => {
+                    self.open_add_tag_dialog();
+                }
+                key_code_char!('e') if matches!(self.tab, Tab::Tags(_)) => {
+                    self.open_edit_tag_dialog();
+                }
+                key_code_char!('d') if matches!(self.tab, Tab::Tags(_)) => {
+                    self.open_remove_tag_dialog();
                 }
                 key_code_char!('s') => {
                     self.download();
@@ -136,9 +201,15 @@ impl ObjectDetailPage {
                 key_code_char!('r') => {
                     self.open_copy_detail_dialog();
                 }
+                key_code_char!('D') => {
+                    self.open_delete_dialog();
+                }
                 key_code_char!('x') => {
                     self.open_management_console();
                 }
+                key_code_char!('t') => {
+                    self.tx.send(AppEventType::OpenTransfers);
+                }
                 key_code_char!('?') => {
                     self.tx.send(AppEventType::OpenHelp);
                 }
@@ -163,6 +234,9 @@ impl ObjectDetailPage {
                 key_code!(KeyCode::Esc) | key_code!(KeyCode::Backspace) => {
                     self.close_copy_detail_dialog();
                 }
+                key_code!(KeyCode::Enter) if state.is_presigned_url_selected() => {
+                    self.open_presign_dialog();
+                }
                 key_code!(KeyCode::Enter) => {
                     let (name, value) = state.selected_name_and_value();
                     self.tx.send(AppEventType::CopyToClipboard(name, value));
@@ -178,6 +252,157 @@ impl ObjectDetailPage {
                 }
                 _ => {}
             },
+            ViewState::PresignDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_presign_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    let input = state.input().to_string();
+                    self.presign(&input);
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {
+                    state.handle_key_event(key);
+                }
+            },
+            ViewState::DeleteConfirmDialog(ref target) => match key {
+                key_code!(KeyCode::Esc) | key_code!(KeyCode::Backspace) => {
+                    self.close_delete_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    let target = *target.clone();
+                    self.tx.send(AppEventType::DeleteObjects(target));
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::AddTagDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_tag_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    let input = state.input().to_string();
+                    self.save_tag(None, &input);
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {
+                    state.handle_key_event(key);
+                }
+            },
+            ViewState::EditTagDialog(index, ref mut state) => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_tag_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    let input = state.input().to_string();
+                    self.save_tag(Some(index), &input);
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {
+                    state.handle_key_event(key);
+                }
+            },
+            ViewState::RemoveTagConfirmDialog(index) => match key {
+                key_code!(KeyCode::Esc) | key_code!(KeyCode::Backspace) => {
+                    self.close_tag_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    self.remove_tag(index);
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::MetadataDialog(ref mut state) => match state.input_mut() {
+                Some(input) => match key {
+                    key_code!(KeyCode::Esc) => {
+                        state.close_input();
+                    }
+                    key_code!(KeyCode::Enter) => {
+                        if let Err(msg) = state.apply_input() {
+                            self.tx.send(AppEventType::NotifyWarn(msg));
+                        }
+                    }
+                    _ => {
+                        input.handle_key_event(key);
+                    }
+                },
+                None => match key {
+                    key_code!(KeyCode::Esc) | key_code!(KeyCode::Backspace) => {
+                        self.close_metadata_dialog();
+                    }
+                    key_code_char!('j') => {
+                        state.select_next();
+                    }
+                    key_code_char!('k') => {
+                        state.select_prev();
+                    }
+                    key_code!(KeyCode::Enter) => {
+                        state.open_edit_input();
+                    }
+                    key_code_char!('a') => {
+                        state.open_add_input();
+                    }
+                    key_code_char!('d') => {
+                        state.remove_selected();
+                    }
+                    key_code_char!('s') => {
+                        self.update_metadata();
+                    }
+                    key_code_char!('?') => {
+                        self.tx.send(AppEventType::OpenHelp);
+                    }
+                    _ => {}
+                },
+            },
+            ViewState::PromoteVersionConfirmDialog(index) => match key {
+                key_code!(KeyCode::Esc) | key_code!(KeyCode::Backspace) => {
+                    self.close_promote_version_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    self.promote_version(index);
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::RestoreDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_restore_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    self.restore();
+                }
+                key_code!(KeyCode::Backspace) => {
+                    state.pop_days_digit();
+                }
+                key_code_char!('j') => {
+                    state.select_next();
+                }
+                key_code_char!('k') => {
+                    state.select_prev();
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                KeyEvent {
+                    code: KeyCode::Char(c),
+                    ..
+                } => {
+                    state.push_days_digit(c);
+                }
+                _ => {}
+            },
         }
     }
 
@@ -217,6 +442,10 @@ impl ObjectDetailPage {
                 let version = VersionTab::new(&self.ctx.theme);
                 f.render_stateful_widget(version, chunks[1], state);
             }
+            Tab::Tags(ref mut state) => {
+                let tags = TagsTab::new(&self.ctx.theme);
+                f.render_stateful_widget(tags, chunks[1], state);
+            }
         }
 
         if let ViewState::SaveDialog(state) = &mut self.view_state {
@@ -234,6 +463,84 @@ impl ObjectDetailPage {
             let copy_detail_dialog = CopyDetailDialog::default().theme(&self.ctx.theme);
             f.render_stateful_widget(copy_detail_dialog, area, state);
         }
+
+        if let ViewState::PresignDialog(state) = &mut self.view_state {
+            let presign_dialog = InputDialog::default()
+                .title("Expires in (e.g. 15m, 1h, 7d)")
+                .max_width(40)
+                .theme(&self.ctx.theme);
+            f.render_stateful_widget(presign_dialog, area, state);
+
+            let (cursor_x, cursor_y) = state.cursor();
+            f.set_cursor_position((cursor_x, cursor_y));
+        }
+
+        if let ViewState::DeleteConfirmDialog(target) = &self.view_state {
+            let lines = build_delete_confirm_lines(target);
+            let delete_dialog = ConfirmDialog::new(lines)
+                .title("Delete")
+                .max_width(70)
+                .theme(&self.ctx.theme);
+            f.render_widget(delete_dialog, area);
+        }
+
+        if let ViewState::PromoteVersionConfirmDialog(index) = self.view_state {
+            let lines = build_promote_version_confirm_lines(
+                &self.file_versions[index],
+                &self.ctx.config.ui,
+            );
+            let promote_dialog = ConfirmDialog::new(lines)
+                .title("Promote version")
+                .max_width(70)
+                .theme(&self.ctx.theme);
+            f.render_widget(promote_dialog, area);
+        }
+
+        if let ViewState::AddTagDialog(state) | ViewState::EditTagDialog(_, state) =
+            &mut self.view_state
+        {
+            let tag_dialog = InputDialog::default()
+                .title("Tag (key=value)")
+                .max_width(50)
+                .theme(&self.ctx.theme);
+            f.render_stateful_widget(tag_dialog, area, state);
+
+            let (cursor_x, cursor_y) = state.cursor();
+            f.set_cursor_position((cursor_x, cursor_y));
+        }
+
+        if let ViewState::MetadataDialog(state) = &mut self.view_state {
+            let metadata_dialog = MetadataDialog::default().theme(&self.ctx.theme);
+            f.render_stateful_widget(metadata_dialog, area, state);
+
+            let title = state.input_title();
+            if let Some(input) = state.input_mut() {
+                let input_dialog = InputDialog::default()
+                    .title(title)
+                    .max_width(60)
+                    .theme(&self.ctx.theme);
+                f.render_stateful_widget(input_dialog, area, input);
+
+                let (cursor_x, cursor_y) = input.cursor();
+                f.set_cursor_position((cursor_x, cursor_y));
+            }
+        }
+
+        if let ViewState::RestoreDialog(state) = &mut self.view_state {
+            let restore_dialog = RestoreDialog::default().theme(&self.ctx.theme);
+            f.render_stateful_widget(restore_dialog, area, state);
+        }
+
+        if let ViewState::RemoveTagConfirmDialog(index) = self.view_state {
+            if let Tab::Tags(state) = &self.tab {
+                let lines = build_remove_tag_confirm_lines(&state.tags[index]);
+                let remove_dialog = ConfirmDialog::new(lines)
+                    .title("Remove tag")
+                    .max_width(50)
+                    .theme(&self.ctx.theme);
+                f.render_widget(remove_dialog, area);
+            }
+        }
     }
 
     pub fn helps(&self) -> Vec<String> {
@@ -248,7 +555,11 @@ impl ObjectDetailPage {
                     (&["s"], "Download object"),
                     (&["S"], "Download object as"),
                     (&["p"], "Preview object"),
+                    (&["m"], "Edit metadata"),
+                    (&["R"], "Restore archived object"),
+                    (&["D"], "Delete object"),
                     (&["x"], "Open management console in browser"),
+                    (&["t"], "Open transfers"),
                 ],
                 Tab::Version(_) => &[
                     (&["Esc", "Ctrl-c"], "Quit app"),
@@ -260,7 +571,26 @@ impl ObjectDetailPage {
                     (&["s"], "Download object"),
                     (&["S"], "Download object as"),
                     (&["p"], "Preview object"),
+                    (&["P"], "Make version latest"),
+                    (&["D"], "Delete version"),
+                    (&["x"], "Open management console in browser"),
+                    (&["t"], "Open transfers"),
+                ],
+                Tab::Tags(_) => &[
+                    (&["Esc", "Ctrl-c"], "Quit app"),
+                    (&["h/l"], "Select tabs"),
+                    (&["j/k"], "Select tag"),
+                    (&["g/G"], "Go to top/bottom"),
+                    (&["Backspace"], "Close detail panel"),
+                    (&["a"], "Add tag"),
+                    (&["e"], "Edit tag"),
+                    (&["d"], "Remove tag"),
+                    (&["r"], "Open copy dialog"),
+                    (&["s"], "Download object"),
+                    (&["S"], "Download object as"),
+                    (&["p"], "Preview object"),
                     (&["x"], "Open management console in browser"),
+                    (&["t"], "Open transfers"),
                 ],
             },
             ViewState::SaveDialog(_) => &[
@@ -274,6 +604,47 @@ impl ObjectDetailPage {
                 (&["j/k"], "Select item"),
                 (&["Enter"], "Copy selected value to clipboard"),
             ],
+            ViewState::PresignDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close presign dialog"),
+                (&["Enter"], "Copy presigned URL to clipboard"),
+            ],
+            ViewState::DeleteConfirmDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "Backspace"], "Close delete dialog"),
+                (&["Enter"], "Delete"),
+            ],
+            ViewState::AddTagDialog(_) | ViewState::EditTagDialog(_, _) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close tag dialog"),
+                (&["Enter"], "Save tags"),
+            ],
+            ViewState::RemoveTagConfirmDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "Backspace"], "Close remove dialog"),
+                (&["Enter"], "Remove tag"),
+            ],
+            ViewState::MetadataDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "Backspace"], "Close metadata dialog"),
+                (&["j/k"], "Select item"),
+                (&["Enter"], "Edit selected value"),
+                (&["a"], "Add user metadata"),
+                (&["d"], "Remove user metadata"),
+                (&["s"], "Save metadata"),
+            ],
+            ViewState::PromoteVersionConfirmDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "Backspace"], "Close promote dialog"),
+                (&["Enter"], "Make version latest"),
+            ],
+            ViewState::RestoreDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close restore dialog"),
+                (&["0-9", "Backspace"], "Edit days"),
+                (&["j/k"], "Select tier"),
+                (&["Enter"], "Restore object"),
+            ],
         };
         build_helps(helps)
     }
@@ -299,6 +670,16 @@ impl ObjectDetailPage {
                     (&["Backspace"], "Close", 2),
                     (&["?"], "Help", 0),
                 ],
+                Tab::Tags(_) => &[
+                    (&["Esc"], "Quit", 0),
+                    (&["h/l"], "Select tabs", 3),
+                    (&["j/k"], "Select", 5),
+                    (&["a"], "Add", 1),
+                    (&["e"], "Edit", 4),
+                    (&["d"], "Remove", 4),
+                    (&["Backspace"], "Close", 2),
+                    (&["?"], "Help", 0),
+                ],
             },
             ViewState::SaveDialog(_) => &[
                 (&["Esc"], "Close", 2),
@@ -311,6 +692,44 @@ impl ObjectDetailPage {
                 (&["Enter"], "Copy", 1),
                 (&["?"], "Help", 0),
             ],
+            ViewState::PresignDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["Enter"], "Copy", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::DeleteConfirmDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["Enter"], "Delete", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::AddTagDialog(_) | ViewState::EditTagDialog(_, _) => &[
+                (&["Esc"], "Close", 2),
+                (&["Enter"], "Save", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::RemoveTagConfirmDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["Enter"], "Remove", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::MetadataDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["j/k"], "Select", 4),
+                (&["Enter"], "Edit", 3),
+                (&["s"], "Save", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::PromoteVersionConfirmDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["Enter"], "Promote", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::RestoreDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["j/k"], "Select tier", 3),
+                (&["Enter"], "Restore", 1),
+                (&["?"], "Help", 0),
+            ],
         };
 
         build_short_helps(helps)
@@ -318,19 +737,41 @@ impl ObjectDetailPage {
 }
 
 impl ObjectDetailPage {
-    fn toggle_tab(&mut self) {
+    fn select_next_tab(&mut self) {
         match self.tab {
-            Tab::Detail(_) => {
-                if self.file_versions.is_empty() {
-                    self.tx.send(AppEventType::OpenObjectVersionsTab);
-                } else {
-                    self.select_versions_tab();
-                }
-            }
+            Tab::Detail(_) => self.open_versions_tab(),
+            Tab::Version(_) => self.open_tags_tab(),
+            Tab::Tags(_) => self.select_detail_tab(),
+        }
+    }
+
+    fn select_prev_tab(&mut self) {
+        match self.tab {
+            Tab::Detail(_) => self.open_tags_tab(),
             Tab::Version(_) => self.select_detail_tab(),
+            Tab::Tags(_) => self.open_versions_tab(),
+        }
+    }
+
+    fn open_versions_tab(&mut self) {
+        if self.file_versions.is_empty() {
+            self.tx.send(AppEventType::OpenObjectVersionsTab);
+        } else {
+            self.select_versions_tab();
         }
     }
 
+    // tags are loaded for the version selected in the versions tab, otherwise for the latest one
+    fn open_tags_tab(&self) {
+        if self.is_delete_marker_selected() {
+            let msg = "The selected version is a delete marker and has no tags".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        let version_id = self.current_selected_version_id();
+        self.tx.send(AppEventType::LoadObjectTags(version_id));
+    }
+
     pub fn select_detail_tab(&mut self) {
         self.tab = Tab::Detail(DetailTabState::new(&self.file_detail, &self.ctx.config.ui));
     }
@@ -342,29 +783,306 @@ impl ObjectDetailPage {
         ));
     }
 
-    pub fn set_versions(&mut self, versions: Vec<FileVersion>) {
-        self.file_versions = versions;
+    pub fn set_versions(&mut self, versions: ObjectVersions) {
+        self.has_more_versions = versions.has_more();
+        self.file_versions = versions.versions;
+    }
+
+    pub fn extend_versions(&mut self, versions: ObjectVersions) {
+        self.has_more_versions = versions.has_more();
+        if let Tab::Version(state) = &mut self.tab {
+            state.extend(&versions.versions, &self.ctx.config.ui);
+        }
+        self.file_versions.extend(versions.versions);
+    }
+
+    fn load_more_versions_if_needed(&self) {
+        if let Tab::Version(state) = &self.tab {
+            if self.has_more_versions
+                && state.selected + LOAD_MORE_VERSIONS_THRESHOLD >= state.lines.len()
+            {
+                self.tx.send(AppEventType::LoadMoreObjectVersions);
+            }
+        }
+    }
+
+    pub fn set_file_detail(&mut self, file_detail: FileDetail) {
+        // the detail is treated as the latest version unless set_detail_version_id is called after this
+        self.file_detail = file_detail;
+        self.detail_version_id = None;
+        if let Tab::Detail(_) = self.tab {
+            self.select_detail_tab();
+        }
+    }
+
+    pub fn set_detail_version_id(&mut self, version_id: Option<String>) {
+        self.detail_version_id = version_id;
+    }
+
+    pub fn select_tags_tab(&mut self, tags: Vec<ObjectTag>, version_id: Option<String>) {
+        self.tab = Tab::Tags(TagsTabState::new(tags, version_id));
+    }
+
+    pub fn set_tags(&mut self, tags: Vec<ObjectTag>) {
+        if let Tab::Tags(state) = &mut self.tab {
+            state.set_tags(tags);
+        }
+    }
+
+    fn open_save_dialog(&mut self) {
+        if !self.ensure_readable() {
+            return;
+        }
+        self.view_state = ViewState::SaveDialog(InputDialogState::default());
+    }
+
+    pub fn close_save_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    fn open_copy_detail_dialog(&mut self) {
+        self.view_state = ViewState::CopyDetailDialog(CopyDetailDialogState::object_detail(
+            self.file_detail.clone(),
+        ));
+    }
+
+    fn close_copy_detail_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    fn open_presign_dialog(&mut self) {
+        self.view_state = ViewState::PresignDialog(InputDialogState::default());
+    }
+
+    fn close_presign_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    fn open_delete_dialog(&mut self) {
+        let (version_id, size_byte) = match &self.tab {
+            Tab::Detail(_) => (None, self.file_detail.size_byte),
+            Tab::Version(state) => match self.file_versions.get(state.selected) {
+                Some(v) => (Some(v.version_id.clone()), v.size_byte),
+                None => return,
+            },
+            Tab::Tags(_) => return,
+        };
+        let target = DeleteTarget {
+            object_key: self.object_key.clone(),
+            dir: false,
+            objects: vec![ObjectSummary {
+                key: self.file_detail.key.clone(),
+                size_byte,
+                storage_class: self.file_detail.storage_class.clone(),
+                restored: false,
+            }],
+            version_id,
+        };
+        self.view_state = ViewState::DeleteConfirmDialog(Box::new(target));
+    }
+
+    pub fn close_delete_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    fn open_promote_version_dialog(&mut self) {
+        let Tab::Version(state) = &self.tab else {
+            return;
+        };
+        let Some(version) = self.file_versions.get(state.selected) else {
+            return;
+        };
+        if version.is_latest {
+            let msg = "The selected version is already the latest".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        if version.is_delete_marker {
+            let msg = "The selected version is a delete marker".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        self.view_state = ViewState::PromoteVersionConfirmDialog(state.selected);
+    }
+
+    pub fn close_promote_version_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    fn promote_version(&self, index: usize) {
+        let version = self.file_versions[index].clone();
+        self.tx.send(AppEventType::PromoteObjectVersion(version));
+    }
+
+    fn open_metadata_dialog(&mut self) {
+        let metadata = ObjectMetadata::from(&self.file_detail);
+        self.view_state = ViewState::MetadataDialog(Box::new(MetadataDialogState::new(metadata)));
+    }
+
+    fn close_metadata_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    fn update_metadata(&mut self) {
+        if let ViewState::MetadataDialog(state) = &self.view_state {
+            if !state.is_changed() {
+                let msg = "Metadata has not been changed".to_string();
+                self.tx.send(AppEventType::NotifyWarn(msg));
+                return;
+            }
+            let metadata = state.metadata().clone();
+            self.tx.send(AppEventType::UpdateObjectMetadata(metadata));
+        }
+        self.close_metadata_dialog();
+    }
+
+    fn open_restore_dialog(&mut self) {
+        let detail = &self.file_detail;
+        if !detail.is_archived() {
+            let msg = format!(
+                "The object is not archived (storage class: {})",
+                detail.storage_class
+            );
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        if detail.restore_status() == RestoreStatus::InProgress {
+            let msg = "The object is already being restored".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        let state = RestoreDialogState::new(&detail.s3_uri, &detail.storage_class);
+        self.view_state = ViewState::RestoreDialog(Box::new(state));
+    }
+
+    fn close_restore_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    fn restore(&mut self) {
+        if let ViewState::RestoreDialog(state) = &self.view_state {
+            let Some(days) = state.days() else {
+                let msg = "Invalid days (must be a positive integer)".to_string();
+                self.tx.send(AppEventType::NotifyWarn(msg));
+                return;
+            };
+            let tier = state.selected_tier().to_string();
+            let version_id = self.current_selected_version_id();
+            self.tx
+                .send(AppEventType::RestoreObject(days, tier, version_id));
+        }
+        self.close_restore_dialog();
+    }
+
+    // archived objects cannot be read until they are restored,
+    // so explain it (and offer to restore) instead of letting the request fail
+    fn ensure_readable(&mut self) -> bool {
+        if self.is_delete_marker_selected() {
+            let msg = "The selected version is a delete marker and has no content".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return false;
+        }
+        // the storage class is only known for the version whose detail has been loaded
+        if !self.is_detail_version_selected() || self.file_detail.is_readable() {
+            return true;
+        }
+        let detail = &self.file_detail;
+        if detail.restore_status() == RestoreStatus::InProgress {
+            let msg = format!(
+                "The object is archived in {} and is being restored, try again later",
+                detail.storage_class
+            );
+            self.tx.send(AppEventType::NotifyWarn(msg));
+        } else {
+            let msg = format!(
+                "The object is archived in {} and must be restored before reading",
+                detail.storage_class
+            );
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            self.open_restore_dialog();
+        }
+        false
+    }
+
+    fn open_add_tag_dialog(&mut self) {
+        self.view_state = ViewState::AddTagDialog(InputDialogState::default());
+    }
+
+    fn open_edit_tag_dialog(&mut self) {
+        if let Tab::Tags(state) = &self.tab {
+            if let Some(tag) = state.tags.get(state.selected) {
+                let input = format!("{}={}", tag.key, tag.value);
+                self.view_state =
+                    ViewState::EditTagDialog(state.selected, InputDialogState::new(input));
+            }
+        }
     }
 
-    fn open_save_dialog(&mut self) {
-        self.view_state = ViewState::SaveDialog(InputDialogState::default());
+    fn open_remove_tag_dialog(&mut self) {
+        if let Tab::Tags(state) = &self.tab {
+            if !state.tags.is_empty() {
+                self.view_state = ViewState::RemoveTagConfirmDialog(state.selected);
+            }
+        }
     }
 
-    pub fn close_save_dialog(&mut self) {
+    fn close_tag_dialog(&mut self) {
         self.view_state = ViewState::Default;
     }
 
-    fn open_copy_detail_dialog(&mut self) {
-        self.view_state = ViewState::CopyDetailDialog(CopyDetailDialogState::object_detail(
-            self.file_detail.clone(),
-        ));
+    // add a new tag if index is None, otherwise replace the tag at index
+    fn save_tag(&mut self, index: Option<usize>, input: &str) {
+        let Tab::Tags(state) = &self.tab else {
+            return;
+        };
+
+        let Some(tag) = parse_tag(input) else {
+            let msg = format!("Invalid tag: {} (must be key=value)", input.trim());
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        };
+        let duplicated = state
+            .tags
+            .iter()
+            .enumerate()
+            .any(|(i, t)| Some(i) != index && t.key == tag.key);
+        if duplicated {
+            let msg = format!("Tag key already exists: {}", tag.key);
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+
+        let mut tags = state.tags.clone();
+        match index {
+            Some(i) => tags[i] = tag,
+            None if tags.len() >= MAX_OBJECT_TAGS => {
+                let msg = format!("An object can have up to {} tags", MAX_OBJECT_TAGS);
+                self.tx.send(AppEventType::NotifyWarn(msg));
+                return;
+            }
+            None => tags.push(tag),
+        }
+
+        let version_id = state.version_id.clone();
+        self.tx.send(AppEventType::SaveObjectTags(version_id, tags));
+        self.close_tag_dialog();
     }
 
-    fn close_copy_detail_dialog(&mut self) {
-        self.view_state = ViewState::Default;
+    fn remove_tag(&mut self, index: usize) {
+        if let Tab::Tags(state) = &self.tab {
+            let mut tags = state.tags.clone();
+            tags.remove(index);
+
+            let version_id = state.version_id.clone();
+            self.tx.send(AppEventType::SaveObjectTags(version_id, tags));
+        }
+        self.close_tag_dialog();
     }
 
-    fn download(&self) {
+    fn download(&mut self) {
+        if !self.ensure_readable() {
+            return;
+        }
         let file_detail = self.file_detail.clone();
         let version_id = self.current_selected_version_id();
         self.tx
@@ -386,13 +1104,39 @@ impl ObjectDetailPage {
         ));
     }
 
-    fn preview(&self) {
+    fn preview(&mut self) {
+        if !self.ensure_readable() {
+            return;
+        }
         let file_detail = self.file_detail.clone();
         let version_id = self.current_selected_version_id();
         self.tx
             .send(AppEventType::OpenPreview(file_detail, version_id));
     }
 
+    fn presign(&mut self, input: &str) {
+        let input = input.trim();
+        if input.is_empty() {
+            return;
+        }
+
+        // presigned URLs signed with SigV4 are valid for at most 7 days
+        match parse_duration(input) {
+            Some(expires_in) if expires_in > Duration::ZERO && expires_in <= PRESIGN_MAX_EXPIRY => {
+                let object_key = self.object_key.clone();
+                let version_id = self.current_selected_version_id();
+                self.tx.send(AppEventType::PresignObject(
+                    object_key, version_id, expires_in,
+                ));
+                self.close_presign_dialog();
+            }
+            _ => {
+                let msg = format!("Invalid expiry: {} (must be between 1s and 7d)", input);
+                self.tx.send(AppEventType::NotifyWarn(msg));
+            }
+        }
+    }
+
     fn open_management_console(&self) {
         self.tx
             .send(AppEventType::ObjectDetailOpenManagementConsole);
@@ -400,17 +1144,45 @@ impl ObjectDetailPage {
 
     fn current_selected_version_id(&self) -> Option<String> {
         match &self.tab {
-            Tab::Detail(_) => None,
+            Tab::Detail(_) => self.detail_version_id.clone(),
             Tab::Version(state) => self
                 .file_versions
                 .get(state.selected)
                 .map(|v| v.version_id.clone()),
+            Tab::Tags(state) => state.version_id.clone(),
+        }
+    }
+
+    // whether the loaded detail is of the selected version
+    fn is_detail_version_selected(&self) -> bool {
+        match &self.tab {
+            Tab::Version(state) => self.file_versions.get(state.selected).is_some_and(|v| {
+                match &self.detail_version_id {
+                    Some(version_id) => &v.version_id == version_id,
+                    None => v.is_latest,
+                }
+            }),
+            _ => self.current_selected_version_id() == self.detail_version_id,
+        }
+    }
+
+    fn is_delete_marker_selected(&self) -> bool {
+        match &self.tab {
+            Tab::Version(state) => self
+                .file_versions
+                .get(state.selected)
+                .is_some_and(|v| v.is_delete_marker),
+            _ => false,
         }
     }
 
     pub fn current_object_key(&self) -> &ObjectKey {
         &self.object_key
     }
+
+    pub fn file_detail(&self) -> &FileDetail {
+        &self.file_detail
+    }
 }
 
 fn build_list_items_from_object_items<'a>(
@@ -475,7 +1247,7 @@ fn format_file_item(name: &str, width: u16) -> String {
 }
 
 fn build_tabs(tab: &Tab, theme: &ColorTheme) -> Tabs<'static> {
-    let tabs = vec!["Detail", "Version"];
+    let tabs = vec!["Detail", "Version", "Tags"];
     Tabs::new(tabs)
         .select(tab.val())
         .highlight_style(
@@ -487,34 +1259,98 @@ fn build_tabs(tab: &Tab, theme: &ColorTheme) -> Tabs<'static> {
 }
 
 fn build_detail_content_lines(detail: &FileDetail, ui_config: &UiConfig) -> Vec<Line<'static>> {
-    let details = [
-        ("Name:", &detail.name),
-        ("Size:", &format_size_byte(detail.size_byte)),
+    let date_format = &ui_config.object_detail.date_format;
+
+    let object_lock = [
+        ("Mode", detail.object_lock_mode.clone()),
+        (
+            "Retain until",
+            detail
+                .object_lock_retain_until
+                .map(|dt| format_datetime(&dt, date_format))
+                .unwrap_or_default(),
+        ),
+        ("Legal hold", detail.object_lock_legal_hold.clone()),
+    ];
+
+    let details: Vec<(&str, Vec<String>)> = vec![
+        ("Name:", single_value(&detail.name)),
+        ("Size:", single_value(&format_size_byte(detail.size_byte))),
         (
             "Last Modified:",
-            &format_datetime(&detail.last_modified, &ui_config.object_detail.date_format),
+            single_value(&format_datetime(&detail.last_modified, date_format)),
         ),
-        ("ETag:", &detail.e_tag),
-        ("Content-Type:", &detail.content_type),
-        ("Storage class:", &detail.storage_class),
-    ]
-    .iter()
-    .filter_map(|(label, value)| {
-        if value.is_empty() {
-            None
-        } else {
-            let lines = vec![
-                Line::from(label.add_modifier(Modifier::BOLD)),
-                Line::from(format!(" {}", value)),
-            ];
-            Some(lines)
-        }
-    })
-    .collect();
+        ("ETag:", single_value(&detail.e_tag)),
+        ("Content-Type:", single_value(&detail.content_type)),
+        ("Storage class:", single_value(&detail.storage_class)),
+        ("Cache-Control:", single_value(&detail.cache_control)),
+        ("Content-Encoding:", single_value(&detail.content_encoding)),
+        (
+            "Content-Disposition:",
+            single_value(&detail.content_disposition),
+        ),
+        ("Expires:", single_value(&detail.expires)),
+        ("Metadata:", key_value_lines(&detail.user_metadata)),
+        (
+            "Server-side encryption:",
+            single_value(&detail.server_side_encryption),
+        ),
+        ("KMS key ID:", single_value(&detail.sse_kms_key_id)),
+        ("Checksums:", key_value_lines(&detail.checksums)),
+        ("Object Lock:", key_value_lines(&object_lock)),
+        (
+            "Replication status:",
+            single_value(&detail.replication_status),
+        ),
+        (
+            "Restore status:",
+            single_value(&restore_status_value(detail, date_format)),
+        ),
+    ];
+
+    let details = details
+        .into_iter()
+        .filter(|(_, values)| !values.is_empty())
+        .map(|(label, values)| {
+            let mut lines = vec![Line::from(label.add_modifier(Modifier::BOLD))];
+            lines.extend(values.into_iter().map(|v| Line::from(format!(" {}", v))));
+            lines
+        })
+        .collect();
 
     flatten_with_empty_lines(details)
 }
 
+fn restore_status_value(detail: &FileDetail, date_format: &str) -> String {
+    match detail.restore_status() {
+        RestoreStatus::NotRestored if detail.is_archived() => "Not restored".into(),
+        RestoreStatus::NotRestored => "".into(),
+        RestoreStatus::InProgress => "In progress".into(),
+        RestoreStatus::Restored(Some(expiry_date)) => format!(
+            "Restored (expires {})",
+            format_datetime(&expiry_date, date_format)
+        ),
+        RestoreStatus::Restored(None) => "Restored".into(),
+    }
+}
+
+fn single_value(value: &str) -> Vec<String> {
+    if value.is_empty() {
+        vec![]
+    } else {
+        vec![value.into()]
+    }
+}
+
+// pairs with empty values are omitted
+fn key_value_lines<K: AsRef<str>, V: AsRef<str>>(pairs: &[(K, V)]) -> Vec<String> {
+    pairs
+        .iter()
+        .filter(|(_, v)| !v.as_ref().is_empty())
+        .map(|(k, v)| format!("{}: {}", k.as_ref(), v.as_ref()))
+        .collect()
+}
+
 #[derive(Debug)]
 struct DetailTabState {
     scroll_lines_state: ScrollLinesState,
@@ -560,6 +1396,17 @@ fn build_version_detail_lines(
             let last_modified =
                 format_datetime(&v.last_modified, &ui_config.object_detail.date_format);
             let size_byte = format_size_byte(v.size_byte);
+            let last_line = if v.is_delete_marker {
+                Line::from(vec![
+                    "         Type: ".add_modifier(Modifier::BOLD),
+                    Span::raw("Delete marker"),
+                ])
+            } else {
+                Line::from(vec![
+                    "         Size: ".add_modifier(Modifier::BOLD),
+                    Span::raw(size_byte),
+                ])
+            };
             vec![
                 Line::from(vec![
                     "   Version ID: ".add_modifier(Modifier::BOLD),
@@ -569,10 +1416,7 @@ fn build_version_detail_lines(
                     "Last Modified: ".add_modifier(Modifier::BOLD),
                     Span::raw(last_modified),
                 ]),
-                Line::from(vec![
-                    "         Size: ".add_modifier(Modifier::BOLD),
-                    Span::raw(size_byte),
-                ]),
+                last_line,
             ]
         })
         .collect()
@@ -595,6 +1439,11 @@ impl VersionTabState {
         }
     }
 
+    fn extend(&mut self, versions: &[FileVersion], ui_config: &UiConfig) {
+        let lines = build_version_detail_lines(versions, ui_config);
+        self.lines.extend(lines);
+    }
+
     fn select_next(&mut self) {
         if self.selected >= self.lines.len() - 1 {
             return;
@@ -728,6 +1577,156 @@ impl StatefulWidget for VersionTab {
     }
 }
 
+fn build_promote_version_confirm_lines(
+    version: &FileVersion,
+    ui_config: &UiConfig,
+) -> Vec<Line<'static>> {
+    let last_modified =
+        format_datetime(&version.last_modified, &ui_config.object_detail.date_format);
+    vec![
+        Line::from("Make this version the latest?".bold()),
+        Line::from(format!("     Version ID: {}", version.version_id)),
+        Line::from(format!("  Last Modified: {}", last_modified)),
+        Line::from(""),
+        Line::from("The version is copied as a new latest version."),
+    ]
+}
+
+fn parse_tag(input: &str) -> Option<ObjectTag> {
+    parse_key_value(input).map(|(key, value)| ObjectTag { key, value })
+}
+
+fn build_remove_tag_confirm_lines(tag: &ObjectTag) -> Vec<Line<'static>> {
+    vec![
+        Line::from("Remove the tag?".bold()),
+        Line::from(format!("  {}={}", tag.key, tag.value)),
+    ]
+}
+
+#[derive(Debug, Default)]
+struct TagsTabState {
+    tags: Vec<ObjectTag>,
+    version_id: Option<String>,
+    selected: usize,
+    offset: usize,
+    height: usize,
+}
+
+impl TagsTabState {
+    fn new(tags: Vec<ObjectTag>, version_id: Option<String>) -> Self {
+        Self {
+            tags,
+            version_id,
+            ..Default::default()
+        }
+    }
+
+    fn set_tags(&mut self, tags: Vec<ObjectTag>) {
+        self.tags = tags;
+        self.selected = self.selected.min(self.tags.len().saturating_sub(1));
+        self.offset = self.offset.min(self.selected);
+    }
+
+    fn select_next(&mut self) {
+        if self.selected + 1 >= self.tags.len() {
+            return;
+        }
+
+        self.selected += 1;
+        if self.selected >= self.offset + self.height {
+            self.offset += 1;
+        }
+    }
+
+    fn select_prev(&mut self) {
+        if self.selected == 0 {
+            return;
+        }
+
+        self.selected -= 1;
+        if self.selected < self.offset {
+            self.offset -= 1;
+        }
+    }
+
+    fn select_first(&mut self) {
+        self.selected = 0;
+        self.offset = 0;
+    }
+
+    fn select_last(&mut self) {
+        if self.tags.is_empty() {
+            return;
+        }
+
+        self.selected = self.tags.len() - 1;
+        self.offset = self.tags.len().saturating_sub(self.height);
+    }
+}
+
+#[derive(Debug)]
+struct TagsTab {
+    color: VersionTabColor,
+}
+
+impl TagsTab {
+    fn new(theme: &ColorTheme) -> Self {
+        Self {
+            color: VersionTabColor::new(theme),
+        }
+    }
+}
+
+impl StatefulWidget for TagsTab {
+    type State = TagsTabState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let header_height = if state.version_id.is_some() { 2 } else { 0 };
+        let chunks =
+            Layout::vertical([Constraint::Length(header_height), Constraint::Min(0)]).split(area);
+
+        if let Some(version_id) = &state.version_id {
+            let header = Paragraph::new(Line::from(vec![
+                " Version ID: ".add_modifier(Modifier::BOLD),
+                Span::raw(format_version(version_id).to_owned()),
+            ]));
+            header.render(chunks[0], buf);
+        }
+
+        // update state
+        let area = chunks[1];
+        state.height = area.height as usize;
+
+        if state.tags.is_empty() {
+            Paragraph::new(" No tags").render(area, buf);
+            return;
+        }
+
+        let rows = state
+            .tags
+            .iter()
+            .enumerate()
+            .skip(state.offset)
+            .take(state.height);
+        for (row, (i, tag)) in rows.enumerate() {
+            let row_area = Rect::new(area.x, area.y + row as u16, area.width, 1);
+            let chunks =
+                Layout::horizontal([Constraint::Length(1), Constraint::Min(0)]).split(row_area);
+
+            if i == state.selected {
+                let bar = Bar::default().color(self.color.selected);
+                bar.render(chunks[0], buf);
+            }
+            let line = Line::from(vec![
+                " ".into(),
+                tag.key.clone().add_modifier(Modifier::BOLD),
+                format!(": {}", tag.value).into(),
+            ]);
+            Paragraph::new(line).render(chunks[1], buf);
+        }
+    }
+}
+
 fn flatten_with_empty_lines(line_groups: Vec<Vec<Line>>) -> Vec<Line> {
     let n = line_groups.len();
     let mut ret: Vec<Line> = Vec::new();
@@ -774,7 +1773,7 @@ mod tests {
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
             "┌───────────────────── 1 / 3 ┐┌────────────────────────────┐",
-            "│  file1                     ││ Detail │ Version           │",
+            "│  file1                     ││ Detail │ Version │ Tags    │",
             "│  file2                     ││────────────────────────────│",
             "│  file3                     ││ Name:                      │",
             "│                            ││  file1                     │",
@@ -841,7 +1840,7 @@ mod tests {
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
             "┌───────────────────── 1 / 3 ┐┌────────────────────────────┐",
-            "│  file1                     ││ Detail │ Version           │",
+            "│  file1                     ││ Detail │ Version │ Tags    │",
             "│  file2                     ││────────────────────────────│",
             "│  file3                     ││ Name:                      │",
             "│                            ││  file1                     │",
@@ -900,7 +1899,10 @@ mod tests {
                 ctx,
                 tx,
             );
-            page.set_versions(file_versions);
+            page.set_versions(ObjectVersions {
+                versions: file_versions,
+                next_version_id_marker: None,
+            });
             page.select_versions_tab();
             let area = Rect::new(0, 0, 60, 20);
             page.render(f, area);
@@ -909,7 +1911,7 @@ mod tests {
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
             "┌───────────────────── 1 / 3 ┐┌────────────────────────────┐",
-            "│  file1                     ││ Detail │ Version           │",
+            "│  file1                     ││ Detail │ Version │ Tags    │",
             "│  file2                     ││────────────────────────────│",
             "│  file3                     ││┃    Version ID: 60f36bc2-0f│",
             "│                            ││┃ Last Modified: 2024-01-02 │",
@@ -951,6 +1953,95 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render_tags_tab() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let (items, file_detail, file_versions, object_key) = fixtures();
+            let items_len = items.len();
+            let mut page = ObjectDetailPage::new(
+                file_detail,
+                items,
+                object_key,
+                ScrollListState::new(items_len),
+                ctx,
+                tx,
+            );
+            let tags = vec![
+                ObjectTag {
+                    key: "env".to_string(),
+                    value: "prod".to_string(),
+                },
+                ObjectTag {
+                    key: "team".to_string(),
+                    value: "data".to_string(),
+                },
+            ];
+            let version_id = Some(file_versions[0].version_id.clone());
+            page.select_tags_tab(tags, version_id);
+            let area = Rect::new(0, 0, 60, 20);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌───────────────────── 1 / 3 ┐┌────────────────────────────┐",
+            "│  file1                     ││ Detail │ Version │ Tags    │",
+            "│  file2                     ││────────────────────────────│",
+            "│  file3                     ││ Version ID: 60f36bc2-0f38-4│",
+            "│                            ││                            │",
+            "│                            ││┃ env: prod                 │",
+            "│                            ││  team: data                │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "│                            ││                            │",
+            "└────────────────────────────┘└────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..28, [1]) => bg: Color::DarkGray, fg: Color::Black,
+            // "Tags" is selected
+            (51..55, [1]) => fg: Color::Cyan, modifier: Modifier::BOLD,
+            // "Version ID" label
+            (31..44, [3]) => modifier: Modifier::BOLD,
+            // tag keys
+            (33..36, [5]) => modifier: Modifier::BOLD,
+            (33..37, [6]) => modifier: Modifier::BOLD,
+            // selected bar
+            ([31], [5]) => fg: Color::Cyan,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_tag() {
+        let tag = |key: &str, value: &str| ObjectTag {
+            key: key.to_string(),
+            value: value.to_string(),
+        };
+        assert_eq!(parse_tag("env=prod"), Some(tag("env", "prod")));
+        assert_eq!(parse_tag(" env = prod "), Some(tag("env", "prod")));
+        assert_eq!(parse_tag("env="), Some(tag("env", "")));
+        assert_eq!(parse_tag("expr=a=b"), Some(tag("expr", "a=b")));
+        assert_eq!(parse_tag("env"), None);
+        assert_eq!(parse_tag("=prod"), None);
+    }
+
     #[test]
     fn test_render_version_tab_with_config() -> std::io::Result<()> {
         let (tx, _) = event::new();
@@ -969,7 +2060,10 @@ mod tests {
                 Rc::new(ctx),
                 tx,
             );
-            page.set_versions(file_versions);
+            page.set_versions(ObjectVersions {
+                versions: file_versions,
+                next_version_id_marker: None,
+            });
             page.select_versions_tab();
             let area = Rect::new(0, 0, 60, 20);
             page.render(f, area);
@@ -978,7 +2072,7 @@ mod tests {
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
             "┌───────────────────── 1 / 3 ┐┌────────────────────────────┐",
-            "│  file1                     ││ Detail │ Version           │",
+            "│  file1                     ││ Detail │ Version │ Tags    │",
             "│  file2                     ││────────────────────────────│",
             "│  file3                     ││┃    Version ID: 60f36bc2-0f│",
             "│                            ││┃ Last Modified: 2024/01/02 │",
@@ -1045,7 +2139,7 @@ mod tests {
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
             "┌───────────────────── 1 / 3 ┐┌────────────────────────────┐",
-            "│  file1                     ││ Detail │ Version           │",
+            "│  file1                     ││ Detail │ Version │ Tags    │",
             "│  file2                     ││────────────────────────────│",
             "│  file3                     ││ Name:                      │",
             "│                            ││  file1                     │",
@@ -1110,9 +2204,8 @@ mod tests {
         #[rustfmt::skip]
         let mut expected = Buffer::with_lines([
             "┌───────────────────── 1 / 3 ┐┌────────────────────────────┐",
-            "│  file1                     ││ Detail │ Version           │",
+            "│  file1                     ││ Detail │ Version │ Tags    │",
             "│  file2                     ││────────────────────────────│",
-            "│  file3                     ││ Name:                      │",
             "│ ╭Copy──────────────────────────────────────────────────╮ │",
             "│ │ Key:                                                 │ │",
             "│ │   file1                                              │ │",
@@ -1124,8 +2217,9 @@ mod tests {
             "│ │   https://bucket-1.s3.ap-northeast-1.amazonaws.com/f │ │",
             "│ │ ETag:                                                │ │",
             "│ │   bef684de-a260-48a4-8178-8a535ecccadb               │ │",
+            "│ │ Presigned URL:                                       │ │",
+            "│ │   (Enter: Set expiry)                                │ │",
             "│ ╰──────────────────────────────────────────────────────╯ │",
-            "│                            ││ Content-Type:              │",
             "│                            ││  text/plain                │",
             "│                            ││                            │",
             "└────────────────────────────┘└────────────────────────────┘",
@@ -1135,22 +2229,20 @@ mod tests {
             (2..28, [1]) => bg: Color::DarkGray, fg: Color::Black,
             // "Detail" is selected
             (32..38, [1]) => fg: Color::Cyan, modifier: Modifier::BOLD,
-            // "Name" label
-            (32..37, [3]) => modifier: Modifier::BOLD,
-            // "Content-Type" label
-            (32..45, [16]) => modifier: Modifier::BOLD,
             // "Key" label
-            (4..8, [5]) => modifier: Modifier::BOLD,
+            (4..8, [4]) => modifier: Modifier::BOLD,
             // "S3 URI" label
-            (4..11, [7]) => modifier: Modifier::BOLD,
+            (4..11, [6]) => modifier: Modifier::BOLD,
             // "ARN" label
-            (4..8, [9]) => modifier: Modifier::BOLD,
+            (4..8, [8]) => modifier: Modifier::BOLD,
             // "Object URL" label
-            (4..15, [11]) => modifier: Modifier::BOLD,
+            (4..15, [10]) => modifier: Modifier::BOLD,
             // "ETag" label
-            (4..9, [13]) => modifier: Modifier::BOLD,
+            (4..9, [12]) => modifier: Modifier::BOLD,
+            // "Presigned URL" label
+            (4..18, [14]) => modifier: Modifier::BOLD,
             // "Key" is selected
-            (4..56, [5, 6]) => fg: Color::Cyan,
+            (4..56, [4, 5]) => fg: Color::Cyan,
         }
 
         terminal.backend().assert_buffer(&expected);
@@ -1158,6 +2250,64 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_build_detail_content_lines_with_metadata() {
+        let (_, file_detail, _, _) = fixtures();
+        let file_detail = FileDetail {
+            cache_control: "max-age=3600".to_string(),
+            user_metadata: vec![
+                ("author".to_string(), "alice".to_string()),
+                ("project".to_string(), "stu".to_string()),
+            ],
+            server_side_encryption: "AES256".to_string(),
+            checksums: vec![("CRC32".to_string(), "AAAAAA==".to_string())],
+            object_lock_mode: "GOVERNANCE".to_string(),
+            ..file_detail
+        };
+        let ui_config = UiConfig::default();
+
+        let actual: Vec<String> = build_detail_content_lines(&file_detail, &ui_config)
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+        let expected = [
+            "Name:",
+            " file1",
+            "",
+            "Size:",
+            " 1.01 KiB",
+            "",
+            "Last Modified:",
+            " 2024-01-02 13:01:02",
+            "",
+            "ETag:",
+            " bef684de-a260-48a4-8178-8a535ecccadb",
+            "",
+            "Content-Type:",
+            " text/plain",
+            "",
+            "Storage class:",
+            " STANDARD",
+            "",
+            "Cache-Control:",
+            " max-age=3600",
+            "",
+            "Metadata:",
+            " author: alice",
+            " project: stu",
+            "",
+            "Server-side encryption:",
+            " AES256",
+            "",
+            "Checksums:",
+            " CRC32: AAAAAA==",
+            "",
+            "Object Lock:",
+            " Mode: GOVERNANCE",
+        ];
+        assert_eq!(actual, expected);
+    }
+
     fn setup_terminal() -> std::io::Result<Terminal<TestBackend>> {
         let backend = TestBackend::new(60, 20);
         let mut terminal = Terminal::new(backend)?;
@@ -1189,6 +2339,7 @@ mod tests {
             s3_uri: "s3://bucket-1/file1".to_string(),
             arn: "arn:aws:s3:::bucket-1/file1".to_string(),
             object_url: "https://bucket-1.s3.ap-northeast-1.amazonaws.com/file1".to_string(),
+            ..Default::default()
         };
         let file_versions = vec![
             FileVersion {
@@ -1196,12 +2347,14 @@ mod tests {
                 size_byte: 1024 + 10,
                 last_modified: parse_datetime("2024-01-02 13:01:02"),
                 is_latest: true,
+                is_delete_marker: false,
             },
             FileVersion {
                 version_id: "1c5d3bcc-2bb3-4cd5-875f-a95a6ae53f65".to_string(),
                 size_byte: 1024,
                 last_modified: parse_datetime("2024-01-01 23:59:59"),
                 is_latest: false,
+                is_delete_marker: false,
             },
         ];
         let object_key = ObjectKey {
@@ -1,4 +1,4 @@
-use std::{cmp::Ordering, rc::Rc};
+use std::{cmp::Ordering, collections::HashMap, rc::Rc};
 
 use chrono::{DateTime, Local};
 use laurier::{highlight::highlight_matched_text, key_code, key_code_char};
@@ -16,13 +16,18 @@ use crate::{
     color::ColorTheme,
     config::UiConfig,
     event::{AppEventType, Sender},
-    object::{ObjectItem, ObjectKey},
-    pages::util::{build_helps, build_short_helps},
+    object::{
+        CopyMode, CopyTarget, DeleteTarget, ObjectItem, ObjectKey, ObjectSummary,
+        StorageClassTarget, TimeTravelObjects, UndeleteTarget,
+    },
+    pages::util::{build_delete_confirm_lines, build_helps, build_short_helps},
     ui::common::{format_datetime, format_size_byte},
+    util::{parse_datetime_input, DATETIME_INPUT_FORMAT},
     widget::{
-        CopyDetailDialog, CopyDetailDialogState, InputDialog, InputDialogState,
-        ObjectListSortDialog, ObjectListSortDialogState, ObjectListSortType, ScrollList,
-        ScrollListState,
+        ConfirmDialog, CopyDetailDialog, CopyDetailDialogState, DestinationDialog,
+        DestinationDialogState, InputDialog, InputDialogState, ObjectListSortDialog,
+        ObjectListSortDialogState, ObjectListSortType, ScrollList, ScrollListState,
+        StorageClassDialog, StorageClassDialogState, UndeleteDialog, UndeleteDialogState,
     },
 };
 
@@ -31,6 +36,12 @@ pub struct ObjectListPage {
     object_items: Vec<ObjectItem>,
     object_key: ObjectKey,
     view_indices: Vec<usize>,
+    // items from this index are deleted objects shown by the toggle
+    live_items_len: usize,
+    show_deleted: bool,
+    // set while the list shows the objects as they existed at that time
+    time_travel_at: Option<DateTime<Local>>,
+    version_ids: HashMap<String, String>,
 
     view_state: ViewState,
 
@@ -48,6 +59,13 @@ enum ViewState {
     FilterDialog,
     SortDialog,
     CopyDetailDialog(Box<CopyDetailDialogState>),
+    UploadDialog(InputDialogState),
+    DeleteConfirmDialog(Box<DeleteTarget>),
+    CopyDialog(CopyMode, Box<DestinationDialogState>),
+    StorageClassDialog(Box<StorageClassDialogState>),
+    UndeleteConfirmDialog(ObjectKey),
+    UndeleteDialog(Box<UndeleteDialogState>),
+    TimeTravelDialog(InputDialogState),
 }
 
 impl ObjectListPage {
@@ -63,6 +81,10 @@ impl ObjectListPage {
             object_items,
             object_key,
             view_indices,
+            live_items_len: items_len,
+            show_deleted: false,
+            time_travel_at: None,
+            version_ids: HashMap::new(),
             view_state: ViewState::Default,
             list_state: ScrollListState::new(items_len),
             filter_input_state: InputDialogState::default(),
@@ -82,6 +104,29 @@ impl ObjectListPage {
                         self.reset_filter();
                     }
                 }
+                key_code_char!('u')
+                | key_code_char!('D')
+                | key_code_char!('c')
+                | key_code_char!('m')
+                | key_code_char!('C')
+                | key_code_char!('H')
+                | key_code_char!('U')
+                    if self.is_time_traveling() =>
+                {
+                    let msg = "Not available in time travel mode (press T to exit)".to_string();
+                    self.tx.send(AppEventType::NotifyWarn(msg));
+                }
+                key_code!(KeyCode::Enter)
+                | key_code_char!('s')
+                | key_code_char!('D')
+                | key_code_char!('c')
+                | key_code_char!('m')
+                | key_code_char!('C')
+                    if self.non_empty() && self.is_selected_deleted() =>
+                {
+                    let msg = "The object is deleted (press U to undelete)".to_string();
+                    self.tx.send(AppEventType::NotifyWarn(msg));
+                }
                 key_code!(KeyCode::Enter) if self.non_empty() => {
                     self.tx.send(AppEventType::ObjectListMoveDown);
                 }
@@ -124,6 +169,38 @@ impl ObjectListPage {
                 key_code_char!('r') => {
                     self.open_copy_detail_dialog();
                 }
+                key_code_char!('s') if self.non_empty() => {
+                    self.tx.send(AppEventType::ObjectListDownloadObject);
+                }
+                key_code_char!('u') => {
+                    self.open_upload_dialog();
+                }
+                key_code_char!('D') if self.non_empty() => {
+                    self.delete();
+                }
+                key_code_char!('c') if self.non_empty() => {
+                    self.tx
+                        .send(AppEventType::ObjectListOpenCopyDialog(CopyMode::Copy));
+                }
+                key_code_char!('m') if self.non_empty() => {
+                    self.tx
+                        .send(AppEventType::ObjectListOpenCopyDialog(CopyMode::Move));
+                }
+                key_code_char!('C') if self.non_empty() => {
+                    self.tx.send(AppEventType::ObjectListOpenStorageClassDialog);
+                }
+                key_code_char!('H') => {
+                    self.toggle_deleted_items();
+                }
+                key_code_char!('U') if self.non_empty() => {
+                    self.open_undelete_dialog();
+                }
+                key_code_char!('T') => {
+                    self.toggle_time_travel();
+                }
+                key_code_char!('t') => {
+                    self.tx.send(AppEventType::OpenTransfers);
+                }
                 key_code_char!('?') => {
                     self.tx.send(AppEventType::OpenHelp);
                 }
@@ -181,6 +258,119 @@ impl ObjectListPage {
                 }
                 _ => {}
             },
+            ViewState::UploadDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_upload_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    let input = state.input().into();
+                    self.upload(input);
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {
+                    state.handle_key_event(key);
+                }
+            },
+            ViewState::DeleteConfirmDialog(ref target) => match key {
+                key_code!(KeyCode::Esc) | key_code!(KeyCode::Backspace) => {
+                    self.close_delete_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    let target = *target.clone();
+                    self.tx.send(AppEventType::DeleteObjects(target));
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::StorageClassDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) | key_code!(KeyCode::Backspace) => {
+                    self.close_storage_class_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    self.change_storage_class();
+                }
+                key_code_char!('j') => {
+                    state.select_next();
+                }
+                key_code_char!('k') => {
+                    state.select_prev();
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::UndeleteConfirmDialog(ref object_key) => match key {
+                key_code!(KeyCode::Esc) | key_code!(KeyCode::Backspace) => {
+                    self.close_undelete_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    let object_key = object_key.clone();
+                    self.tx.send(AppEventType::UndeleteObject(object_key));
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {}
+            },
+            ViewState::UndeleteDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_undelete_objects_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    self.undelete_objects();
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {
+                    state.handle_key_event(key);
+                }
+            },
+            ViewState::TimeTravelDialog(ref mut state) => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_time_travel_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    let input = state.input().to_owned();
+                    self.time_travel(&input);
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                _ => {
+                    state.handle_key_event(key);
+                }
+            },
+            ViewState::CopyDialog(_, ref mut state) => match key {
+                key_code!(KeyCode::Esc) => {
+                    self.close_copy_dialog();
+                }
+                key_code!(KeyCode::Enter) => {
+                    self.copy();
+                }
+                key_code!(KeyCode::Tab) => {
+                    state.toggle_focus();
+                }
+                key_code_char!('?') => {
+                    self.tx.send(AppEventType::OpenHelp);
+                }
+                key_code_char!('j') if !state.is_prefix_focused() => {
+                    state.select_next();
+                }
+                key_code_char!('k') if !state.is_prefix_focused() => {
+                    state.select_prev();
+                }
+                _ => {
+                    if state.is_prefix_focused() {
+                        state.handle_key_event(key);
+                    }
+                }
+            },
         }
     }
 
@@ -191,6 +381,7 @@ impl ObjectListPage {
         let list_items = build_list_items(
             &self.object_items,
             &self.view_indices,
+            self.live_items_len,
             self.filter_input_state.input(),
             offset,
             selected,
@@ -199,7 +390,14 @@ impl ObjectListPage {
             &self.ctx.theme,
         );
 
-        let list = ScrollList::new(list_items).theme(&self.ctx.theme);
+        let mut list = ScrollList::new(list_items).theme(&self.ctx.theme);
+        if let Some(at) = &self.time_travel_at {
+            let date_format = &self.ctx.config.ui.object_list.date_format;
+            list = list.title(format!(
+                " Time travel: {} ",
+                format_datetime(at, date_format)
+            ));
+        }
         f.render_stateful_widget(list, area, &mut self.list_state);
 
         if let ViewState::FilterDialog = self.view_state {
@@ -223,6 +421,75 @@ impl ObjectListPage {
             let copy_detail_dialog = CopyDetailDialog::default().theme(&self.ctx.theme);
             f.render_stateful_widget(copy_detail_dialog, area, state);
         }
+
+        if let ViewState::UploadDialog(state) = &mut self.view_state {
+            let upload_dialog = InputDialog::default()
+                .title("Upload")
+                .max_width(40)
+                .theme(&self.ctx.theme);
+            f.render_stateful_widget(upload_dialog, area, state);
+
+            let (cursor_x, cursor_y) = state.cursor();
+            f.set_cursor_position((cursor_x, cursor_y));
+        }
+
+        if let ViewState::DeleteConfirmDialog(target) = &self.view_state {
+            let lines = build_delete_confirm_lines(target);
+            let delete_dialog = ConfirmDialog::new(lines)
+                .title("Delete")
+                .max_width(70)
+                .theme(&self.ctx.theme);
+            f.render_widget(delete_dialog, area);
+        }
+
+        if let ViewState::CopyDialog(mode, state) = &mut self.view_state {
+            let title = match mode {
+                CopyMode::Copy => "Copy to",
+                CopyMode::Move => "Move to",
+            };
+            let copy_dialog = DestinationDialog::default()
+                .title(title)
+                .max_width(60)
+                .theme(&self.ctx.theme);
+            f.render_stateful_widget(copy_dialog, area, state);
+
+            if let Some((cursor_x, cursor_y)) = state.cursor() {
+                f.set_cursor_position((cursor_x, cursor_y));
+            }
+        }
+
+        if let ViewState::StorageClassDialog(state) = &mut self.view_state {
+            let storage_class_dialog = StorageClassDialog::default().theme(&self.ctx.theme);
+            f.render_stateful_widget(storage_class_dialog, area, state);
+        }
+
+        if let ViewState::UndeleteConfirmDialog(object_key) = &self.view_state {
+            let lines = build_undelete_confirm_lines(object_key);
+            let undelete_dialog = ConfirmDialog::new(lines)
+                .title("Undelete")
+                .max_width(70)
+                .theme(&self.ctx.theme);
+            f.render_widget(undelete_dialog, area);
+        }
+
+        if let ViewState::UndeleteDialog(state) = &mut self.view_state {
+            let undelete_dialog = UndeleteDialog::default().theme(&self.ctx.theme);
+            f.render_stateful_widget(undelete_dialog, area, state);
+
+            let (cursor_x, cursor_y) = state.cursor();
+            f.set_cursor_position((cursor_x, cursor_y));
+        }
+
+        if let ViewState::TimeTravelDialog(state) = &mut self.view_state {
+            let time_travel_dialog = InputDialog::default()
+                .title("Time travel to")
+                .max_width(40)
+                .theme(&self.ctx.theme);
+            f.render_stateful_widget(time_travel_dialog, area, state);
+
+            let (cursor_x, cursor_y) = state.cursor();
+            f.set_cursor_position((cursor_x, cursor_y));
+        }
     }
 
     pub fn helps(&self) -> Vec<String> {
@@ -242,7 +509,17 @@ impl ObjectListPage {
                         (&["o"], "Sort object list"),
                         (&["r"], "Open copy dialog"),
                         (&["R"], "Refresh object list"),
+                        (&["s"], "Download object or folder"),
+                        (&["u"], "Upload file or directory"),
+                        (&["D"], "Delete object or folder"),
+                        (&["c"], "Copy object or folder to"),
+                        (&["m"], "Move object or folder to"),
+                        (&["C"], "Change storage class"),
+                        (&["H"], "Toggle deleted objects"),
+                        (&["U"], "Undelete object or folder"),
+                        (&["T"], "Toggle time travel"),
                         (&["x"], "Open management console in browser"),
+                        (&["t"], "Open transfers"),
                     ]
                 } else {
                     &[
@@ -259,7 +536,17 @@ impl ObjectListPage {
                         (&["o"], "Sort object list"),
                         (&["r"], "Open copy dialog"),
                         (&["R"], "Refresh object list"),
+                        (&["s"], "Download object or folder"),
+                        (&["u"], "Upload file or directory"),
+                        (&["D"], "Delete object or folder"),
+                        (&["c"], "Copy object or folder to"),
+                        (&["m"], "Move object or folder to"),
+                        (&["C"], "Change storage class"),
+                        (&["H"], "Toggle deleted objects"),
+                        (&["U"], "Undelete object or folder"),
+                        (&["T"], "Toggle time travel"),
                         (&["x"], "Open management console in browser"),
+                        (&["t"], "Open transfers"),
                     ]
                 }
             }
@@ -280,6 +567,44 @@ impl ObjectListPage {
                 (&["j/k"], "Select item"),
                 (&["Enter"], "Copy selected value to clipboard"),
             ],
+            ViewState::UploadDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close upload dialog"),
+                (&["Enter"], "Upload file or directory"),
+            ],
+            ViewState::DeleteConfirmDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "Backspace"], "Close delete dialog"),
+                (&["Enter"], "Delete"),
+            ],
+            ViewState::CopyDialog(_, _) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close dialog"),
+                (&["Tab"], "Switch bucket/prefix"),
+                (&["j/k"], "Select bucket"),
+                (&["Enter"], "Copy or move to destination"),
+            ],
+            ViewState::StorageClassDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "Backspace"], "Close storage class dialog"),
+                (&["j/k"], "Select storage class"),
+                (&["Enter"], "Change storage class"),
+            ],
+            ViewState::UndeleteConfirmDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc", "Backspace"], "Close undelete dialog"),
+                (&["Enter"], "Undelete"),
+            ],
+            ViewState::UndeleteDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close undelete dialog"),
+                (&["Enter"], "Undelete"),
+            ],
+            ViewState::TimeTravelDialog(_) => &[
+                (&["Ctrl-c"], "Quit app"),
+                (&["Esc"], "Close time travel dialog"),
+                (&["Enter"], "Show objects at the time"),
+            ],
         };
         build_helps(helps)
     }
@@ -330,6 +655,43 @@ impl ObjectListPage {
                 (&["Enter"], "Copy", 1),
                 (&["?"], "Help", 0),
             ],
+            ViewState::UploadDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["Enter"], "Upload", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::DeleteConfirmDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["Enter"], "Delete", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::CopyDialog(_, _) => &[
+                (&["Esc"], "Close", 2),
+                (&["Tab"], "Switch", 3),
+                (&["Enter"], "Confirm", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::StorageClassDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["j/k"], "Select", 3),
+                (&["Enter"], "Change", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::UndeleteConfirmDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["Enter"], "Undelete", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::UndeleteDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["Enter"], "Undelete", 1),
+                (&["?"], "Help", 0),
+            ],
+            ViewState::TimeTravelDialog(_) => &[
+                (&["Esc"], "Close", 2),
+                (&["Enter"], "Travel", 1),
+                (&["?"], "Help", 0),
+            ],
         };
         build_short_helps(helps)
     }
@@ -393,6 +755,235 @@ impl ObjectListPage {
         self.view_state = ViewState::Default;
     }
 
+    fn open_upload_dialog(&mut self) {
+        self.view_state = ViewState::UploadDialog(InputDialogState::default());
+    }
+
+    pub fn close_upload_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    pub fn open_delete_dialog(&mut self, target: DeleteTarget) {
+        self.view_state = ViewState::DeleteConfirmDialog(Box::new(target));
+    }
+
+    pub fn close_delete_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    pub fn open_copy_dialog(&mut self, mode: CopyMode, buckets: Vec<String>) {
+        let bucket = &self.object_key.bucket_name;
+        let prefix = self.object_key.joined_object_path(false);
+        let state = DestinationDialogState::new(buckets, bucket, &prefix);
+        self.view_state = ViewState::CopyDialog(mode, Box::new(state));
+    }
+
+    pub fn close_copy_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    pub fn open_storage_class_dialog(&mut self, target: StorageClassTarget) {
+        let state = StorageClassDialogState::new(target);
+        self.view_state = ViewState::StorageClassDialog(Box::new(state));
+    }
+
+    pub fn close_storage_class_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    fn toggle_deleted_items(&mut self) {
+        if self.show_deleted {
+            self.object_items.truncate(self.live_items_len);
+            self.show_deleted = false;
+            self.filter_view_indices();
+        } else {
+            // deleted objects are loaded every time, since they are not cached
+            self.tx.send(AppEventType::ObjectListShowDeletedObjects);
+        }
+    }
+
+    pub fn show_deleted_items(&mut self, items: Vec<ObjectItem>) {
+        self.object_items.truncate(self.live_items_len);
+        self.object_items.extend(items);
+        self.show_deleted = true;
+        self.filter_view_indices();
+    }
+
+    pub fn is_showing_deleted(&self) -> bool {
+        self.show_deleted
+    }
+
+    fn open_undelete_dialog(&mut self) {
+        if let ObjectItem::Dir { .. } = self.current_selected_item() {
+            // the delete markers under the prefix must be loaded before confirmation
+            self.tx.send(AppEventType::ObjectListOpenUndeleteDialog);
+            return;
+        }
+        if !self.is_selected_deleted() {
+            let msg = "The object is not deleted".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        let object_key = self.current_selected_object_key();
+        self.view_state = ViewState::UndeleteConfirmDialog(object_key);
+    }
+
+    pub fn close_undelete_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    pub fn open_undelete_objects_dialog(&mut self, target: UndeleteTarget) {
+        let state = UndeleteDialogState::new(target);
+        self.view_state = ViewState::UndeleteDialog(Box::new(state));
+    }
+
+    pub fn close_undelete_objects_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    fn undelete_objects(&self) {
+        let ViewState::UndeleteDialog(state) = &self.view_state else {
+            return;
+        };
+        match state.target_to_undelete() {
+            None => {
+                let msg = format!("Invalid time (expected {})", DATETIME_INPUT_FORMAT);
+                self.tx.send(AppEventType::NotifyWarn(msg));
+            }
+            Some(target) if target.markers.is_empty() => {
+                let msg = "No objects were deleted after the time".to_string();
+                self.tx.send(AppEventType::NotifyWarn(msg));
+            }
+            Some(target) => {
+                self.tx.send(AppEventType::UndeleteObjects(target));
+            }
+        }
+    }
+
+    fn toggle_time_travel(&mut self) {
+        if self.is_time_traveling() {
+            self.tx.send(AppEventType::ObjectListExitTimeTravel);
+        } else {
+            let now = Local::now().format(DATETIME_INPUT_FORMAT).to_string();
+            self.view_state = ViewState::TimeTravelDialog(InputDialogState::new(now));
+        }
+    }
+
+    fn close_time_travel_dialog(&mut self) {
+        self.view_state = ViewState::Default;
+    }
+
+    fn time_travel(&mut self, input: &str) {
+        let Some(at) = parse_datetime_input(input) else {
+            let msg = format!("Invalid time (expected {})", DATETIME_INPUT_FORMAT);
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        };
+        if at > Local::now() {
+            let msg = "Cannot travel to the future".to_string();
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        self.close_time_travel_dialog();
+        let object_key = self.object_key.clone();
+        self.tx.send(AppEventType::LoadObjectsAt(object_key, at));
+    }
+
+    pub fn set_time_travel_objects(&mut self, at: DateTime<Local>, objects: TimeTravelObjects) {
+        self.live_items_len = objects.items.len();
+        self.object_items = objects.items;
+        self.version_ids = objects.version_ids;
+        self.show_deleted = false;
+        self.time_travel_at = Some(at);
+        self.filter_view_indices();
+    }
+
+    pub fn time_travel_at(&self) -> Option<DateTime<Local>> {
+        self.time_travel_at
+    }
+
+    pub fn is_time_traveling(&self) -> bool {
+        self.time_travel_at.is_some()
+    }
+
+    // the version of the selected file that was current at the time traveled to
+    pub fn current_selected_version_id(&self) -> Option<String> {
+        match self.current_selected_item() {
+            ObjectItem::File { key, .. } => self.version_ids.get(key).cloned(),
+            ObjectItem::Dir { .. } => None,
+        }
+    }
+
+    fn delete(&mut self) {
+        match self.current_selected_item() {
+            ObjectItem::Dir { .. } => {
+                // all objects under the prefix must be loaded before confirmation
+                self.tx.send(AppEventType::ObjectListOpenDeleteDialog);
+            }
+            ObjectItem::File { key, size_byte, .. } => {
+                let target = DeleteTarget {
+                    object_key: self.current_selected_object_key(),
+                    dir: false,
+                    objects: vec![ObjectSummary {
+                        key: key.clone(),
+                        size_byte: *size_byte,
+                        storage_class: String::new(),
+                        restored: false,
+                    }],
+                    version_id: None,
+                };
+                self.open_delete_dialog(target);
+            }
+        }
+    }
+
+    fn copy(&self) {
+        let ViewState::CopyDialog(mode, state) = &self.view_state else {
+            return;
+        };
+        let Some(bucket) = state.selected_bucket() else {
+            return;
+        };
+        let target = CopyTarget {
+            object_key: self.current_selected_object_key(),
+            dir: matches!(self.current_selected_item(), ObjectItem::Dir { .. }),
+            dst_bucket: bucket.into(),
+            dst_prefix: state.prefix(),
+            mode: *mode,
+        };
+        self.tx.send(AppEventType::CopyObjects(target));
+    }
+
+    fn change_storage_class(&self) {
+        let ViewState::StorageClassDialog(state) = &self.view_state else {
+            return;
+        };
+        let storage_class = state.selected_storage_class();
+        if state.target().objects_to_change(storage_class).is_empty() {
+            let msg = if state.target().unrestored_objects(storage_class).is_empty() {
+                format!("All objects are already in {}", storage_class)
+            } else {
+                "Archived objects must be restored before changing the storage class".to_string()
+            };
+            self.tx.send(AppEventType::NotifyWarn(msg));
+            return;
+        }
+        let target = state.target().clone();
+        self.tx.send(AppEventType::ChangeStorageClass(
+            target,
+            storage_class.into(),
+        ));
+    }
+
+    fn upload(&self, input: String) {
+        let input: String = input.trim().into();
+        if input.is_empty() {
+            return;
+        }
+
+        self.tx.send(AppEventType::ObjectListUploadObject(input));
+    }
+
     fn apply_filter(&mut self) {
         self.view_state = ViewState::Default;
 
@@ -514,11 +1105,18 @@ impl ObjectListPage {
     fn non_empty(&self) -> bool {
         !self.view_indices.is_empty()
     }
+
+    fn is_selected_deleted(&self) -> bool {
+        self.view_indices
+            .get(self.list_state.selected)
+            .is_some_and(|i| *i >= self.live_items_len)
+    }
 }
 
 fn build_list_items<'a>(
     current_items: &'a [ObjectItem],
     view_indices: &'a [usize],
+    live_items_len: usize,
     filter: &'a str,
     offset: usize,
     selected: usize,
@@ -529,14 +1127,15 @@ fn build_list_items<'a>(
     let show_item_count = (area.height as usize) - 2 /* border */;
     view_indices
         .iter()
-        .map(|&original_idx| &current_items[original_idx])
+        .map(|&original_idx| (&current_items[original_idx], original_idx >= live_items_len))
         .skip(offset)
         .take(show_item_count)
         .enumerate()
-        .map(|(idx, item)| {
+        .map(|(idx, (item, deleted))| {
             build_list_item(
                 item,
                 idx + offset == selected,
+                deleted,
                 filter,
                 area,
                 ui_config,
@@ -549,6 +1148,7 @@ fn build_list_items<'a>(
 fn build_list_item<'a>(
     item: &'a ObjectItem,
     selected: bool,
+    deleted: bool,
     filter: &'a str,
     area: Rect,
     ui_config: &UiConfig,
@@ -576,12 +1176,27 @@ fn build_list_item<'a>(
         Style::default()
             .bg(theme.list_selected_bg)
             .fg(theme.list_selected_fg)
+    } else if deleted {
+        Style::default().fg(theme.list_deleted)
     } else {
         Style::default()
     };
     ListItem::new(line).style(style)
 }
 
+fn build_undelete_confirm_lines(object_key: &ObjectKey) -> Vec<Line<'static>> {
+    vec![
+        Line::from("Undelete the object?".bold()),
+        Line::from(format!(
+            "  s3://{}/{}",
+            object_key.bucket_name,
+            object_key.joined_object_path(true)
+        )),
+        Line::from(""),
+        Line::from("The latest delete marker will be removed."),
+    ]
+}
+
 fn build_object_dir_line<'a>(name: &'a str, filter: &'a str, theme: &ColorTheme) -> Line<'a> {
     if filter.is_empty() {
         Line::from(vec![" ".into(), name.bold(), "/".bold(), " ".into()])
@@ -705,6 +1320,103 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render_with_deleted_items() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let items = vec![
+                object_dir_item("dir1"),
+                object_file_item("file1", 1024 + 10, "2024-01-02 13:01:02"),
+            ];
+            let object_key = ObjectKey {
+                bucket_name: "test-bucket".to_string(),
+                object_path: vec!["path".to_string(), "to".to_string()],
+            };
+            let mut page = ObjectListPage::new(items, object_key, ctx, tx);
+            page.show_deleted_items(vec![object_file_item("file2", 0, "2024-01-03 08:00:00")]);
+            let area = Rect::new(0, 0, 60, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────────────────────────── 1 / 3 ┐",
+            "│  dir1/                                                   │",
+            "│  file1                2024-01-02 13:01:02      1.01 KiB  │",
+            "│  file2                2024-01-03 08:00:00           0 B  │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // dir items
+            (3..8, [1]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..58, [1]) => bg: Color::Cyan, fg: Color::Black,
+            // deleted item
+            (2..58, [3]) => fg: Color::DarkGray,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_with_time_travel() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let object_key = ObjectKey {
+                bucket_name: "test-bucket".to_string(),
+                object_path: vec!["path".to_string(), "to".to_string()],
+            };
+            let mut page = ObjectListPage::new(vec![], object_key, ctx, tx);
+            let objects = TimeTravelObjects {
+                items: vec![
+                    object_dir_item("dir1"),
+                    object_file_item("file1", 1024 + 10, "2024-01-02 13:01:02"),
+                ],
+                version_ids: HashMap::new(),
+            };
+            page.set_time_travel_objects(parse_datetime("2024-01-03 00:00:00"), objects);
+            let area = Rect::new(0, 0, 60, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌ Time travel: 2024-01-03 00:00:00 ───────────────── 1 / 2 ┐",
+            "│  dir1/                                                   │",
+            "│  file1                2024-01-02 13:01:02      1.01 KiB  │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // dir items
+            (3..8, [1]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..58, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_render_with_scroll() -> std::io::Result<()> {
         let ctx = Rc::default();
@@ -796,6 +1508,100 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_render_upload_dialog() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let items = vec![
+                object_dir_item("dir1"),
+                object_file_item("file1", 1024 + 10, "2024-01-02 13:01:02"),
+            ];
+            let object_key = ObjectKey {
+                bucket_name: "test-bucket".to_string(),
+                object_path: vec!["path".to_string(), "to".to_string()],
+            };
+            let mut page = ObjectListPage::new(items, object_key, ctx, tx);
+            page.handle_key(KeyEvent::from(KeyCode::Char('u')));
+            for c in "~/foo".chars() {
+                page.handle_key(KeyEvent::from(KeyCode::Char(c)));
+            }
+            let area = Rect::new(0, 0, 60, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────────────────────────── 1 / 2 ┐",
+            "│  dir1/                                                   │",
+            "│  file1                2024-01-02 13:01:02      1.01 KiB  │",
+            "│         ╭Upload────────────────────────────────╮         │",
+            "│         │ ~/foo                                │         │",
+            "│         ╰──────────────────────────────────────╯         │",
+            "│                                                          │",
+            "│                                                          │",
+            "│                                                          │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // dir items
+            (3..8, [1]) => modifier: Modifier::BOLD,
+            // selected item
+            (2..58, [1]) => bg: Color::Cyan, fg: Color::Black,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_delete_dialog() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let items = vec![object_file_item("file1", 1024 + 10, "2024-01-02 13:01:02")];
+            let object_key = ObjectKey {
+                bucket_name: "test-bucket".to_string(),
+                object_path: vec!["path".to_string(), "to".to_string()],
+            };
+            let mut page = ObjectListPage::new(items, object_key, ctx, tx);
+            page.handle_key(KeyEvent::from(KeyCode::Char('D')));
+            let area = Rect::new(0, 0, 60, 10);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────────────────────────── 1 / 1 ┐",
+            "│ ╭Delete────────────────────────────────────────────────╮ │",
+            "│ │ Delete the object?                                   │ │",
+            "│ │   s3://test-bucket/path/to/file1                     │ │",
+            "│ │ Keys: 1                                              │ │",
+            "│ │ Total size: 1.01 KiB                                 │ │",
+            "│ │                                                      │ │",
+            "│ │ Enter: Confirm, Esc: Cancel                          │ │",
+            "│ ╰──────────────────────────────────────────────────────╯ │",
+            "└──────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // labels
+            (4..22, [2]) => modifier: Modifier::BOLD,
+            (4..10, [4]) => modifier: Modifier::BOLD,
+            (4..16, [5]) => modifier: Modifier::BOLD,
+            // help line
+            (4..31, [7]) => fg: Color::Yellow, modifier: Modifier::BOLD,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_sort_items() {
         let ctx = Rc::default();
@@ -510,6 +510,7 @@ mod tests {
             s3_uri: "s3://bucket-1/file.txt".to_string(),
             arn: "arn:aws:s3:::bucket-1/file.txt".to_string(),
             object_url: "https://bucket-1.s3.ap-northeast-1.amazonaws.com/file.txt".to_string(),
+            ..Default::default()
         }
     }
 }
@@ -7,10 +7,11 @@ use crate::{
     event::Sender,
     object::{BucketItem, FileDetail, ObjectItem, ObjectKey, RawObject},
     pages::{
-        bucket_list::BucketListPage, help::HelpPage, initializing::InitializingPage,
-        object_detail::ObjectDetailPage, object_list::ObjectListPage,
-        object_preview::ObjectPreviewPage,
+        bucket_detail::BucketDetailPage, bucket_list::BucketListPage, help::HelpPage,
+        initializing::InitializingPage, object_detail::ObjectDetailPage,
+        object_list::ObjectListPage, object_preview::ObjectPreviewPage, transfers::TransfersPage,
     },
+    transfer::TransferItem,
     widget::ScrollListState,
 };
 
@@ -18,9 +19,11 @@ use crate::{
 pub enum Page {
     Initializing(Box<InitializingPage>),
     BucketList(Box<BucketListPage>),
+    BucketDetail(Box<BucketDetailPage>),
     ObjectList(Box<ObjectListPage>),
     ObjectDetail(Box<ObjectDetailPage>),
     ObjectPreview(Box<ObjectPreviewPage>),
+    Transfers(Box<TransfersPage>),
     Help(Box<HelpPage>),
 }
 
@@ -29,9 +32,11 @@ impl Page {
         match self {
             Page::Initializing(page) => page.handle_key(key),
             Page::BucketList(page) => page.handle_key(key),
+            Page::BucketDetail(page) => page.handle_key(key),
             Page::ObjectList(page) => page.handle_key(key),
             Page::ObjectDetail(page) => page.handle_key(key),
             Page::ObjectPreview(page) => page.handle_key(key),
+            Page::Transfers(page) => page.handle_key(key),
             Page::Help(page) => page.handle_key(key),
         }
     }
@@ -40,9 +45,11 @@ impl Page {
         match self {
             Page::Initializing(page) => page.render(f, area),
             Page::BucketList(page) => page.render(f, area),
+            Page::BucketDetail(page) => page.render(f, area),
             Page::ObjectList(page) => page.render(f, area),
             Page::ObjectDetail(page) => page.render(f, area),
             Page::ObjectPreview(page) => page.render(f, area),
+            Page::Transfers(page) => page.render(f, area),
             Page::Help(page) => page.render(f, area),
         }
     }
@@ -51,9 +58,11 @@ impl Page {
         match self {
             Page::Initializing(page) => page.helps(),
             Page::BucketList(page) => page.helps(),
+            Page::BucketDetail(page) => page.helps(),
             Page::ObjectList(page) => page.helps(),
             Page::ObjectDetail(page) => page.helps(),
             Page::ObjectPreview(page) => page.helps(),
+            Page::Transfers(page) => page.helps(),
             Page::Help(page) => page.helps(),
         }
     }
@@ -62,9 +71,11 @@ impl Page {
         match self {
             Page::Initializing(page) => page.short_helps(),
             Page::BucketList(page) => page.short_helps(),
+            Page::BucketDetail(page) => page.short_helps(),
             Page::ObjectList(page) => page.short_helps(),
             Page::ObjectDetail(page) => page.short_helps(),
             Page::ObjectPreview(page) => page.short_helps(),
+            Page::Transfers(page) => page.short_helps(),
             Page::Help(page) => page.short_helps(),
         }
     }
@@ -79,6 +90,10 @@ impl Page {
         Self::BucketList(Box::new(BucketListPage::new(bucket_items, ctx, tx)))
     }
 
+    pub fn of_bucket_detail(bucket_name: String, ctx: Rc<AppContext>, tx: Sender) -> Self {
+        Self::BucketDetail(Box::new(BucketDetailPage::new(bucket_name, ctx, tx)))
+    }
+
     pub fn of_object_list(
         object_items: Vec<ObjectItem>,
         object_key: ObjectKey,
@@ -131,6 +146,10 @@ impl Page {
         )))
     }
 
+    pub fn of_transfers(items: Vec<TransferItem>, ctx: Rc<AppContext>, tx: Sender) -> Self {
+        Self::Transfers(Box::new(TransfersPage::new(items, ctx, tx)))
+    }
+
     pub fn of_help(helps: Vec<String>, ctx: Rc<AppContext>, tx: Sender) -> Self {
         Self::Help(Box::new(HelpPage::new(helps, ctx, tx)))
     }
@@ -142,6 +161,13 @@ impl Page {
         }
     }
 
+    pub fn as_mut_bucket_detail(&mut self) -> &mut BucketDetailPage {
+        match self {
+            Self::BucketDetail(page) => &mut *page,
+            page => panic!("Page is not BucketDetail: {:?}", page),
+        }
+    }
+
     pub fn as_object_list(&self) -> &ObjectListPage {
         match self {
             Self::ObjectList(page) => page,
@@ -149,6 +175,13 @@ impl Page {
         }
     }
 
+    pub fn as_mut_object_list(&mut self) -> &mut ObjectListPage {
+        match self {
+            Self::ObjectList(page) => &mut *page,
+            page => panic!("Page is not ObjectList: {:?}", page),
+        }
+    }
+
     pub fn as_object_detail(&self) -> &ObjectDetailPage {
         match self {
             Self::ObjectDetail(page) => page,
@@ -214,7 +247,11 @@ impl PageStack {
         self.stack.last_mut().unwrap()
     }
 
-    pub fn iter(&self) -> std::slice::Iter<Page> {
+    pub fn iter(&self) -> std::slice::Iter<'_, Page> {
         self.stack.iter()
     }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Page> {
+        self.stack.iter_mut()
+    }
 }
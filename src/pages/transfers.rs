@@ -0,0 +1,325 @@
+use std::{
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use laurier::{key_code, key_code_char};
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::ListItem,
+    Frame,
+};
+
+use crate::{
+    app::AppContext,
+    color::ColorTheme,
+    event::{AppEventType, Sender},
+    pages::util::{build_helps, build_short_helps},
+    transfer::{TransferItem, TransferStatus},
+    ui::common::format_size_byte,
+    widget::{ScrollList, ScrollListState},
+};
+
+#[derive(Debug)]
+pub struct TransfersPage {
+    items: Vec<TransferItem>,
+
+    list_state: ScrollListState,
+
+    ctx: Rc<AppContext>,
+    tx: Sender,
+}
+
+impl TransfersPage {
+    pub fn new(items: Vec<TransferItem>, ctx: Rc<AppContext>, tx: Sender) -> Self {
+        let items_len = items.len();
+        Self {
+            items,
+            list_state: ScrollListState::new(items_len),
+            ctx,
+            tx,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        match key {
+            key_code!(KeyCode::Esc) => {
+                self.tx.send(AppEventType::Quit);
+            }
+            key_code!(KeyCode::Backspace) => {
+                self.tx.send(AppEventType::CloseCurrentPage);
+            }
+            key_code_char!('j') if self.non_empty() => {
+                self.list_state.select_next();
+            }
+            key_code_char!('k') if self.non_empty() => {
+                self.list_state.select_prev();
+            }
+            key_code_char!('g') if self.non_empty() => {
+                self.list_state.select_first();
+            }
+            key_code_char!('G') if self.non_empty() => {
+                self.list_state.select_last();
+            }
+            key_code_char!('r') if self.non_empty() => {
+                if let TransferStatus::Failed(_) = self.current_selected_item().status {
+                    let id = self.current_selected_item().id;
+                    self.tx.send(AppEventType::RetryTransfer(id));
+                }
+            }
+            key_code_char!('x') if self.non_empty() => {
+                if let TransferStatus::Queued | TransferStatus::Running =
+                    self.current_selected_item().status
+                {
+                    let id = self.current_selected_item().id;
+                    self.tx.send(AppEventType::CancelTransfer(id));
+                }
+            }
+            key_code_char!('?') => {
+                self.tx.send(AppEventType::OpenHelp);
+            }
+            _ => {}
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let offset = self.list_state.offset;
+        let selected = self.list_state.selected;
+
+        let list_items = build_list_items(
+            &self.items,
+            offset,
+            selected,
+            area,
+            Instant::now(),
+            &self.ctx.theme,
+        );
+
+        let list = ScrollList::new(list_items).theme(&self.ctx.theme);
+        f.render_stateful_widget(list, area, &mut self.list_state);
+    }
+
+    pub fn helps(&self) -> Vec<String> {
+        let helps: &[(&[&str], &str)] = &[
+            (&["Esc", "Ctrl-c"], "Quit app"),
+            (&["j/k"], "Select item"),
+            (&["g/G"], "Go to top/bottom"),
+            (&["r"], "Retry failed transfer"),
+            (&["x"], "Cancel transfer"),
+            (&["Backspace"], "Close transfers"),
+        ];
+        build_helps(helps)
+    }
+
+    pub fn short_helps(&self) -> Vec<(String, usize)> {
+        let helps: &[(&[&str], &str, usize)] = &[
+            (&["Esc"], "Quit", 0),
+            (&["j/k"], "Select", 3),
+            (&["r"], "Retry", 1),
+            (&["x"], "Cancel", 2),
+            (&["Backspace"], "Close", 1),
+            (&["?"], "Help", 0),
+        ];
+        build_short_helps(helps)
+    }
+}
+
+impl TransfersPage {
+    pub fn set_items(&mut self, items: Vec<TransferItem>) {
+        self.list_state.set_total(items.len());
+        self.items = items;
+    }
+
+    fn current_selected_item(&self) -> &TransferItem {
+        &self.items[self.list_state.selected]
+    }
+
+    fn non_empty(&self) -> bool {
+        !self.items.is_empty()
+    }
+}
+
+fn build_list_items(
+    items: &[TransferItem],
+    offset: usize,
+    selected: usize,
+    area: Rect,
+    now: Instant,
+    theme: &ColorTheme,
+) -> Vec<ListItem<'static>> {
+    let show_item_count = (area.height as usize) - 2 /* border */;
+    items
+        .iter()
+        .skip(offset)
+        .take(show_item_count)
+        .enumerate()
+        .map(|(idx, item)| build_list_item(item, idx + offset == selected, area.width, now, theme))
+        .collect()
+}
+
+fn build_list_item(
+    item: &TransferItem,
+    selected: bool,
+    width: u16,
+    now: Instant,
+    theme: &ColorTheme,
+) -> ListItem<'static> {
+    let (status, status_style) = match &item.status {
+        TransferStatus::Queued => ("Queued", Style::default()),
+        TransferStatus::Running => ("Running", Style::default().fg(theme.status_info)),
+        TransferStatus::Finished => ("Done", Style::default().fg(theme.status_success)),
+        TransferStatus::Failed(_) => ("Failed", Style::default().fg(theme.status_error)),
+    };
+    let progress = format!(
+        "{} / {}",
+        format_size_byte(item.done_byte),
+        format_size_byte(item.total_byte)
+    );
+    let throughput = item
+        .throughput(now)
+        .map(|t| format!("{}/s", format_size_byte(t as usize)))
+        .unwrap_or_default();
+    let eta = item.eta(now).map(format_duration).unwrap_or_default();
+
+    let mut name = item.request.name();
+    if let TransferStatus::Failed(msg) = &item.status {
+        name = format!("{} ({})", name, msg);
+    }
+    let columns_w: usize = 66;
+    let name_w: usize = (width as usize).saturating_sub(columns_w + 4 /* border + pad */);
+    let name: String = name.chars().take(name_w).collect();
+
+    let line = Line::from(vec![
+        " ".into(),
+        format!("{:<8}", item.request.kind()).into(),
+        "  ".into(),
+        Span::styled(format!("{:<7}", status), status_style),
+        "  ".into(),
+        format!("{:>21}", progress).into(),
+        "  ".into(),
+        format!("{:>12}", throughput).into(),
+        "  ".into(),
+        format!("{:>6}", eta).into(),
+        "  ".into(),
+        name.into(),
+    ]);
+
+    let style = if selected {
+        Style::default()
+            .bg(theme.list_selected_bg)
+            .fg(theme.list_selected_fg)
+    } else {
+        Style::default()
+    };
+    ListItem::new(line).style(style)
+}
+
+fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    if secs >= 3600 {
+        format!("{}h{:02}m", secs / 3600, (secs % 3600) / 60)
+    } else if secs >= 60 {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{event, object::ObjectKey, set_cells, transfer::TransferRequest};
+
+    use super::*;
+    use ratatui::{backend::TestBackend, buffer::Buffer, style::Color, Terminal};
+
+    #[test]
+    fn test_render() -> std::io::Result<()> {
+        let ctx = Rc::default();
+        let (tx, _) = event::new();
+        let mut terminal = setup_terminal()?;
+
+        terminal.draw(|f| {
+            let start = Instant::now();
+            let items = vec![
+                transfer_item(0, "file1.txt", TransferStatus::Finished, 2048, Some(start)),
+                transfer_item(
+                    1,
+                    "file2.txt",
+                    TransferStatus::Failed("err".into()),
+                    0,
+                    None,
+                ),
+                transfer_item(2, "file3.txt", TransferStatus::Queued, 0, None),
+            ];
+            let mut page = TransfersPage::new(items, ctx, tx);
+            let area = Rect::new(0, 0, 100, 6);
+            page.render(f, area);
+        })?;
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "┌─────────────────────────────────────────────────────────────────────────────────────────── 1 / 3 ┐",
+            "│  Download  Done             2 KiB / 2 KiB       1 KiB/s          s3://bucket/file1.txt           │",
+            "│  Download  Failed             0 B / 2 KiB                        s3://bucket/file2.txt (err)     │",
+            "│  Download  Queued             0 B / 2 KiB                        s3://bucket/file3.txt           │",
+            "│                                                                                                  │",
+            "└──────────────────────────────────────────────────────────────────────────────────────────────────┘",
+        ]);
+        set_cells! { expected =>
+            // selected item
+            (2..98, [1]) => bg: Color::Cyan, fg: Color::Black,
+            // status
+            (13..20, [1]) => fg: Color::Green,
+            (13..20, [2]) => fg: Color::Red,
+        }
+
+        terminal.backend().assert_buffer(&expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_secs(42)), "42s");
+        assert_eq!(format_duration(Duration::from_secs(185)), "3m05s");
+        assert_eq!(format_duration(Duration::from_secs(7320)), "2h02m");
+    }
+
+    fn transfer_item(
+        id: usize,
+        name: &str,
+        status: TransferStatus,
+        done_byte: usize,
+        started_at: Option<Instant>,
+    ) -> TransferItem {
+        TransferItem {
+            id,
+            request: TransferRequest::Download {
+                object_key: ObjectKey {
+                    bucket_name: "bucket".into(),
+                    object_path: vec![name.into()],
+                },
+                version_id: None,
+                size_byte: 2048,
+                path: PathBuf::from(name),
+            },
+            status,
+            total_byte: 2048,
+            done_byte,
+            started_at,
+            finished_at: started_at.map(|t| t + Duration::from_secs(2)),
+        }
+    }
+
+    fn setup_terminal() -> std::io::Result<Terminal<TestBackend>> {
+        let backend = TestBackend::new(100, 6);
+        let mut terminal = Terminal::new(backend)?;
+        terminal.clear()?;
+        Ok(terminal)
+    }
+}
@@ -1,3 +1,7 @@
+use ratatui::{style::Stylize, text::Line};
+
+use crate::{object::DeleteTarget, ui::common::format_size_byte};
+
 pub fn build_helps(helps: &[(&[&str], &str)]) -> Vec<String> {
     helps
         .iter()
@@ -27,3 +31,29 @@ pub fn build_short_helps(helps: &[(&[&str], &str, usize)]) -> Vec<(String, usize
         })
         .collect()
 }
+
+pub fn build_delete_confirm_lines(target: &DeleteTarget) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(if target.dir {
+            "Delete all objects under the prefix?".bold()
+        } else {
+            "Delete the object?".bold()
+        }),
+        Line::from(format!("  {}", target.s3_uri())),
+    ];
+    if let Some(version_id) = &target.version_id {
+        lines.push(Line::from(vec![
+            "Version ID: ".bold(),
+            version_id.to_string().into(),
+        ]));
+    }
+    lines.push(Line::from(vec![
+        "Keys: ".bold(),
+        target.objects.len().to_string().into(),
+    ]));
+    lines.push(Line::from(vec![
+        "Total size: ".bold(),
+        format_size_byte(target.total_size_byte()).into(),
+    ]));
+    lines
+}
@@ -31,7 +31,10 @@ pub async fn run<B: Backend>(
                 }
 
                 if app.loading() {
-                    // Ignore key inputs while loading (except quit)
+                    if matches!(key, key_code!(KeyCode::Esc)) {
+                        app.cancel_loading();
+                    }
+                    // Ignore other key inputs while loading (except quit and cancel)
                     continue;
                 }
 
@@ -68,6 +71,9 @@ pub async fn run<B: Backend>(
             AppEventType::CompleteInitialize(result) => {
                 app.complete_initialize(result);
             }
+            AppEventType::BucketRegionsProgress(resolved, total) => {
+                app.update_bucket_regions_progress(resolved, total);
+            }
             AppEventType::ReloadBuckets => {
                 app.reload_buckets();
             }
@@ -98,6 +104,42 @@ pub async fn run<B: Backend>(
             AppEventType::CompleteLoadObjectVersions(result) => {
                 app.complete_load_object_versions(result);
             }
+            AppEventType::LoadMoreObjectVersions => {
+                app.load_more_object_versions();
+            }
+            AppEventType::CompleteLoadMoreObjectVersions(result) => {
+                app.complete_load_more_object_versions(result);
+            }
+            AppEventType::LoadObjectTags(version_id) => {
+                app.load_object_tags(version_id);
+            }
+            AppEventType::CompleteLoadObjectTags(result) => {
+                app.complete_load_object_tags(result);
+            }
+            AppEventType::SaveObjectTags(version_id, tags) => {
+                app.save_object_tags(version_id, tags);
+            }
+            AppEventType::CompleteSaveObjectTags(result) => {
+                app.complete_save_object_tags(result);
+            }
+            AppEventType::UpdateObjectMetadata(metadata) => {
+                app.update_object_metadata(metadata);
+            }
+            AppEventType::CompleteUpdateObjectMetadata(result) => {
+                app.complete_update_object_metadata(result);
+            }
+            AppEventType::PromoteObjectVersion(version) => {
+                app.promote_object_version(version);
+            }
+            AppEventType::CompletePromoteObjectVersion(result) => {
+                app.complete_promote_object_version(result);
+            }
+            AppEventType::RestoreObject(days, tier, version_id) => {
+                app.restore_object(days, tier, version_id);
+            }
+            AppEventType::CompleteRestoreObject(result) => {
+                app.complete_restore_object(result);
+            }
             AppEventType::DownloadObject(file_detail, version_id) => {
                 app.download_object(file_detail, version_id);
             }
@@ -113,12 +155,105 @@ pub async fn run<B: Backend>(
             AppEventType::CompletePreviewObject(result) => {
                 app.complete_preview_object(result);
             }
+            AppEventType::UploadObject(input) => {
+                app.upload_object(input);
+            }
+            AppEventType::TransferProgress(id, done_byte) => {
+                app.update_transfer_progress(id, done_byte);
+            }
+            AppEventType::CompleteTransfer(id, result) => {
+                app.complete_transfer(id, result);
+            }
+            AppEventType::RetryTransfer(id) => {
+                app.retry_transfer(id);
+            }
+            AppEventType::CancelTransfer(id) => {
+                app.cancel_transfer(id);
+            }
+            AppEventType::LoadDownloadTarget => {
+                app.load_download_target();
+            }
+            AppEventType::CompleteLoadDownloadTarget(result) => {
+                app.complete_load_download_target(result);
+            }
+            AppEventType::LoadDeleteTarget => {
+                app.load_delete_target();
+            }
+            AppEventType::CompleteLoadDeleteTarget(result) => {
+                app.complete_load_delete_target(result);
+            }
+            AppEventType::DeleteObjects(target) => {
+                app.delete_objects(target);
+            }
+            AppEventType::CompleteDeleteObjects(result) => {
+                app.complete_delete_objects(result);
+            }
+            AppEventType::LoadStorageClassTarget => {
+                app.load_storage_class_target();
+            }
+            AppEventType::CompleteLoadStorageClassTarget(result) => {
+                app.complete_load_storage_class_target(result);
+            }
+            AppEventType::ChangeStorageClass(target, storage_class) => {
+                app.change_storage_class(target, storage_class);
+            }
+            AppEventType::CompleteChangeStorageClass(result) => {
+                app.complete_change_storage_class(result);
+            }
+            AppEventType::LoadDeletedObjects => {
+                app.load_deleted_objects();
+            }
+            AppEventType::CompleteLoadDeletedObjects(result) => {
+                app.complete_load_deleted_objects(result);
+            }
+            AppEventType::UndeleteObject(object_key) => {
+                app.undelete_object(object_key);
+            }
+            AppEventType::CompleteUndeleteObject(result) => {
+                app.complete_undelete_object(result);
+            }
+            AppEventType::LoadUndeleteTarget => {
+                app.load_undelete_target();
+            }
+            AppEventType::CompleteLoadUndeleteTarget(result) => {
+                app.complete_load_undelete_target(result);
+            }
+            AppEventType::UndeleteObjects(target) => {
+                app.undelete_objects(target);
+            }
+            AppEventType::CompleteUndeleteObjects(result) => {
+                app.complete_undelete_objects(result);
+            }
+            AppEventType::LoadObjectsAt(object_key, at) => {
+                app.load_objects_at(object_key, at);
+            }
+            AppEventType::CompleteLoadObjectsAt(result) => {
+                app.complete_load_objects_at(result);
+            }
+            AppEventType::CopyObjects(target) => {
+                app.copy_objects(target);
+            }
+            AppEventType::CompleteCopyObjects(result) => {
+                app.complete_copy_objects(result);
+            }
+            AppEventType::LoadBucketProperty(bucket, property_type) => {
+                app.load_bucket_property(bucket, property_type);
+            }
+            AppEventType::CompleteLoadBucketProperty(bucket, property_type, result) => {
+                app.complete_load_bucket_property(bucket, property_type, result);
+            }
+            AppEventType::SaveBucketPropertyJson(file_name, json) => {
+                app.save_bucket_property_json(file_name, json);
+            }
             AppEventType::BucketListMoveDown => {
                 app.bucket_list_move_down();
             }
             AppEventType::BucketListRefresh => {
                 app.bucket_list_refresh();
             }
+            AppEventType::BucketListOpenProperties => {
+                app.bucket_list_open_properties();
+            }
             AppEventType::ObjectListMoveDown => {
                 app.object_list_move_down();
             }
@@ -128,6 +263,30 @@ pub async fn run<B: Backend>(
             AppEventType::ObjectListRefresh => {
                 app.object_list_refresh();
             }
+            AppEventType::ObjectListUploadObject(input) => {
+                app.object_list_upload_object(input);
+            }
+            AppEventType::ObjectListDownloadObject => {
+                app.object_list_download_object();
+            }
+            AppEventType::ObjectListOpenDeleteDialog => {
+                app.object_list_open_delete_dialog();
+            }
+            AppEventType::ObjectListOpenStorageClassDialog => {
+                app.object_list_open_storage_class_dialog();
+            }
+            AppEventType::ObjectListShowDeletedObjects => {
+                app.object_list_show_deleted_objects();
+            }
+            AppEventType::ObjectListOpenUndeleteDialog => {
+                app.object_list_open_undelete_dialog();
+            }
+            AppEventType::ObjectListExitTimeTravel => {
+                app.object_list_exit_time_travel();
+            }
+            AppEventType::ObjectListOpenCopyDialog(mode) => {
+                app.object_list_open_copy_dialog(mode);
+            }
             AppEventType::BackToBucketList => {
                 app.back_to_bucket_list();
             }
@@ -167,9 +326,15 @@ pub async fn run<B: Backend>(
             AppEventType::OpenHelp => {
                 app.open_help();
             }
+            AppEventType::OpenTransfers => {
+                app.open_transfers();
+            }
             AppEventType::CopyToClipboard(name, value) => {
                 app.copy_to_clipboard(name, value);
             }
+            AppEventType::PresignObject(object_key, version_id, expires_in) => {
+                app.presign_object(object_key, version_id, expires_in);
+            }
             AppEventType::NotifyInfo(msg) => {
                 app.info_notification(msg);
             }
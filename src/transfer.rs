@@ -0,0 +1,460 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    path::{Component, Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use tokio::task::{self, JoinError, JoinSet};
+
+use crate::{
+    client::Client,
+    error::{AppError, Result},
+    file::LocalFile,
+    object::{ObjectKey, ObjectSummary},
+};
+
+const DOWNLOAD_DIR_CONCURRENCY: usize = 4;
+
+#[derive(Debug, Clone)]
+pub enum TransferRequest {
+    Download {
+        object_key: ObjectKey,
+        version_id: Option<String>,
+        size_byte: usize,
+        path: PathBuf,
+    },
+    DownloadDir {
+        object_key: ObjectKey,
+        path: PathBuf,
+        objects: Arc<Vec<ObjectSummary>>,
+    },
+    Upload {
+        object_key: ObjectKey, // destination directory
+        path: PathBuf,
+        files: Arc<Vec<LocalFile>>, // shared to keep page updates cheap
+    },
+}
+
+impl TransferRequest {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            TransferRequest::Download { .. } | TransferRequest::DownloadDir { .. } => "Download",
+            TransferRequest::Upload { .. } => "Upload",
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            TransferRequest::Download { object_key, .. } => format!(
+                "s3://{}/{}",
+                object_key.bucket_name,
+                object_key.joined_object_path(true)
+            ),
+            TransferRequest::DownloadDir { object_key, .. } => format!(
+                "s3://{}/{}",
+                object_key.bucket_name,
+                object_key.joined_object_path(false)
+            ),
+            TransferRequest::Upload { path, .. } => path.to_string_lossy().into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransferStatus {
+    Queued,
+    Running,
+    Finished,
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct TransferItem {
+    pub id: usize,
+    pub request: TransferRequest,
+    pub status: TransferStatus,
+    pub total_byte: usize,
+    pub done_byte: usize,
+    pub started_at: Option<Instant>,
+    pub finished_at: Option<Instant>,
+}
+
+impl TransferItem {
+    fn new(id: usize, request: TransferRequest, total_byte: usize) -> Self {
+        Self {
+            id,
+            request,
+            status: TransferStatus::Queued,
+            total_byte,
+            done_byte: 0,
+            started_at: None,
+            finished_at: None,
+        }
+    }
+
+    // bytes per second, averaged since the transfer started
+    pub fn throughput(&self, now: Instant) -> Option<f64> {
+        let started_at = self.started_at?;
+        let end = self.finished_at.unwrap_or(now);
+        let elapsed = end.duration_since(started_at).as_secs_f64();
+        if elapsed <= 0.0 {
+            return None;
+        }
+        Some(self.done_byte as f64 / elapsed)
+    }
+
+    pub fn eta(&self, now: Instant) -> Option<Duration> {
+        if self.status != TransferStatus::Running {
+            return None;
+        }
+        let throughput = self.throughput(now)?;
+        if throughput <= 0.0 {
+            return None;
+        }
+        let remaining = self.total_byte.saturating_sub(self.done_byte);
+        Some(Duration::from_secs_f64(remaining as f64 / throughput))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Transfers {
+    items: Vec<TransferItem>,
+    next_id: usize,
+}
+
+impl Transfers {
+    pub fn items(&self) -> &[TransferItem] {
+        &self.items
+    }
+
+    pub fn get(&self, id: usize) -> Option<&TransferItem> {
+        self.items.iter().find(|item| item.id == id)
+    }
+
+    pub fn enqueue(&mut self, request: TransferRequest, total_byte: usize) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.items.push(TransferItem::new(id, request, total_byte));
+        id
+    }
+
+    pub fn running_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|item| item.status == TransferStatus::Running)
+            .count()
+    }
+
+    pub fn next_queued_id(&self) -> Option<usize> {
+        self.items
+            .iter()
+            .find(|item| item.status == TransferStatus::Queued)
+            .map(|item| item.id)
+    }
+
+    pub fn start(&mut self, id: usize, now: Instant) {
+        if let Some(item) = self.get_mut(id) {
+            item.status = TransferStatus::Running;
+            item.done_byte = 0;
+            item.started_at = Some(now);
+            item.finished_at = None;
+        }
+    }
+
+    pub fn update_progress(&mut self, id: usize, done_byte: usize) {
+        if let Some(item) = self.get_mut(id) {
+            if item.status == TransferStatus::Running {
+                item.done_byte = done_byte;
+            }
+        }
+    }
+
+    pub fn finish(&mut self, id: usize, now: Instant) {
+        if let Some(item) = self.get_mut(id) {
+            item.status = TransferStatus::Finished;
+            item.done_byte = item.total_byte;
+            item.finished_at = Some(now);
+        }
+    }
+
+    pub fn fail(&mut self, id: usize, msg: String, now: Instant) {
+        if let Some(item) = self.get_mut(id) {
+            item.status = TransferStatus::Failed(msg);
+            item.finished_at = Some(now);
+        }
+    }
+
+    pub fn retry(&mut self, id: usize) -> bool {
+        match self.get_mut(id) {
+            Some(item) if matches!(item.status, TransferStatus::Failed(_)) => {
+                item.status = TransferStatus::Queued;
+                item.done_byte = 0;
+                item.started_at = None;
+                item.finished_at = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn get_mut(&mut self, id: usize) -> Option<&mut TransferItem> {
+        self.items.iter_mut().find(|item| item.id == id)
+    }
+}
+
+pub async fn run_transfer<F>(client: Arc<Client>, request: TransferRequest, f: F) -> Result<()>
+where
+    F: Fn(usize) + Send + Sync + 'static,
+{
+    match request {
+        TransferRequest::Download {
+            object_key,
+            version_id,
+            size_byte,
+            path,
+        } => {
+            let bucket = &object_key.bucket_name;
+            let key = object_key.joined_object_path(true);
+            client
+                .download_object_to_file(bucket, &key, version_id, size_byte, &path, f)
+                .await
+        }
+        TransferRequest::DownloadDir {
+            object_key,
+            path,
+            objects,
+        } => download_dir(client, object_key, path, objects, f).await,
+        TransferRequest::Upload {
+            object_key, files, ..
+        } => {
+            let bucket = &object_key.bucket_name;
+            let prefix = object_key.joined_object_path(false);
+            let mut done = 0;
+            for file in files.iter() {
+                let key = format!("{}{}", prefix, file.key);
+                client
+                    .upload_object(bucket, &key, &file.path, file.size_byte, |n| f(done + n))
+                    .await?;
+                done += file.size_byte;
+                f(done);
+            }
+            Ok(())
+        }
+    }
+}
+
+async fn download_dir<F>(
+    client: Arc<Client>,
+    object_key: ObjectKey,
+    path: PathBuf,
+    objects: Arc<Vec<ObjectSummary>>,
+    f: F,
+) -> Result<()>
+where
+    F: Fn(usize) + Send + Sync + 'static,
+{
+    let prefix = object_key.joined_object_path(false);
+    let bucket = object_key.bucket_name;
+    let total = objects.len();
+
+    let f = Arc::new(f);
+    let done = Arc::new(AtomicUsize::new(0));
+    let mut failures = Vec::new();
+    let mut task_keys = HashMap::new();
+
+    // dropping the set aborts the running downloads, so canceling the transfer cancels them all
+    let mut set = JoinSet::new();
+    for obj in objects.iter() {
+        if set.len() >= DOWNLOAD_DIR_CONCURRENCY {
+            if let Some(result) = set.join_next().await {
+                failures.extend(download_failure(result, &task_keys));
+            }
+        }
+
+        let file_path = match relative_key_path(&obj.key, &prefix) {
+            Some(relative) => path.join(relative),
+            None => {
+                let e = AppError::msg("Invalid object key for a local path");
+                failures.push(DownloadFailure::new(obj.key.clone(), e));
+                continue;
+            }
+        };
+
+        let client = client.clone();
+        let bucket = bucket.clone();
+        let key = obj.key.clone();
+        let size_byte = obj.size_byte;
+        let f = f.clone();
+        let done = done.clone();
+        let handle = set.spawn(async move {
+            // each download reports its own written bytes, so add only the difference
+            let written = AtomicUsize::new(0);
+            let progress = |n: usize| {
+                let delta = n.saturating_sub(written.swap(n, Ordering::Relaxed));
+                f(done.fetch_add(delta, Ordering::Relaxed) + delta);
+            };
+            client
+                .download_object_to_file(&bucket, &key, None, size_byte, &file_path, &progress)
+                .await
+                .map(|_| progress(size_byte))
+                .map_err(|e| DownloadFailure::new(key, e))
+        });
+        task_keys.insert(handle.id(), obj.key.clone());
+    }
+    while let Some(result) = set.join_next().await {
+        failures.extend(download_failure(result, &task_keys));
+    }
+
+    if failures.is_empty() {
+        return Ok(());
+    }
+    let msg = format!(
+        "Failed to download {} out of {} objects",
+        failures.len(),
+        total
+    );
+    Err(AppError::new(msg, DownloadFailures(failures)))
+}
+
+// returns None if the key would be written outside of the destination directory
+fn relative_key_path(key: &str, prefix: &str) -> Option<PathBuf> {
+    let relative = Path::new(key.strip_prefix(prefix)?);
+    let valid = relative.components().next().is_some()
+        && relative
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)));
+    valid.then(|| relative.to_path_buf())
+}
+
+#[derive(Debug)]
+struct DownloadFailure {
+    key: String,
+    error: AppError,
+}
+
+impl DownloadFailure {
+    fn new(key: String, error: AppError) -> Self {
+        Self { key, error }
+    }
+}
+
+// a panicked download is recorded as a failure of its object, so that the other downloads can finish
+fn download_failure(
+    result: std::result::Result<std::result::Result<(), DownloadFailure>, JoinError>,
+    task_keys: &HashMap<task::Id, String>,
+) -> Option<DownloadFailure> {
+    match result {
+        Ok(result) => result.err(),
+        Err(e) => {
+            let key = task_keys.get(&e.id()).cloned().unwrap_or_default();
+            Some(DownloadFailure::new(
+                key,
+                AppError::new("Failed to download object", e),
+            ))
+        }
+    }
+}
+
+#[derive(Debug)]
+struct DownloadFailures(Vec<DownloadFailure>);
+
+impl fmt::Display for DownloadFailures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for failure in &self.0 {
+            writeln!(f, "{}: {}", failure.key, failure.error.msg)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for DownloadFailures {}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_transfer_lifecycle() {
+        let mut transfers = Transfers::default();
+        let id1 = transfers.enqueue(download_request("a.txt"), 100);
+        let id2 = transfers.enqueue(download_request("b.txt"), 200);
+
+        assert_eq!(transfers.next_queued_id(), Some(id1));
+
+        let now = Instant::now();
+        transfers.start(id1, now);
+        assert_eq!(transfers.running_count(), 1);
+        assert_eq!(transfers.next_queued_id(), Some(id2));
+
+        transfers.fail(id1, "error".into(), now);
+        assert_eq!(transfers.running_count(), 0);
+        assert_eq!(
+            transfers.get(id1).unwrap().status,
+            TransferStatus::Failed("error".into())
+        );
+
+        assert!(transfers.retry(id1));
+        assert!(!transfers.retry(id2));
+        assert_eq!(transfers.next_queued_id(), Some(id1));
+
+        transfers.start(id1, now);
+        transfers.finish(id1, now);
+        assert_eq!(transfers.get(id1).unwrap().done_byte, 100);
+        assert!(!transfers.retry(id1));
+    }
+
+    #[test]
+    fn test_transfer_throughput_and_eta() {
+        let mut transfers = Transfers::default();
+        let id = transfers.enqueue(download_request("a.txt"), 1000);
+
+        let start = Instant::now();
+        transfers.start(id, start);
+        transfers.update_progress(id, 250);
+
+        let item = transfers.get(id).unwrap();
+        let now = start + Duration::from_secs(5);
+        assert_eq!(item.throughput(now), Some(50.0));
+        assert_eq!(item.eta(now), Some(Duration::from_secs(15)));
+
+        transfers.finish(id, start + Duration::from_secs(10));
+        let item = transfers.get(id).unwrap();
+        let now = start + Duration::from_secs(100);
+        assert_eq!(item.throughput(now), Some(100.0));
+        assert_eq!(item.eta(now), None);
+    }
+
+    #[rstest]
+    #[case("a/b/c.txt", "a/", Some("b/c.txt"))]
+    #[case("a/c.txt", "", Some("a/c.txt"))]
+    #[case("a/", "a/", None)]
+    #[case("a/../c.txt", "a/", None)]
+    #[case("a//c.txt", "a/", None)]
+    #[case("b/c.txt", "a/", None)]
+    fn test_relative_key_path(
+        #[case] key: &str,
+        #[case] prefix: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(relative_key_path(key, prefix), expected.map(PathBuf::from));
+    }
+
+    fn download_request(name: &str) -> TransferRequest {
+        TransferRequest::Download {
+            object_key: ObjectKey {
+                bucket_name: "bucket".into(),
+                object_path: vec![name.into()],
+            },
+            version_id: None,
+            size_byte: 100,
+            path: PathBuf::from(name),
+        }
+    }
+}
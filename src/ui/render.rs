@@ -81,7 +81,12 @@ fn render_footer(f: &mut Frame, area: Rect, app: &App) {
 
 fn render_loading_dialog(f: &mut Frame, app: &App) {
     if app.loading() {
-        let loading = build_loading_dialog("Loading...", app.theme());
+        let msg = if app.cancelable() {
+            "Loading... (Esc: Cancel)"
+        } else {
+            "Loading..."
+        };
+        let loading = build_loading_dialog(msg, app.theme());
         let area = calc_centered_dialog_rect(f.area(), 30, 5);
         let dialog = Dialog::new(Box::new(loading), app.theme().bg);
         f.render_widget_ref(dialog, area);
@@ -92,7 +97,12 @@ fn build_header(app: &App) -> Header {
     let mut target_pages: Vec<&Page> = app
         .page_stack
         .iter()
-        .filter(|page| matches!(page, Page::BucketList(_) | Page::ObjectList(_)))
+        .filter(|page| {
+            matches!(
+                page,
+                Page::BucketList(_) | Page::BucketDetail(_) | Page::ObjectList(_)
+            )
+        })
         .collect();
     target_pages.pop(); // Remove the last item (current page)
 
@@ -114,7 +124,7 @@ fn build_header(app: &App) -> Header {
     Header::new(breadcrumb).theme(app.theme())
 }
 
-fn build_short_help(app: &App, width: u16) -> Paragraph {
+fn build_short_help(app: &App, width: u16) -> Paragraph<'_> {
     let helps = app.page_stack.current_page().short_helps();
     let pad = Padding::horizontal(2);
     let max_width = (width - pad.left - pad.right) as usize;
@@ -1,3 +1,9 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone};
+
+pub const DATETIME_INPUT_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
 pub fn prune_strings_to_fit_width(
     words_with_priority: &[(String, usize)],
     max_width: usize,
@@ -72,11 +78,45 @@ pub fn digits(n: usize) -> usize {
 pub fn extension_from_file_name(filename: &str) -> String {
     filename
         .split('.')
-        .last()
+        .next_back()
         .map(|s| s.to_string())
         .unwrap_or_default()
 }
 
+// parses "key=value" into a trimmed pair, the value may be empty but the key may not
+pub fn parse_key_value(s: &str) -> Option<(String, String)> {
+    let (key, value) = s.split_once('=')?;
+    let key = key.trim();
+    if key.is_empty() {
+        return None;
+    }
+    Some((key.into(), value.trim().into()))
+}
+
+// parses a duration like "30s", "15m", "1h" or "7d"
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    let s = s.trim();
+    let unit = s.chars().last()?;
+    let n: u64 = s[..s.len() - unit.len_utf8()].parse().ok()?;
+    let secs = match unit {
+        's' => n,
+        'm' => n.checked_mul(60)?,
+        'h' => n.checked_mul(60 * 60)?,
+        'd' => n.checked_mul(24 * 60 * 60)?,
+        _ => return None,
+    };
+    Some(Duration::from_secs(secs))
+}
+
+// accepts a date and time, or only a date meaning the start of the day, in local time
+pub fn parse_datetime_input(input: &str) -> Option<DateTime<Local>> {
+    let input = input.trim();
+    let datetime = NaiveDateTime::parse_from_str(input, DATETIME_INPUT_FORMAT)
+        .or_else(|_| NaiveDate::parse_from_str(input, "%Y-%m-%d").map(|d| d.into()))
+        .ok()?;
+    Local.from_local_datetime(&datetime).earliest()
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -156,4 +196,30 @@ mod tests {
         assert_eq!(extension_from_file_name("a.txt"), "txt");
         assert_eq!(extension_from_file_name("a.gif.txt"), "txt");
     }
+
+    #[rstest]
+    #[case("30s", Some(30))]
+    #[case("15m", Some(900))]
+    #[case(" 1h ", Some(3600))]
+    #[case("7d", Some(604800))]
+    #[case("0m", Some(0))]
+    #[case("10", None)]
+    #[case("h", None)]
+    #[case("1w", None)]
+    #[case("-1h", None)]
+    #[case("", None)]
+    fn test_parse_duration(#[case] s: &str, #[case] expected: Option<u64>) {
+        assert_eq!(parse_duration(s), expected.map(Duration::from_secs));
+    }
+
+    #[rstest]
+    #[case("2024-01-02 13:01:02", Some("2024-01-02 13:01:02"))]
+    #[case(" 2024-01-02 ", Some("2024-01-02 00:00:00"))]
+    #[case("2024-01-02 13:01", None)]
+    #[case("2024-13-01", None)]
+    #[case("", None)]
+    fn test_parse_datetime_input(#[case] s: &str, #[case] expected: Option<&str>) {
+        let actual = parse_datetime_input(s).map(|d| d.format(DATETIME_INPUT_FORMAT).to_string());
+        assert_eq!(actual.as_deref(), expected);
+    }
 }
@@ -1,23 +1,33 @@
 mod bar;
+mod confirm_dialog;
 mod copy_detail_dialog;
+mod destination_dialog;
 mod dialog;
 mod divider;
 mod header;
 mod image_preview;
 mod input_dialog;
+mod metadata_dialog;
+mod restore_dialog;
 mod scroll;
 mod scroll_lines;
 mod scroll_list;
 mod sort_list_dialog;
+mod storage_class_dialog;
 mod text_preview;
+mod undelete_dialog;
 
 pub use bar::Bar;
+pub use confirm_dialog::ConfirmDialog;
 pub use copy_detail_dialog::{CopyDetailDialog, CopyDetailDialogState};
+pub use destination_dialog::{DestinationDialog, DestinationDialogState};
 pub use dialog::Dialog;
 pub use divider::Divider;
 pub use header::Header;
 pub use image_preview::{ImagePicker, ImagePreview, ImagePreviewState};
 pub use input_dialog::{InputDialog, InputDialogState};
+pub use metadata_dialog::{MetadataDialog, MetadataDialogState};
+pub use restore_dialog::{RestoreDialog, RestoreDialogState};
 pub use scroll::ScrollBar;
 pub use scroll_lines::{ScrollLines, ScrollLinesOptions, ScrollLinesState};
 pub use scroll_list::{ScrollList, ScrollListState};
@@ -25,4 +35,6 @@ pub use sort_list_dialog::{
     BucketListSortDialog, BucketListSortDialogState, BucketListSortType, ObjectListSortDialog,
     ObjectListSortDialogState, ObjectListSortType,
 };
-pub use text_preview::{TextPreview, TextPreviewState};
+pub use storage_class_dialog::{StorageClassDialog, StorageClassDialogState};
+pub use text_preview::{build_text_lines, TextPreview, TextPreviewState};
+pub use undelete_dialog::{UndeleteDialog, UndeleteDialogState};
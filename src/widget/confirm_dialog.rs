@@ -0,0 +1,131 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Stylize},
+    text::Line,
+    widgets::{block::Title, Block, BorderType, Padding, Paragraph, Widget, WidgetRef},
+};
+
+use crate::{color::ColorTheme, ui::common::calc_centered_dialog_rect, widget::Dialog};
+
+#[derive(Debug, Default)]
+struct ConfirmDialogColor {
+    bg: Color,
+    block: Color,
+    text: Color,
+    warn: Color,
+}
+
+impl ConfirmDialogColor {
+    fn new(theme: &ColorTheme) -> ConfirmDialogColor {
+        ConfirmDialogColor {
+            bg: theme.bg,
+            block: theme.fg,
+            text: theme.fg,
+            warn: theme.status_warn,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ConfirmDialog {
+    title: &'static str,
+    lines: Vec<Line<'static>>,
+    max_width: Option<u16>,
+    color: ConfirmDialogColor,
+}
+
+impl ConfirmDialog {
+    pub fn new(lines: Vec<Line<'static>>) -> Self {
+        Self {
+            lines,
+            ..Default::default()
+        }
+    }
+
+    pub fn title(mut self, title: &'static str) -> Self {
+        self.title = title;
+        self
+    }
+
+    pub fn max_width(mut self, max_width: u16) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    pub fn theme(mut self, theme: &ColorTheme) -> Self {
+        self.color = ConfirmDialogColor::new(theme);
+        self
+    }
+}
+
+impl Widget for ConfirmDialog {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut dialog_width = area.width - 4;
+        if let Some(max_width) = self.max_width {
+            dialog_width = dialog_width.min(max_width);
+        }
+        let mut lines = self.lines;
+        lines.push(Line::from(""));
+        lines.push(Line::from(
+            "Enter: Confirm, Esc: Cancel"
+                .add_modifier(Modifier::BOLD)
+                .fg(self.color.warn),
+        ));
+        let dialog_height = lines.len() as u16 + 2 /* border */;
+        let dialog_height = dialog_height.min(area.height);
+        let dialog_area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        let title = Title::from(self.title);
+        let content = Paragraph::new(lines).fg(self.color.text).block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .title(title)
+                .bg(self.color.bg)
+                .fg(self.color.block)
+                .padding(Padding::horizontal(1)),
+        );
+        let dialog = Dialog::new(Box::new(content), self.color.bg);
+        dialog.render_ref(dialog_area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::set_cells;
+
+    use super::*;
+
+    #[test]
+    fn test_render_confirm_dialog() {
+        let theme = ColorTheme::default();
+        let lines = vec![Line::from("Delete 3 objects?"), Line::from("  12 KiB")];
+        let confirm_dialog = ConfirmDialog::new(lines)
+            .title("Delete")
+            .max_width(34)
+            .theme(&theme);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 10));
+        confirm_dialog.render(buf.area, &mut buf);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                        ",
+            "                                        ",
+            "   ╭Delete──────────────────────────╮   ",
+            "   │ Delete 3 objects?              │   ",
+            "   │   12 KiB                       │   ",
+            "   │                                │   ",
+            "   │ Enter: Confirm, Esc: Cancel    │   ",
+            "   ╰────────────────────────────────╯   ",
+            "                                        ",
+            "                                        ",
+        ]);
+        set_cells! { expected =>
+            // help line
+            (5..32, [6]) => fg: Color::Yellow, modifier: Modifier::BOLD,
+        }
+
+        assert_eq!(buf, expected);
+    }
+}
@@ -107,16 +107,19 @@ enum ObjectDetailItemType {
     Arn,
     ObjectUrl,
     Etag,
+    PresignedUrl,
 }
 
 impl ObjectDetailItemType {
     fn name_and_value(&self, file_detail: &FileDetail) -> (String, String) {
         let (name, value) = match self {
-            Self::Key => ("Key", &file_detail.key),
-            Self::S3Uri => ("S3 URI", &file_detail.s3_uri),
-            Self::Arn => ("ARN", &file_detail.arn),
-            Self::ObjectUrl => ("Object URL", &file_detail.object_url),
-            Self::Etag => ("ETag", &file_detail.e_tag),
+            Self::Key => ("Key", file_detail.key.as_str()),
+            Self::S3Uri => ("S3 URI", file_detail.s3_uri.as_str()),
+            Self::Arn => ("ARN", file_detail.arn.as_str()),
+            Self::ObjectUrl => ("Object URL", file_detail.object_url.as_str()),
+            Self::Etag => ("ETag", file_detail.e_tag.as_str()),
+            // generated after the expiry is entered
+            Self::PresignedUrl => ("Presigned URL", "(Enter: Set expiry)"),
         };
         (name.into(), value.into())
     }
@@ -125,7 +128,7 @@ impl ObjectDetailItemType {
 #[derive(Debug)]
 pub enum CopyDetailDialogState {
     BucketList(BucketListItemType, BucketItem),
-    ObjectDetail(ObjectDetailItemType, FileDetail),
+    ObjectDetail(ObjectDetailItemType, Box<FileDetail>),
     ObjectListFile(ObjectListFileItemType, ObjectItem),
     ObjectListDir(ObjectListDirItemType, ObjectItem),
 }
@@ -144,7 +147,7 @@ impl CopyDetailDialogState {
     }
 
     pub fn object_detail(file_detail: FileDetail) -> Self {
-        Self::ObjectDetail(ObjectDetailItemType::default(), file_detail)
+        Self::ObjectDetail(ObjectDetailItemType::default(), Box::new(file_detail))
     }
 }
 
@@ -176,6 +179,13 @@ impl CopyDetailDialogState {
         }
     }
 
+    pub fn is_presigned_url_selected(&self) -> bool {
+        matches!(
+            self,
+            Self::ObjectDetail(ObjectDetailItemType::PresignedUrl, _)
+        )
+    }
+
     pub fn selected_name_and_value(&self) -> (String, String) {
         match self {
             Self::BucketList(selected, bucket_item) => selected.name_and_value(bucket_item),
@@ -319,7 +329,6 @@ mod tests {
             "                                        ",
             "                                        ",
             "                                        ",
-            "                                        ",
             "  ╭Copy──────────────────────────────╮  ",
             "  │ Key:                             │  ",
             "  │   file.txt                       │  ",
@@ -331,25 +340,28 @@ mod tests {
             "  │   https://bucket-1.s3.ap-northea │  ",
             "  │ ETag:                            │  ",
             "  │   bef684de-a260-48a4-8178-8a535e │  ",
+            "  │ Presigned URL:                   │  ",
+            "  │   (Enter: Set expiry)            │  ",
             "  ╰──────────────────────────────────╯  ",
             "                                        ",
             "                                        ",
             "                                        ",
-            "                                        ",
         ]);
         set_cells! { expected =>
             // "Key" is bold
-            (4..8, [5]) => modifier: Modifier::BOLD,
+            (4..8, [4]) => modifier: Modifier::BOLD,
             // "S3 URI" is bold
-            (4..11, [7]) => modifier: Modifier::BOLD,
+            (4..11, [6]) => modifier: Modifier::BOLD,
             // "ARN" is bold
-            (4..8, [9]) => modifier: Modifier::BOLD,
+            (4..8, [8]) => modifier: Modifier::BOLD,
             // "Object URL" is bold
-            (4..15, [11]) => modifier: Modifier::BOLD,
+            (4..15, [10]) => modifier: Modifier::BOLD,
             // "ETag" is bold
-            (4..9, [13]) => modifier: Modifier::BOLD,
+            (4..9, [12]) => modifier: Modifier::BOLD,
+            // "Presigned URL" is bold
+            (4..18, [14]) => modifier: Modifier::BOLD,
             // selected item
-            (4..36, [5, 6]) => fg: Color::Cyan,
+            (4..36, [4, 5]) => fg: Color::Cyan,
         }
 
         assert_eq!(buf, expected);
@@ -367,6 +379,7 @@ mod tests {
             s3_uri: "s3://bucket-1/file.txt".to_string(),
             arn: "arn:aws:s3:::bucket-1/file.txt".to_string(),
             object_url: "https://bucket-1.s3.ap-northeast-1.amazonaws.com/file.txt".to_string(),
+            ..Default::default()
         }
     }
 
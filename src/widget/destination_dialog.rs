@@ -0,0 +1,281 @@
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::KeyEvent,
+    layout::Rect,
+    style::{Color, Modifier, Stylize},
+    text::Line,
+    widgets::{block::Title, Block, BorderType, Padding, Paragraph, StatefulWidget, WidgetRef},
+};
+use tui_input::{backend::crossterm::EventHandler, Input};
+
+use crate::{color::ColorTheme, ui::common::calc_centered_dialog_rect, widget::Dialog};
+
+const MAX_BUCKET_LIST_HEIGHT: usize = 8;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Focus {
+    #[default]
+    Bucket,
+    Prefix,
+}
+
+#[derive(Debug, Default)]
+pub struct DestinationDialogState {
+    buckets: Vec<String>,
+    selected: usize,
+    offset: usize,
+    input: Input,
+    focus: Focus,
+    cursor: Option<(u16, u16)>,
+}
+
+impl DestinationDialogState {
+    pub fn new(buckets: Vec<String>, current_bucket: &str, current_prefix: &str) -> Self {
+        let selected = buckets
+            .iter()
+            .position(|b| b == current_bucket)
+            .unwrap_or_default();
+        let offset = (selected + 1).saturating_sub(MAX_BUCKET_LIST_HEIGHT);
+        Self {
+            buckets,
+            selected,
+            offset,
+            input: Input::new(current_prefix.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.buckets.is_empty() {
+            return;
+        }
+        self.selected = (self.selected + 1) % self.buckets.len();
+        self.adjust_offset();
+    }
+
+    pub fn select_prev(&mut self) {
+        if self.buckets.is_empty() {
+            return;
+        }
+        self.selected = (self.selected + self.buckets.len() - 1) % self.buckets.len();
+        self.adjust_offset();
+    }
+
+    fn adjust_offset(&mut self) {
+        if self.selected < self.offset {
+            self.offset = self.selected;
+        } else if self.selected >= self.offset + MAX_BUCKET_LIST_HEIGHT {
+            self.offset = self.selected + 1 - MAX_BUCKET_LIST_HEIGHT;
+        }
+    }
+
+    pub fn toggle_focus(&mut self) {
+        self.focus = match self.focus {
+            Focus::Bucket => Focus::Prefix,
+            Focus::Prefix => Focus::Bucket,
+        };
+    }
+
+    pub fn is_prefix_focused(&self) -> bool {
+        self.focus == Focus::Prefix
+    }
+
+    pub fn selected_bucket(&self) -> Option<&str> {
+        self.buckets.get(self.selected).map(String::as_str)
+    }
+
+    pub fn prefix(&self) -> String {
+        let prefix = self.input.value().trim().trim_start_matches('/');
+        if prefix.is_empty() || prefix.ends_with('/') {
+            prefix.into()
+        } else {
+            format!("{}/", prefix)
+        }
+    }
+
+    pub fn cursor(&self) -> Option<(u16, u16)> {
+        self.cursor
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) {
+        let event = &ratatui::crossterm::event::Event::Key(key);
+        self.input.handle_event(event);
+    }
+}
+
+#[derive(Debug, Default)]
+struct DestinationDialogColor {
+    bg: Color,
+    block: Color,
+    text: Color,
+    selected: Color,
+}
+
+impl DestinationDialogColor {
+    fn new(theme: &ColorTheme) -> DestinationDialogColor {
+        DestinationDialogColor {
+            bg: theme.bg,
+            block: theme.fg,
+            text: theme.fg,
+            selected: theme.dialog_selected,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct DestinationDialog {
+    title: &'static str,
+    max_width: Option<u16>,
+    color: DestinationDialogColor,
+}
+
+impl DestinationDialog {
+    pub fn title(mut self, title: &'static str) -> Self {
+        self.title = title;
+        self
+    }
+
+    pub fn max_width(mut self, max_width: u16) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    pub fn theme(mut self, theme: &ColorTheme) -> Self {
+        self.color = DestinationDialogColor::new(theme);
+        self
+    }
+}
+
+impl StatefulWidget for DestinationDialog {
+    type State = DestinationDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let mut dialog_width = area.width - 4;
+        if let Some(max_width) = self.max_width {
+            dialog_width = dialog_width.min(max_width);
+        }
+        let list_height = state.buckets.len().min(MAX_BUCKET_LIST_HEIGHT);
+        let dialog_height = list_height as u16 + 3;
+        let dialog_height = dialog_height + 2 /* border */;
+        let dialog_area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        let bucket_focused = state.focus == Focus::Bucket;
+        let mut lines: Vec<Line> = vec![Line::from("Bucket:".add_modifier(Modifier::BOLD))];
+        for (i, bucket) in state
+            .buckets
+            .iter()
+            .enumerate()
+            .skip(state.offset)
+            .take(list_height)
+        {
+            let line = if i == state.selected {
+                let line = Line::from(format!("> {}", bucket));
+                if bucket_focused {
+                    line.fg(self.color.selected)
+                } else {
+                    line.fg(self.color.text)
+                }
+            } else {
+                Line::from(format!("  {}", bucket)).fg(self.color.text)
+            };
+            lines.push(line);
+        }
+
+        // show the last `input_max_width` characters of the input
+        let label = "Prefix: ";
+        let input_max_width = (dialog_width as usize).saturating_sub(4 + label.len());
+        let input_start_index = state.input.visual_cursor().saturating_sub(input_max_width);
+        let input_view: &str = &state.input.value()[input_start_index..];
+        lines.push(Line::from(""));
+        let label = if bucket_focused {
+            label.add_modifier(Modifier::BOLD)
+        } else {
+            label.add_modifier(Modifier::BOLD).fg(self.color.selected)
+        };
+        lines.push(Line::from(vec![label, input_view.fg(self.color.text)]));
+
+        let title = Title::from(self.title);
+        let dialog_content = Paragraph::new(lines).block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .title(title)
+                .bg(self.color.bg)
+                .fg(self.color.block)
+                .padding(Padding::horizontal(1)),
+        );
+        let dialog = Dialog::new(Box::new(dialog_content), self.color.bg);
+        dialog.render_ref(dialog_area, buf);
+
+        // update cursor position
+        state.cursor = if bucket_focused {
+            None
+        } else {
+            let cursor_x = dialog_area.x
+                + state.input.visual_cursor().min(input_max_width) as u16
+                + 2
+                + 8 /* label */;
+            let cursor_y = dialog_area.y + dialog_height - 2;
+            Some((cursor_x, cursor_y))
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::crossterm::event::KeyCode;
+
+    use crate::set_cells;
+
+    use super::*;
+
+    #[test]
+    fn test_render_destination_dialog() {
+        let theme = ColorTheme::default();
+        let buckets = vec!["bucket-1".to_string(), "bucket-2".to_string()];
+        let mut state = DestinationDialogState::new(buckets, "bucket-2", "dir/");
+        let dialog = DestinationDialog::default()
+            .title("Copy to")
+            .max_width(30)
+            .theme(&theme);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 10));
+        dialog.render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                        ",
+            "     ╭Copy to─────────────────────╮     ",
+            "     │ Bucket:                    │     ",
+            "     │   bucket-1                 │     ",
+            "     │ > bucket-2                 │     ",
+            "     │                            │     ",
+            "     │ Prefix: dir/               │     ",
+            "     ╰────────────────────────────╯     ",
+            "                                        ",
+            "                                        ",
+        ]);
+        set_cells! { expected =>
+            // "Bucket:" is bold
+            (7..14, [2]) => modifier: Modifier::BOLD,
+            // selected bucket
+            (7..17, [4]) => fg: Color::Cyan,
+            // "Prefix: " is bold
+            (7..15, [6]) => modifier: Modifier::BOLD,
+        }
+
+        assert_eq!(buf, expected);
+        assert_eq!(state.cursor(), None);
+        assert_eq!(state.selected_bucket(), Some("bucket-2"));
+    }
+
+    #[test]
+    fn test_destination_dialog_prefix() {
+        let mut state = DestinationDialogState::new(vec![], "bucket-1", "");
+        state.toggle_focus();
+        for c in "/a/b".chars() {
+            state.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+        }
+        assert_eq!(state.prefix(), "a/b/");
+        assert_eq!(state.selected_bucket(), None);
+    }
+}
@@ -16,6 +16,13 @@ pub struct InputDialogState {
 }
 
 impl InputDialogState {
+    pub fn new(input: impl Into<String>) -> Self {
+        Self {
+            input: Input::new(input.into()),
+            ..Default::default()
+        }
+    }
+
     pub fn input(&self) -> &str {
         self.input.value()
     }
@@ -0,0 +1,385 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Stylize},
+    text::Line,
+    widgets::{
+        block::Title, Block, BorderType, List, ListItem, Padding, StatefulWidget, WidgetRef,
+    },
+};
+
+use crate::{
+    color::ColorTheme,
+    object::ObjectMetadata,
+    ui::common::calc_centered_dialog_rect,
+    util::parse_key_value,
+    widget::{Dialog, InputDialogState},
+};
+
+const SYSTEM_METADATA_NAMES: [&str; 4] = [
+    "Content-Type",
+    "Cache-Control",
+    "Content-Encoding",
+    "Content-Disposition",
+];
+
+#[derive(Debug, Default)]
+pub struct MetadataDialogState {
+    original: ObjectMetadata,
+    metadata: ObjectMetadata,
+    selected: usize,
+    offset: usize,
+    input: Option<(InputTarget, InputDialogState)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InputTarget {
+    Edit(usize),
+    Add,
+}
+
+impl MetadataDialogState {
+    pub fn new(metadata: ObjectMetadata) -> Self {
+        Self {
+            original: metadata.clone(),
+            metadata,
+            ..Default::default()
+        }
+    }
+
+    pub fn metadata(&self) -> &ObjectMetadata {
+        &self.metadata
+    }
+
+    pub fn is_changed(&self) -> bool {
+        self.metadata != self.original
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.items_len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+        }
+    }
+
+    pub fn input_mut(&mut self) -> Option<&mut InputDialogState> {
+        self.input.as_mut().map(|(_, state)| state)
+    }
+
+    pub fn input_title(&self) -> &'static str {
+        match self.input {
+            Some((InputTarget::Edit(i), _)) if i < SYSTEM_METADATA_NAMES.len() => "Value",
+            _ => "User metadata (key=value)",
+        }
+    }
+
+    pub fn open_edit_input(&mut self) {
+        let value = match self.user_metadata_index(self.selected) {
+            None => self.system_value(self.selected).to_string(),
+            Some(i) => {
+                let (key, value) = &self.metadata.user_metadata[i];
+                format!("{}={}", key, value)
+            }
+        };
+        let target = InputTarget::Edit(self.selected);
+        self.input = Some((target, InputDialogState::new(value)));
+    }
+
+    pub fn open_add_input(&mut self) {
+        self.input = Some((InputTarget::Add, InputDialogState::default()));
+    }
+
+    pub fn close_input(&mut self) {
+        self.input = None;
+    }
+
+    // returns a message to be shown as a warning if the input is invalid
+    pub fn apply_input(&mut self) -> Result<(), String> {
+        let Some((target, state)) = &self.input else {
+            return Ok(());
+        };
+        let input = state.input().trim().to_string();
+
+        let edit_index = match *target {
+            InputTarget::Edit(i) => match self.user_metadata_index(i) {
+                None => {
+                    *self.system_value_mut(i) = input;
+                    self.close_input();
+                    return Ok(());
+                }
+                Some(i) => Some(i),
+            },
+            InputTarget::Add => None,
+        };
+
+        let Some((key, value)) = parse_key_value(&input) else {
+            return Err(format!("Invalid metadata: {} (must be key=value)", input));
+        };
+        // S3 stores user metadata keys in lowercase
+        let key = key.to_lowercase();
+        let duplicated = self
+            .metadata
+            .user_metadata
+            .iter()
+            .enumerate()
+            .any(|(i, (k, _))| Some(i) != edit_index && *k == key);
+        if duplicated {
+            return Err(format!("Metadata key already exists: {}", key));
+        }
+
+        match edit_index {
+            Some(i) => self.metadata.user_metadata[i] = (key, value),
+            None => {
+                self.metadata.user_metadata.push((key, value));
+                self.selected = self.items_len() - 1;
+            }
+        }
+        self.close_input();
+        Ok(())
+    }
+
+    pub fn remove_selected(&mut self) {
+        if let Some(i) = self.user_metadata_index(self.selected) {
+            self.metadata.user_metadata.remove(i);
+            self.selected = self.selected.min(self.items_len() - 1);
+        }
+    }
+
+    fn items_len(&self) -> usize {
+        SYSTEM_METADATA_NAMES.len() + self.metadata.user_metadata.len()
+    }
+
+    fn user_metadata_index(&self, i: usize) -> Option<usize> {
+        i.checked_sub(SYSTEM_METADATA_NAMES.len())
+    }
+
+    fn system_value(&self, i: usize) -> &str {
+        match i {
+            0 => &self.metadata.content_type,
+            1 => &self.metadata.cache_control,
+            2 => &self.metadata.content_encoding,
+            _ => &self.metadata.content_disposition,
+        }
+    }
+
+    fn system_value_mut(&mut self, i: usize) -> &mut String {
+        match i {
+            0 => &mut self.metadata.content_type,
+            1 => &mut self.metadata.cache_control,
+            2 => &mut self.metadata.content_encoding,
+            _ => &mut self.metadata.content_disposition,
+        }
+    }
+
+    fn name_and_value_vec(&self) -> Vec<(String, String)> {
+        let system = SYSTEM_METADATA_NAMES
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.to_string(), self.system_value(i).to_string()));
+        let user = self
+            .metadata
+            .user_metadata
+            .iter()
+            .map(|(k, v)| (format!("x-amz-meta-{}", k), v.clone()));
+        system.chain(user).collect()
+    }
+}
+
+#[derive(Debug, Default)]
+struct MetadataDialogColor {
+    bg: Color,
+    block: Color,
+    text: Color,
+    selected: Color,
+}
+
+impl MetadataDialogColor {
+    fn new(theme: &ColorTheme) -> Self {
+        Self {
+            bg: theme.bg,
+            block: theme.fg,
+            text: theme.fg,
+            selected: theme.dialog_selected,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MetadataDialog {
+    color: MetadataDialogColor,
+}
+
+impl MetadataDialog {
+    pub fn theme(mut self, theme: &ColorTheme) -> Self {
+        self.color = MetadataDialogColor::new(theme);
+        self
+    }
+}
+
+impl StatefulWidget for MetadataDialog {
+    type State = MetadataDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let dialog_width = (area.width - 4).min(80);
+        let max_items = (area.height.saturating_sub(4 /* border + margin */) / 2) as usize;
+        let show_items = state.items_len().min(max_items);
+        let dialog_height = show_items * 2 + 2 /* border */;
+        let area = calc_centered_dialog_rect(area, dialog_width, dialog_height as u16);
+
+        // update state
+        if state.selected < state.offset {
+            state.offset = state.selected;
+        } else if state.selected >= state.offset + show_items {
+            state.offset = state.selected + 1 - show_items;
+        }
+
+        let list_items: Vec<ListItem> = state
+            .name_and_value_vec()
+            .into_iter()
+            .enumerate()
+            .skip(state.offset)
+            .take(show_items)
+            .map(|(i, (name, value))| self.build_list_item(i == state.selected, name, value))
+            .collect();
+
+        let title = Title::from("Edit metadata");
+        let list = List::new(list_items).block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .title(title)
+                .bg(self.color.bg)
+                .fg(self.color.block)
+                .padding(Padding::horizontal(1)),
+        );
+        let dialog = Dialog::new(Box::new(list), self.color.bg);
+        dialog.render_ref(area, buf);
+    }
+}
+
+impl MetadataDialog {
+    fn build_list_item<'a>(&self, selected: bool, name: String, value: String) -> ListItem<'a> {
+        let item = ListItem::new(vec![
+            Line::from(format!("{}:", name).add_modifier(Modifier::BOLD)),
+            Line::from(format!("  {}", value)),
+        ]);
+        if selected {
+            item.fg(self.color.selected)
+        } else {
+            item.fg(self.color.text)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::set_cells;
+
+    use super::*;
+
+    #[test]
+    fn test_render_metadata_dialog() {
+        let theme = ColorTheme::default();
+        let mut state = MetadataDialogState::new(metadata());
+        state.select_next();
+        let metadata_dialog = MetadataDialog::default().theme(&theme);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 40, 14));
+        metadata_dialog.render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                        ",
+            "  ╭Edit metadata─────────────────────╮  ",
+            "  │ Content-Type:                    │  ",
+            "  │   text/plain                     │  ",
+            "  │ Cache-Control:                   │  ",
+            "  │                                  │  ",
+            "  │ Content-Encoding:                │  ",
+            "  │                                  │  ",
+            "  │ Content-Disposition:             │  ",
+            "  │                                  │  ",
+            "  │ x-amz-meta-author:               │  ",
+            "  │   alice                          │  ",
+            "  ╰──────────────────────────────────╯  ",
+            "                                        ",
+        ]);
+        set_cells! { expected =>
+            // "Content-Type" label
+            (4..17, [2]) => modifier: Modifier::BOLD,
+            // "Cache-Control" label
+            (4..18, [4]) => modifier: Modifier::BOLD,
+            // "Content-Encoding" label
+            (4..21, [6]) => modifier: Modifier::BOLD,
+            // "Content-Disposition" label
+            (4..24, [8]) => modifier: Modifier::BOLD,
+            // "x-amz-meta-author" label
+            (4..22, [10]) => modifier: Modifier::BOLD,
+            // "Cache-Control" is selected
+            (4..36, [4, 5]) => fg: Color::Cyan,
+        }
+
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_apply_input() {
+        let mut state = MetadataDialogState::new(metadata());
+
+        state.select_next();
+        state.open_edit_input();
+        input(&mut state, "max-age=3600");
+        assert_eq!(state.apply_input(), Ok(()));
+        assert_eq!(state.metadata().cache_control, "max-age=3600");
+
+        state.open_add_input();
+        input(&mut state, "Owner=bob");
+        assert_eq!(state.apply_input(), Ok(()));
+        assert_eq!(
+            state.metadata().user_metadata,
+            vec![
+                ("author".to_string(), "alice".to_string()),
+                ("owner".to_string(), "bob".to_string()),
+            ]
+        );
+
+        state.open_add_input();
+        input(&mut state, "author=carol");
+        assert!(state.apply_input().is_err());
+
+        state.open_add_input();
+        input(&mut state, "invalid");
+        assert!(state.apply_input().is_err());
+
+        state.close_input();
+        state.remove_selected();
+        assert_eq!(
+            state.metadata().user_metadata,
+            vec![("author".to_string(), "alice".to_string())]
+        );
+        assert!(state.is_changed());
+    }
+
+    fn input(state: &mut MetadataDialogState, s: &str) {
+        let input = state.input_mut().unwrap();
+        input.clear_input();
+        for c in s.chars() {
+            let key = ratatui::crossterm::event::KeyEvent::from(
+                ratatui::crossterm::event::KeyCode::Char(c),
+            );
+            input.handle_key_event(key);
+        }
+    }
+
+    fn metadata() -> ObjectMetadata {
+        ObjectMetadata {
+            content_type: "text/plain".to_string(),
+            user_metadata: vec![("author".to_string(), "alice".to_string())],
+            ..Default::default()
+        }
+    }
+}
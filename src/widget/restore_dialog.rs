@@ -0,0 +1,234 @@
+use itsuki::zero_indexed_enum;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Stylize},
+    text::Line,
+    widgets::{block::Title, Block, BorderType, Padding, Paragraph, StatefulWidget, WidgetRef},
+};
+
+use crate::{color::ColorTheme, ui::common::calc_centered_dialog_rect, widget::Dialog};
+
+const DEFAULT_RESTORE_DAYS: &str = "7";
+const MAX_DAYS_DIGITS: usize = 5;
+
+#[derive(Default)]
+#[zero_indexed_enum]
+pub enum RestoreTierType {
+    Expedited,
+    #[default]
+    Standard,
+    Bulk,
+}
+
+impl RestoreTierType {
+    pub fn str(&self) -> &'static str {
+        match self {
+            Self::Expedited => "Expedited",
+            Self::Standard => "Standard",
+            Self::Bulk => "Bulk",
+        }
+    }
+
+    fn retrieval_time(&self, storage_class: &str) -> &'static str {
+        match (self, storage_class) {
+            (Self::Expedited, "DEEP_ARCHIVE") => "not available",
+            (Self::Expedited, _) => "typically 1-5 minutes",
+            (Self::Standard, "DEEP_ARCHIVE") => "typically within 12 hours",
+            (Self::Standard, _) => "typically 3-5 hours",
+            (Self::Bulk, "DEEP_ARCHIVE") => "typically within 48 hours",
+            (Self::Bulk, _) => "typically 5-12 hours",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RestoreDialogState {
+    s3_uri: String,
+    storage_class: String,
+    days: String,
+    selected: RestoreTierType,
+}
+
+impl RestoreDialogState {
+    pub fn new(s3_uri: impl Into<String>, storage_class: impl Into<String>) -> Self {
+        Self {
+            s3_uri: s3_uri.into(),
+            storage_class: storage_class.into(),
+            days: DEFAULT_RESTORE_DAYS.to_string(),
+            selected: RestoreTierType::default(),
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = self.selected.next();
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.prev();
+    }
+
+    pub fn push_days_digit(&mut self, c: char) {
+        if c.is_ascii_digit() && self.days.len() < MAX_DAYS_DIGITS {
+            self.days.push(c);
+        }
+    }
+
+    pub fn pop_days_digit(&mut self) {
+        self.days.pop();
+    }
+
+    // returns None if the number of days is not a positive integer
+    pub fn days(&self) -> Option<i32> {
+        self.days.parse().ok().filter(|d| *d > 0)
+    }
+
+    pub fn selected_tier(&self) -> &'static str {
+        self.selected.str()
+    }
+}
+
+#[derive(Debug, Default)]
+struct RestoreDialogColor {
+    bg: Color,
+    block: Color,
+    text: Color,
+    selected: Color,
+}
+
+impl RestoreDialogColor {
+    fn new(theme: &ColorTheme) -> Self {
+        Self {
+            bg: theme.bg,
+            block: theme.fg,
+            text: theme.fg,
+            selected: theme.dialog_selected,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct RestoreDialog {
+    color: RestoreDialogColor,
+}
+
+impl RestoreDialog {
+    pub fn theme(mut self, theme: &ColorTheme) -> Self {
+        self.color = RestoreDialogColor::new(theme);
+        self
+    }
+}
+
+impl StatefulWidget for RestoreDialog {
+    type State = RestoreDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let mut lines = vec![
+            Line::from(format!(
+                "The object is archived in {}.",
+                state.storage_class
+            )),
+            Line::from("Restore a temporary copy to read it?".bold()),
+            Line::from(format!("  {}", state.s3_uri)),
+            Line::from(""),
+            Line::from(vec!["Days: ".bold(), state.days.clone().into()]),
+            Line::from(""),
+            Line::from("Tier:".bold()),
+        ];
+
+        lines.extend(RestoreTierType::vars_vec().iter().map(|t| {
+            let text = format!("{} ({})", t.str(), t.retrieval_time(&state.storage_class));
+            if *t == state.selected {
+                Line::from(format!("> {}", text)).fg(self.color.selected)
+            } else {
+                Line::from(format!("  {}", text)).fg(self.color.text)
+            }
+        }));
+
+        let dialog_width = (area.width - 4).min(70);
+        let dialog_height = lines.len() as u16 + 2 /* border */;
+        let area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        let title = Title::from("Restore object");
+        let paragraph = Paragraph::new(lines).block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .title(title)
+                .bg(self.color.bg)
+                .fg(self.color.block)
+                .padding(Padding::horizontal(1)),
+        );
+        let dialog = Dialog::new(Box::new(paragraph), self.color.bg);
+        dialog.render_ref(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::set_cells;
+
+    use super::*;
+    use ratatui::style::Modifier;
+
+    #[test]
+    fn test_render_restore_dialog() {
+        let theme = ColorTheme::default();
+        let mut state = RestoreDialogState::new("s3://bucket/file.txt", "GLACIER");
+        state.push_days_digit('0');
+        state.select_next();
+        let dialog = RestoreDialog::default().theme(&theme);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 60, 14));
+        dialog.render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                                            ",
+            "  ╭Restore object────────────────────────────────────────╮  ",
+            "  │ The object is archived in GLACIER.                   │  ",
+            "  │ Restore a temporary copy to read it?                 │  ",
+            "  │   s3://bucket/file.txt                               │  ",
+            "  │                                                      │  ",
+            "  │ Days: 70                                             │  ",
+            "  │                                                      │  ",
+            "  │ Tier:                                                │  ",
+            "  │   Expedited (typically 1-5 minutes)                  │  ",
+            "  │   Standard (typically 3-5 hours)                     │  ",
+            "  │ > Bulk (typically 5-12 hours)                        │  ",
+            "  ╰──────────────────────────────────────────────────────╯  ",
+            "                                                            ",
+        ]);
+        set_cells! { expected =>
+            // confirmation message
+            (4..40, [3]) => modifier: Modifier::BOLD,
+            // "Days" label
+            (4..10, [6]) => modifier: Modifier::BOLD,
+            // "Tier" label
+            (4..9, [8]) => modifier: Modifier::BOLD,
+            // "Bulk" is selected
+            (4..33, [11]) => fg: Color::Cyan,
+        }
+
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_restore_dialog_days() {
+        let mut state = RestoreDialogState::new("s3://bucket/file.txt", "GLACIER");
+        assert_eq!(state.days(), Some(7));
+
+        state.push_days_digit('x');
+        assert_eq!(state.days(), Some(7));
+
+        state.pop_days_digit();
+        assert_eq!(state.days(), None);
+
+        state.push_days_digit('0');
+        assert_eq!(state.days(), None);
+
+        for _ in 0..6 {
+            state.push_days_digit('1');
+        }
+        assert_eq!(state.days(), Some(1111));
+    }
+}
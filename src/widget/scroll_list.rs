@@ -2,6 +2,7 @@ use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Margin, Rect},
     style::{Color, Stylize},
+    text::Line,
     widgets::{Block, List, ListItem, Padding, StatefulWidget, Widget},
 };
 
@@ -25,6 +26,17 @@ impl ScrollListState {
         }
     }
 
+    pub fn set_total(&mut self, total: usize) {
+        // keeps the current selection as long as it is still in range
+        self.total = total;
+        if self.selected >= total {
+            self.selected = total.saturating_sub(1);
+        }
+        if self.offset > self.selected {
+            self.offset = self.selected;
+        }
+    }
+
     pub fn select_next(&mut self) {
         if self.total == 0 {
             return;
@@ -130,6 +142,7 @@ impl ScrollListColor {
 #[derive(Debug)]
 pub struct ScrollList<'a> {
     items: Vec<ListItem<'a>>,
+    title: Option<String>,
     color: ScrollListColor,
 }
 
@@ -137,10 +150,16 @@ impl ScrollList<'_> {
     pub fn new(items: Vec<ListItem>) -> ScrollList {
         ScrollList {
             items,
+            title: None,
             color: Default::default(),
         }
     }
 
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
     pub fn theme(mut self, theme: &ColorTheme) -> Self {
         self.color = ScrollListColor::new(theme);
         self
@@ -154,13 +173,15 @@ impl StatefulWidget for ScrollList<'_> {
         state.height = area.height as usize - 2 /* border */;
 
         let title = format_list_count(state.total, state.selected);
-        let list = List::new(self.items).block(
-            Block::bordered()
-                .title(title)
-                .title_alignment(Alignment::Right)
-                .padding(Padding::horizontal(1))
-                .fg(self.color.block),
-        );
+        let mut block = Block::bordered()
+            .title(title)
+            .title_alignment(Alignment::Right)
+            .padding(Padding::horizontal(1))
+            .fg(self.color.block);
+        if let Some(title) = self.title {
+            block = block.title_top(Line::from(title).left_aligned());
+        }
+        let list = List::new(self.items).block(block);
         Widget::render(list, area, buf);
 
         let area = area.inner(Margin::new(2, 1));
@@ -16,6 +16,8 @@ pub enum BucketListSortType {
     Default,
     NameAsc,
     NameDesc,
+    RegionAsc,
+    RegionDesc,
 }
 
 impl BucketListSortType {
@@ -24,6 +26,8 @@ impl BucketListSortType {
             Self::Default => "Default",
             Self::NameAsc => "Name (Asc)",
             Self::NameDesc => "Name (Desc)",
+            Self::RegionAsc => "Region (Asc)",
+            Self::RegionDesc => "Region (Desc)",
         }
     }
 }
@@ -0,0 +1,298 @@
+use itsuki::zero_indexed_enum;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Stylize},
+    text::Line,
+    widgets::{block::Title, Block, BorderType, Padding, Paragraph, StatefulWidget, WidgetRef},
+};
+
+use crate::{
+    color::ColorTheme,
+    object::StorageClassTarget,
+    ui::common::{calc_centered_dialog_rect, format_size_byte},
+    widget::Dialog,
+};
+
+#[derive(Default)]
+#[zero_indexed_enum]
+pub enum StorageClassType {
+    #[default]
+    Standard,
+    IntelligentTiering,
+    StandardIa,
+    OnezoneIa,
+    GlacierIr,
+    Glacier,
+    DeepArchive,
+}
+
+impl StorageClassType {
+    pub fn str(&self) -> &'static str {
+        match self {
+            Self::Standard => "STANDARD",
+            Self::IntelligentTiering => "INTELLIGENT_TIERING",
+            Self::StandardIa => "STANDARD_IA",
+            Self::OnezoneIa => "ONEZONE_IA",
+            Self::GlacierIr => "GLACIER_IR",
+            Self::Glacier => "GLACIER",
+            Self::DeepArchive => "DEEP_ARCHIVE",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct StorageClassDialogState {
+    target: StorageClassTarget,
+    selected: StorageClassType,
+}
+
+impl StorageClassDialogState {
+    pub fn new(target: StorageClassTarget) -> Self {
+        Self {
+            target,
+            selected: StorageClassType::default(),
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = self.selected.next();
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.prev();
+    }
+
+    pub fn target(&self) -> &StorageClassTarget {
+        &self.target
+    }
+
+    pub fn selected_storage_class(&self) -> &'static str {
+        self.selected.str()
+    }
+}
+
+#[derive(Debug, Default)]
+struct StorageClassDialogColor {
+    bg: Color,
+    block: Color,
+    text: Color,
+    selected: Color,
+}
+
+impl StorageClassDialogColor {
+    fn new(theme: &ColorTheme) -> Self {
+        Self {
+            bg: theme.bg,
+            block: theme.fg,
+            text: theme.fg,
+            selected: theme.dialog_selected,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct StorageClassDialog {
+    color: StorageClassDialogColor,
+}
+
+impl StorageClassDialog {
+    pub fn theme(mut self, theme: &ColorTheme) -> Self {
+        self.color = StorageClassDialogColor::new(theme);
+        self
+    }
+}
+
+impl StatefulWidget for StorageClassDialog {
+    type State = StorageClassDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let mut lines = vec![
+            Line::from(if state.target.dir {
+                "Change the storage class of all objects under the prefix?".bold()
+            } else {
+                "Change the storage class of the object?".bold()
+            }),
+            Line::from(format!("  {}", state.target.s3_uri())),
+            Line::from(""),
+        ];
+
+        lines.extend(StorageClassType::vars_vec().iter().map(|t| {
+            if *t == state.selected {
+                Line::from(format!("> {}", t.str())).fg(self.color.selected)
+            } else {
+                Line::from(format!("  {}", t.str())).fg(self.color.text)
+            }
+        }));
+        lines.push(Line::from(""));
+
+        let storage_class = state.selected.str();
+        let summary = state.target.moving_out_summary(storage_class);
+        if summary.is_empty() {
+            lines.push(Line::from("No objects to change".bold()));
+        } else {
+            lines.push(Line::from("Moving out:".bold()));
+            lines.extend(summary.iter().map(|(class, count, size_byte)| {
+                Line::from(format!(
+                    "  {}: {} objects ({})",
+                    class,
+                    count,
+                    format_size_byte(*size_byte)
+                ))
+            }));
+        }
+        let unrestored = state.target.unrestored_objects(storage_class);
+        if !unrestored.is_empty() {
+            lines.push(Line::from(format!(
+                "Skipped: {} archived objects not restored",
+                unrestored.len()
+            )));
+        }
+
+        let dialog_width = (area.width - 4).min(70);
+        let dialog_height = lines.len() as u16 + 2 /* border */;
+        let area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        let title = Title::from("Storage class");
+        let paragraph = Paragraph::new(lines).block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .title(title)
+                .bg(self.color.bg)
+                .fg(self.color.block)
+                .padding(Padding::horizontal(1)),
+        );
+        let dialog = Dialog::new(Box::new(paragraph), self.color.bg);
+        dialog.render_ref(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        object::{ObjectKey, ObjectSummary},
+        set_cells,
+    };
+
+    use super::*;
+    use ratatui::style::Modifier;
+
+    #[test]
+    fn test_render_storage_class_dialog() {
+        let theme = ColorTheme::default();
+        let target = StorageClassTarget {
+            object_key: ObjectKey {
+                bucket_name: "bucket".to_string(),
+                object_path: vec!["dir".to_string()],
+            },
+            dir: true,
+            objects: vec![
+                object_summary("dir/a", 1024, "STANDARD"),
+                object_summary("dir/b", 2048, "STANDARD_IA"),
+                object_summary("dir/c", 1024, "STANDARD"),
+            ],
+        };
+        let mut state = StorageClassDialogState::new(target);
+        state.select_next();
+        state.select_next();
+        let dialog = StorageClassDialog::default().theme(&theme);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 70, 18));
+        dialog.render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                                                      ",
+            "  ╭Storage class───────────────────────────────────────────────────╮  ",
+            "  │ Change the storage class of all objects under the prefix?      │  ",
+            "  │   s3://bucket/dir/                                             │  ",
+            "  │                                                                │  ",
+            "  │   STANDARD                                                     │  ",
+            "  │   INTELLIGENT_TIERING                                          │  ",
+            "  │ > STANDARD_IA                                                  │  ",
+            "  │   ONEZONE_IA                                                   │  ",
+            "  │   GLACIER_IR                                                   │  ",
+            "  │   GLACIER                                                      │  ",
+            "  │   DEEP_ARCHIVE                                                 │  ",
+            "  │                                                                │  ",
+            "  │ Moving out:                                                    │  ",
+            "  │   STANDARD: 2 objects (2 KiB)                                  │  ",
+            "  ╰────────────────────────────────────────────────────────────────╯  ",
+            "                                                                      ",
+            "                                                                      ",
+        ]);
+        set_cells! { expected =>
+            // confirmation message
+            (4..61, [2]) => modifier: Modifier::BOLD,
+            // "Moving out" label
+            (4..15, [13]) => modifier: Modifier::BOLD,
+            // "STANDARD_IA" is selected
+            (4..17, [7]) => fg: Color::Cyan,
+        }
+
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_render_storage_class_dialog_with_unrestored_objects() {
+        let theme = ColorTheme::default();
+        let target = StorageClassTarget {
+            object_key: ObjectKey {
+                bucket_name: "bucket".to_string(),
+                object_path: vec!["dir".to_string()],
+            },
+            dir: true,
+            objects: vec![
+                object_summary("dir/a", 1024, "STANDARD"),
+                object_summary("dir/b", 2048, "GLACIER"),
+                object_summary("dir/c", 1024, "DEEP_ARCHIVE"),
+            ],
+        };
+        let mut state = StorageClassDialogState::new(target);
+        let dialog = StorageClassDialog::default().theme(&theme);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 70, 18));
+        dialog.render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                                                      ",
+            "  ╭Storage class───────────────────────────────────────────────────╮  ",
+            "  │ Change the storage class of all objects under the prefix?      │  ",
+            "  │   s3://bucket/dir/                                             │  ",
+            "  │                                                                │  ",
+            "  │ > STANDARD                                                     │  ",
+            "  │   INTELLIGENT_TIERING                                          │  ",
+            "  │   STANDARD_IA                                                  │  ",
+            "  │   ONEZONE_IA                                                   │  ",
+            "  │   GLACIER_IR                                                   │  ",
+            "  │   GLACIER                                                      │  ",
+            "  │   DEEP_ARCHIVE                                                 │  ",
+            "  │                                                                │  ",
+            "  │ No objects to change                                           │  ",
+            "  │ Skipped: 2 archived objects not restored                       │  ",
+            "  ╰────────────────────────────────────────────────────────────────╯  ",
+            "                                                                      ",
+            "                                                                      ",
+        ]);
+        set_cells! { expected =>
+            // confirmation message
+            (4..61, [2]) => modifier: Modifier::BOLD,
+            // "No objects to change" label
+            (4..24, [13]) => modifier: Modifier::BOLD,
+            // "STANDARD" is selected
+            (4..14, [5]) => fg: Color::Cyan,
+        }
+
+        assert_eq!(buf, expected);
+    }
+
+    fn object_summary(key: &str, size_byte: usize, storage_class: &str) -> ObjectSummary {
+        ObjectSummary {
+            key: key.to_string(),
+            size_byte,
+            storage_class: storage_class.to_string(),
+            restored: false,
+        }
+    }
+}
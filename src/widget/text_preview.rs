@@ -56,21 +56,9 @@ impl TextPreviewState {
         highlight: bool,
         highlight_theme_name: &str,
     ) -> (Self, Option<String>) {
-        let mut warn_msg = None;
-
         let s = to_preview_string(&object.bytes);
-
-        let lines: Vec<Line<'static>> =
-            match build_highlighted_lines(&s, &file_detail.name, highlight, highlight_theme_name) {
-                Ok(lines) => lines,
-                Err(msg) => {
-                    // If there is an error, display the original text
-                    if let Some(msg) = msg {
-                        warn_msg = Some(msg);
-                    }
-                    s.lines().map(drop_control_chars).map(Line::raw).collect()
-                }
-            };
+        let (lines, warn_msg) =
+            build_text_lines(&s, &file_detail.name, highlight, highlight_theme_name);
 
         let scroll_lines_state = ScrollLinesState::new(lines, ScrollLinesOptions::default());
 
@@ -79,6 +67,24 @@ impl TextPreviewState {
     }
 }
 
+// the syntax is chosen by the extension of the file name
+// returns the plain lines with a warning message if the text cannot be highlighted
+pub fn build_text_lines(
+    s: &str,
+    file_name: &str,
+    highlight: bool,
+    highlight_theme_name: &str,
+) -> (Vec<Line<'static>>, Option<String>) {
+    match build_highlighted_lines(s, file_name, highlight, highlight_theme_name) {
+        Ok(lines) => (lines, None),
+        Err(msg) => {
+            // If there is an error, display the original text
+            let lines = s.lines().map(drop_control_chars).map(Line::raw).collect();
+            (lines, msg)
+        }
+    }
+}
+
 fn to_preview_string(bytes: &[u8]) -> String {
     let s: String = String::from_utf8_lossy(bytes).into();
     // tab is not rendered correctly, so replace it
@@ -0,0 +1,244 @@
+use chrono::{DateTime, Local};
+use ratatui::{
+    buffer::Buffer,
+    crossterm::event::KeyEvent,
+    layout::Rect,
+    style::{Color, Stylize},
+    text::Line,
+    widgets::{block::Title, Block, BorderType, Padding, Paragraph, StatefulWidget, WidgetRef},
+};
+use tui_input::{backend::crossterm::EventHandler, Input};
+
+use crate::{
+    color::ColorTheme,
+    object::UndeleteTarget,
+    ui::common::calc_centered_dialog_rect,
+    util::{parse_datetime_input, DATETIME_INPUT_FORMAT},
+    widget::Dialog,
+};
+
+const CREATED_AFTER_LABEL: &str = "Created after: ";
+
+#[derive(Debug)]
+pub struct UndeleteDialogState {
+    target: UndeleteTarget,
+    input: Input,
+    cursor: (u16, u16),
+}
+
+impl UndeleteDialogState {
+    pub fn new(target: UndeleteTarget) -> Self {
+        Self {
+            target,
+            input: Input::default(),
+            cursor: (0, 0),
+        }
+    }
+
+    pub fn cursor(&self) -> (u16, u16) {
+        self.cursor
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) {
+        let event = &ratatui::crossterm::event::Event::Key(key);
+        self.input.handle_event(event);
+    }
+
+    // returns None if the time is invalid, Some(None) if it is not given
+    fn created_after(&self) -> Option<Option<DateTime<Local>>> {
+        let input = self.input.value().trim();
+        if input.is_empty() {
+            Some(None)
+        } else {
+            parse_datetime_input(input).map(Some)
+        }
+    }
+
+    // the target narrowed down to the markers created after the given time
+    pub fn target_to_undelete(&self) -> Option<UndeleteTarget> {
+        let after = self.created_after()?;
+        let markers = self
+            .target
+            .markers_created_after(after)
+            .into_iter()
+            .cloned()
+            .collect();
+        Some(UndeleteTarget {
+            object_key: self.target.object_key.clone(),
+            markers,
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct UndeleteDialogColor {
+    bg: Color,
+    block: Color,
+    text: Color,
+    error: Color,
+}
+
+impl UndeleteDialogColor {
+    fn new(theme: &ColorTheme) -> Self {
+        Self {
+            bg: theme.bg,
+            block: theme.fg,
+            text: theme.fg,
+            error: theme.status_error,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct UndeleteDialog {
+    color: UndeleteDialogColor,
+}
+
+impl UndeleteDialog {
+    pub fn theme(mut self, theme: &ColorTheme) -> Self {
+        self.color = UndeleteDialogColor::new(theme);
+        self
+    }
+}
+
+impl StatefulWidget for UndeleteDialog {
+    type State = UndeleteDialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let dialog_width = (area.width - 4).min(70);
+
+        // show the last `input_max_width` characters of the input
+        let input_max_width = (dialog_width as usize).saturating_sub(4 + CREATED_AFTER_LABEL.len());
+        let input_start_index = state.input.visual_cursor().saturating_sub(input_max_width);
+        let input_view: &str = &state.input.value()[input_start_index..];
+
+        let summary = match state.target_to_undelete() {
+            Some(target) => Line::from(format!(
+                "Dry run: {} of {} deleted objects will be undeleted",
+                target.markers.len(),
+                state.target.markers.len()
+            ))
+            .fg(self.color.text),
+            None => Line::from(format!("Invalid time (expected {})", DATETIME_INPUT_FORMAT))
+                .fg(self.color.error),
+        };
+
+        let lines = vec![
+            Line::from("Undelete all deleted objects under the prefix?".bold()),
+            Line::from(format!("  {}", state.target.s3_uri())),
+            Line::from(""),
+            Line::from(vec![
+                CREATED_AFTER_LABEL.bold(),
+                input_view.fg(self.color.text),
+            ]),
+            Line::from("  (leave empty to undelete all)".fg(self.color.text)),
+            Line::from(""),
+            summary,
+        ];
+
+        let dialog_height = lines.len() as u16 + 2 /* border */;
+        let dialog_area = calc_centered_dialog_rect(area, dialog_width, dialog_height);
+
+        let title = Title::from("Undelete");
+        let paragraph = Paragraph::new(lines).block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .title(title)
+                .bg(self.color.bg)
+                .fg(self.color.block)
+                .padding(Padding::horizontal(1)),
+        );
+        let dialog = Dialog::new(Box::new(paragraph), self.color.bg);
+        dialog.render_ref(dialog_area, buf);
+
+        let cursor_x = dialog_area.x
+            + state.input.visual_cursor().min(input_max_width) as u16
+            + 2 /* border + padding */
+            + CREATED_AFTER_LABEL.len() as u16;
+        let cursor_y = dialog_area.y + 4;
+        state.cursor = (cursor_x, cursor_y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use ratatui::{crossterm::event::KeyCode, style::Modifier};
+
+    use crate::{
+        object::{DeleteMarker, ObjectKey},
+        set_cells,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_render_undelete_dialog() {
+        let theme = ColorTheme::default();
+        let mut state = UndeleteDialogState::new(undelete_target());
+        let dialog = UndeleteDialog::default().theme(&theme);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 60, 11));
+        dialog.render(buf.area, &mut buf, &mut state);
+
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "                                                            ",
+            "  ╭Undelete──────────────────────────────────────────────╮  ",
+            "  │ Undelete all deleted objects under the prefix?       │  ",
+            "  │   s3://bucket/dir/                                   │  ",
+            "  │                                                      │  ",
+            "  │ Created after:                                       │  ",
+            "  │   (leave empty to undelete all)                      │  ",
+            "  │                                                      │  ",
+            "  │ Dry run: 2 of 2 deleted objects will be undeleted    │  ",
+            "  ╰──────────────────────────────────────────────────────╯  ",
+            "                                                            ",
+        ]);
+        set_cells! { expected =>
+            // confirmation message
+            (4..50, [2]) => modifier: Modifier::BOLD,
+            // "Created after" label
+            (4..19, [5]) => modifier: Modifier::BOLD,
+        }
+
+        assert_eq!(buf, expected);
+        assert_eq!(state.cursor(), (19, 5));
+    }
+
+    #[test]
+    fn test_undelete_dialog_target_to_undelete() {
+        let mut state = UndeleteDialogState::new(undelete_target());
+        assert_eq!(state.target_to_undelete().unwrap().markers.len(), 2);
+
+        for c in "2024-01-02".chars() {
+            state.handle_key_event(KeyEvent::from(KeyCode::Char(c)));
+        }
+        let target = state.target_to_undelete().unwrap();
+        assert_eq!(target.markers.len(), 1);
+        assert_eq!(target.markers[0].key, "dir/b.txt");
+
+        state.handle_key_event(KeyEvent::from(KeyCode::Char('x')));
+        assert!(state.target_to_undelete().is_none());
+    }
+
+    fn undelete_target() -> UndeleteTarget {
+        let marker = |key: &str, date: (i32, u32, u32)| DeleteMarker {
+            key: key.to_string(),
+            version_id: "v".to_string(),
+            last_modified: Local
+                .with_ymd_and_hms(date.0, date.1, date.2, 12, 0, 0)
+                .unwrap(),
+        };
+        UndeleteTarget {
+            object_key: ObjectKey {
+                bucket_name: "bucket".to_string(),
+                object_path: vec!["dir".to_string()],
+            },
+            markers: vec![
+                marker("dir/a.txt", (2024, 1, 1)),
+                marker("dir/b.txt", (2024, 1, 3)),
+            ],
+        }
+    }
+}